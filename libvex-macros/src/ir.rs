@@ -0,0 +1,1659 @@
+//! The grammar of VEX's textual IR, as printed by `ppIRSB`, and the code that builds it with
+//! `libvex::ir`.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, bracketed, parenthesized, token, Error, Ident, LitInt, Result, Token};
+
+mod check;
+mod pattern;
+// VEX's types and ops, generated by libvex's tests.
+#[rustfmt::skip]
+mod vex;
+
+pub use check::{check, check_expr, check_match, check_stmts};
+pub use pattern::Match;
+
+pub mod kw {
+    syn::custom_keyword!(IRSB);
+    syn::custom_keyword!(IR); // IR-NoOp, IR-Fence and IR-CancelReservation
+    syn::custom_keyword!(NoOp);
+    syn::custom_keyword!(Fence);
+    syn::custom_keyword!(CancelReservation);
+    syn::custom_keyword!(IMark);
+    syn::custom_keyword!(AbiHint);
+    syn::custom_keyword!(PUT);
+    syn::custom_keyword!(PUTI);
+    syn::custom_keyword!(GET);
+    syn::custom_keyword!(GETI);
+    syn::custom_keyword!(ITE);
+    syn::custom_keyword!(BIND);
+    syn::custom_keyword!(VECRET);
+    syn::custom_keyword!(GSPTR);
+    syn::custom_keyword!(STbe);
+    syn::custom_keyword!(STle);
+    syn::custom_keyword!(LDbe);
+    syn::custom_keyword!(LDle);
+    syn::custom_keyword!(CASbe);
+    syn::custom_keyword!(CASle);
+    syn::custom_keyword!(Linked);
+    syn::custom_keyword!(Cond);
+    syn::custom_keyword!(strict);
+    syn::custom_keyword!(DIRTY);
+    syn::custom_keyword!(mem);
+    syn::custom_keyword!(gst);
+    syn::custom_keyword!(exit);
+    syn::custom_keyword!(arch);
+}
+
+/// A name that may start with a digit, such as the op `32Uto64`. Rust lexes those as integer
+/// literals with a suffix.
+pub struct Name {
+    pub text: String,
+    pub span: Span,
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitInt) {
+            let lit: LitInt = input.parse()?;
+            Ok(Self {
+                text: lit.to_string(),
+                span: lit.span(),
+            })
+        } else if lookahead.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            Ok(Self {
+                text: ident.to_string(),
+                span: ident.span(),
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// A possibly negative integer, such as the bias of `GETI`.
+pub struct SignedInt {
+    neg: Option<Token![-]>,
+    lit: LitInt,
+}
+
+impl Parse for SignedInt {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            neg: input.parse()?,
+            lit: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for SignedInt {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.neg.to_tokens(tokens);
+        self.lit.to_tokens(tokens);
+    }
+}
+
+/// The endness in `LDle`, `STbe`, `CASle`, ...
+pub enum Endness {
+    Big,
+    Little,
+}
+
+impl Endness {
+    fn parse_with(input: ParseStream, prefix: &str) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        let name = ident.to_string();
+        match name.strip_prefix(prefix) {
+            Some("be") => Ok(Self::Big),
+            Some("le") => Ok(Self::Little),
+            _ => Err(Error::new(
+                ident.span(),
+                format!("expected `{0}le` or `{0}be`", prefix),
+            )),
+        }
+    }
+}
+
+impl ToTokens for Endness {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Big => quote!(IREndness::Iend_BE),
+            Self::Little => quote!(IREndness::Iend_LE),
+        })
+    }
+}
+
+pub fn type_ident(ty: &Ident) -> Ident {
+    format_ident!("Ity_{}", ty, span = ty.span())
+}
+
+pub fn jump_kind_ident(kind: &Ident) -> Ident {
+    // ppIRJumpKind prints Ijk_Ret as `Return`.
+    if kind == "Return" {
+        Ident::new("Ijk_Ret", kind.span())
+    } else {
+        format_ident!("Ijk_{}", kind, span = kind.span())
+    }
+}
+
+/// The architectures that have offsets imported by `import_offsets!`.
+const ARCHES: [&str; 9] = [
+    "amd64", "arm", "arm64", "mips32", "mips64", "ppc32", "ppc64", "s390x", "x86",
+];
+
+/// `arch amd64;`, which allows registers to be named in `PUT` and `GET`.
+pub struct ArchHeader {
+    pub arch: Ident,
+}
+
+impl Parse for ArchHeader {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::arch>()?;
+        let arch: Ident = input.parse()?;
+        input.parse::<Token![;]>()?;
+        if !ARCHES.iter().any(|&name| arch == name) {
+            return Err(Error::new(
+                arch.span(),
+                format!("unknown arch, expected one of {}", ARCHES.join(", ")),
+            ));
+        }
+        Ok(Self { arch })
+    }
+}
+
+impl ToTokens for ArchHeader {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let arch = &self.arch;
+        tokens.extend(quote!(use libvex::#arch::offset;))
+    }
+}
+
+/// A guest state offset, either as a number or (given an [ArchHeader]) as a register name.
+/// In `ir_match!`, it may also be a capture, `$off`.
+pub enum Offset {
+    Lit(LitInt),
+    Reg(Ident),
+    Capture(Token![$], Ident),
+}
+
+impl Parse for Offset {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitInt) {
+            input.parse().map(Self::Lit)
+        } else if lookahead.peek(Ident) {
+            input.parse().map(Self::Reg)
+        } else if lookahead.peek(Token![$]) {
+            Ok(Self::Capture(input.parse()?, input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for Offset {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lit(lit) => lit.to_tokens(tokens),
+            Self::Reg(reg) => {
+                // See `import_offsets!`, registers are case insensitive.
+                let reg = Ident::new(&reg.to_string().to_lowercase(), reg.span());
+                tokens.extend(quote!(offset::lowercase::#reg))
+            }
+            // The checker only allows captures in `ir_match!`.
+            Self::Capture(..) => unreachable!(),
+        }
+    }
+}
+
+pub struct TypeEnv {
+    pub tmps: Vec<(Ident, Token![:], Ident)>,
+}
+
+impl Parse for TypeEnv {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut tmps = Vec::new();
+        while input.peek(Ident) && input.peek2(Token![:]) {
+            tmps.push((input.parse()?, input.parse()?, input.parse()?));
+        }
+        Ok(TypeEnv { tmps })
+    }
+}
+
+/// `(base:NxTy)`, as in `GETI` and `PUTI`.
+pub struct RegArray {
+    base: LitInt,
+    n_elems: LitInt,
+    elem_ty: Ident,
+}
+
+impl Parse for RegArray {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let base = content.parse()?;
+        content.parse::<Token![:]>()?;
+        // `8xI64` is an integer literal with the suffix `xI64`.
+        let elems: LitInt = content.parse()?;
+        match elems.suffix().strip_prefix('x') {
+            Some(ty) if !ty.is_empty() => Ok(Self {
+                base,
+                n_elems: LitInt::new(elems.base10_digits(), elems.span()),
+                elem_ty: Ident::new(ty, elems.span()),
+            }),
+            _ => Err(Error::new(elems.span(), "expected `<elements>x<type>`")),
+        }
+    }
+}
+
+impl ToTokens for RegArray {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (base, n_elems) = (&self.base, &self.n_elems);
+        let ty = type_ident(&self.elem_ty);
+        tokens.extend(quote!(RegArray::new(#base, Type::#ty, #n_elems)))
+    }
+}
+
+/// `name[rp=N][mcx=0xM]{0xaddr}`
+pub struct Callee {
+    name: Ident,
+    regparms: Option<LitInt>,
+    mcx_mask: Option<LitInt>,
+    addr: LitInt,
+}
+
+impl Parse for Callee {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        let (mut regparms, mut mcx_mask) = (None, None);
+        while input.peek(token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let key: Ident = content.parse()?;
+            content.parse::<Token![=]>()?;
+            let value = content.parse()?;
+            if key == "rp" {
+                regparms = Some(value);
+            } else if key == "mcx" {
+                mcx_mask = Some(value);
+            } else {
+                return Err(Error::new(key.span(), "expected `rp` or `mcx`"));
+            }
+        }
+        let addr;
+        braced!(addr in input);
+        Ok(Self {
+            name,
+            regparms,
+            mcx_mask,
+            addr: addr.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Callee {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.name.to_string();
+        let addr = &self.addr;
+        let regparms = match &self.regparms {
+            Some(regparms) => quote!(#regparms),
+            None => quote!(0),
+        };
+        let callee = quote!(Callee::new(#regparms, #name, #addr as usize as *mut _));
+        tokens.extend(match &self.mcx_mask {
+            Some(mcx_mask) => quote!({
+                let mut callee = #callee;
+                callee.set_mcx_mask(#mcx_mask);
+                callee
+            }),
+            None => callee,
+        })
+    }
+}
+
+pub enum Expr {
+    Binder(LitInt),
+    Get(ExprGet),
+    GetI(ExprGetI),
+    RdTmp(Ident),
+    Op(ExprOp),
+    Load(ExprLoad),
+    Const(ExprConst),
+    ITE(ExprITE),
+    CCall(ExprCCall),
+    VecRet(kw::VECRET),
+    GsPtr(kw::GSPTR),
+    // Only in `ir_match!`
+    Capture(Capture),
+    Wildcard(Token![_]),
+}
+
+/// `$name`, or `$name:Kind` where the kind is `Const`, `Temp` or an integer type, in
+/// `ir_match!`.
+pub struct Capture {
+    pub dollar: Token![$],
+    pub name: Ident,
+    pub kind: Option<Ident>,
+}
+
+pub struct ExprGet {
+    _get: kw::GET,
+    _colon: Token![:],
+    size: Ident,
+    _paren: token::Paren,
+    offset: Offset,
+}
+
+pub struct ExprGetI {
+    geti: kw::GETI,
+    descr: RegArray,
+    ix: Box<Expr>,
+    bias: SignedInt,
+}
+
+pub struct ExprOp {
+    op: Name,
+    _paren: token::Paren,
+    args: Punctuated<Expr, Token![,]>,
+}
+
+pub struct ExprLoad {
+    end: Endness,
+    _colon: Token![:],
+    ty: Ident,
+    _paren: token::Paren,
+    addr: Box<Expr>,
+}
+
+/// `0x2a:I64`, or `F64{0x3ff0000000000000}` for floats and vectors, which are given as bits.
+pub struct ExprConst {
+    co: LitInt,
+    ty: Ident,
+    brace: Option<token::Brace>,
+}
+
+// The constants that ppIRConst prints as `Ty{0x..}`
+const BRACED_CONSTS: [&str; 6] = ["F32", "F32i", "F64", "F64i", "V128", "V256"];
+
+fn peek_braced_const(input: ParseStream) -> bool {
+    input.peek2(token::Brace)
+        && matches!(
+            input.fork().parse::<Ident>(),
+            Ok(ty) if BRACED_CONSTS.iter().any(|&name| ty == name)
+        )
+}
+
+pub struct ExprITE {
+    ite: kw::ITE,
+    cond: Box<Expr>,
+    if_true: Box<Expr>,
+    if_false: Box<Expr>,
+}
+
+pub struct ExprCCall {
+    callee: Callee,
+    args: Punctuated<Expr, Token![,]>,
+    ret_ty: Ident,
+}
+
+impl Parse for Expr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::GET) {
+            input.parse().map(Self::Get)
+        } else if lookahead.peek(kw::GETI) {
+            input.parse().map(Self::GetI)
+        } else if lookahead.peek(kw::LDle) || lookahead.peek(kw::LDbe) {
+            input.parse().map(Self::Load)
+        } else if lookahead.peek(kw::ITE) {
+            input.parse().map(Self::ITE)
+        } else if lookahead.peek(kw::BIND) {
+            input.parse::<kw::BIND>()?;
+            input.parse::<Token![-]>()?;
+            input.parse().map(Self::Binder)
+        } else if lookahead.peek(kw::VECRET) {
+            input.parse().map(Self::VecRet)
+        } else if lookahead.peek(kw::GSPTR) {
+            input.parse().map(Self::GsPtr)
+        } else if lookahead.peek(Token![$]) {
+            input.parse().map(Self::Capture)
+        } else if lookahead.peek(Token![_]) {
+            input.parse().map(Self::Wildcard)
+        } else if (lookahead.peek(Ident) || lookahead.peek(LitInt)) && input.peek2(token::Paren) {
+            input.parse().map(Self::Op)
+        } else if lookahead.peek(LitInt) || (lookahead.peek(Ident) && peek_braced_const(input)) {
+            input.parse().map(Self::Const)
+        } else if lookahead.peek(Ident)
+            && (input.peek2(token::Bracket) || input.peek2(token::Brace))
+        {
+            input.parse().map(Self::CCall)
+        } else if lookahead.peek(Ident) {
+            input.parse().map(Self::RdTmp)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for Capture {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let dollar = input.parse()?;
+        let name = input.parse()?;
+        let kind = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { dollar, name, kind })
+    }
+}
+
+impl Parse for ExprGet {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let offset;
+        Ok(Self {
+            _get: input.parse()?,
+            _colon: input.parse()?,
+            size: input.parse()?,
+            _paren: parenthesized!(offset in input),
+            offset: offset.parse()?,
+        })
+    }
+}
+
+impl Parse for ExprGetI {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let geti = input.parse()?;
+        let descr = input.parse()?;
+        let index;
+        bracketed!(index in input);
+        let ix = index.parse()?;
+        index.parse::<Token![,]>()?;
+        Ok(Self {
+            geti,
+            descr,
+            ix,
+            bias: index.parse()?,
+        })
+    }
+}
+
+impl Parse for ExprLoad {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let addr;
+        Ok(Self {
+            end: Endness::parse_with(input, "LD")?,
+            _colon: input.parse()?,
+            ty: input.parse()?,
+            _paren: parenthesized!(addr in input),
+            addr: addr.parse()?,
+        })
+    }
+}
+
+impl Parse for ExprConst {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) {
+            let ty = input.parse()?;
+            let co;
+            let brace = braced!(co in input);
+            Ok(Self {
+                co: co.parse()?,
+                ty,
+                brace: Some(brace),
+            })
+        } else {
+            let co = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Ok(Self {
+                co,
+                ty: input.parse()?,
+                brace: None,
+            })
+        }
+    }
+}
+
+impl Parse for ExprOp {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let args;
+        let op: Name = input.parse()?;
+        let _paren = parenthesized!(args in input);
+        let args = args.parse_terminated(Expr::parse)?;
+        if args.is_empty() || args.len() > 4 {
+            return Err(Error::new(op.span, "ops take 1 to 4 arguments"));
+        }
+        Ok(Self { op, _paren, args })
+    }
+}
+
+impl Parse for ExprITE {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ite = input.parse()?;
+        let args;
+        parenthesized!(args in input);
+        let cond = args.parse()?;
+        args.parse::<Token![,]>()?;
+        let if_true = args.parse()?;
+        args.parse::<Token![,]>()?;
+        Ok(Self {
+            ite,
+            cond,
+            if_true,
+            if_false: args.parse()?,
+        })
+    }
+}
+
+impl Parse for ExprCCall {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let callee = input.parse()?;
+        let args;
+        parenthesized!(args in input);
+        let args = args.parse_terminated(Expr::parse)?;
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            callee,
+            args,
+            ret_ty: input.parse()?,
+        })
+    }
+}
+
+impl Expr {
+    /// The span of the expression's first token.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Binder(binder) => binder.span(),
+            Self::Get(get) => get._get.span,
+            Self::GetI(get_i) => get_i.geti.span,
+            Self::RdTmp(tmp) => tmp.span(),
+            Self::Op(op) => op.op.span,
+            Self::Load(load) => load.ty.span(),
+            Self::Const(co) => co.co.span(),
+            Self::ITE(ite) => ite.ite.span,
+            Self::CCall(ccall) => ccall.callee.name.span(),
+            Self::VecRet(vecret) => vecret.span,
+            Self::GsPtr(gsptr) => gsptr.span,
+            Self::Capture(capture) => capture.dollar.span,
+            Self::Wildcard(wildcard) => wildcard.span,
+        }
+    }
+}
+
+impl ToTokens for Expr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Binder(binder) => tokens.extend(quote!(Expr::binder(#binder))),
+            Self::Get(get) => get.to_tokens(tokens),
+            Self::GetI(get_i) => get_i.to_tokens(tokens),
+            Self::RdTmp(tmp) => tokens.extend(quote!(Expr::rd_tmp(#tmp))),
+            Self::Op(op) => op.to_tokens(tokens),
+            Self::Load(load) => load.to_tokens(tokens),
+            Self::Const(co) => co.to_tokens(tokens),
+            Self::ITE(ite) => ite.to_tokens(tokens),
+            Self::CCall(ccall) => ccall.to_tokens(tokens),
+            Self::VecRet(_) => tokens.extend(quote!(Expr::vecret())),
+            Self::GsPtr(_) => tokens.extend(quote!(Expr::gsptr())),
+            Self::Capture(_) | Self::Wildcard(_) => unreachable!(),
+        }
+    }
+}
+
+impl ToTokens for ExprGet {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let offset = &self.offset;
+        let ty = type_ident(&self.size);
+        tokens.extend(quote!(Expr::get(#offset, Type::#ty)))
+    }
+}
+
+impl ToTokens for ExprGetI {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (descr, ix, bias) = (&self.descr, &self.ix, &self.bias);
+        tokens.extend(quote!(Expr::get_i(#descr, #ix, #bias)))
+    }
+}
+
+impl ToTokens for ExprOp {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op = check::op_ident(&self.op.text, self.op.span);
+        let args = &self.args;
+        match self.args.len() {
+            1 => tokens.extend(quote!(Expr::unop(Op::#op, #args))),
+            2 => tokens.extend(quote!(Expr::binop(Op::#op, #args))),
+            3 => tokens.extend(quote!(Expr::triop(Op::#op, #args))),
+            4 => tokens.extend(quote!(Expr::qop(Op::#op, #args))),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl ToTokens for ExprLoad {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (end, addr) = (&self.end, &self.addr);
+        let ty = type_ident(&self.ty);
+        tokens.extend(quote!(Expr::load(#end, Type::#ty, #addr)))
+    }
+}
+
+impl ExprConst {
+    /// The `Const` itself, for exits.
+    fn to_const(&self) -> TokenStream {
+        let co = &self.co;
+        match self.ty.to_string().as_str() {
+            "I1" => match self.co.base10_parse() {
+                Ok(0) => quote!(Const::u1(false)),
+                Ok(1) => quote!(Const::u1(true)),
+                _ => quote!(compile_error!("Const of type I1 must be 0 or 1")),
+            },
+            "I8" => quote!(Const::u8(#co)),
+            "I16" => quote!(Const::u16(#co)),
+            "I32" => quote!(Const::u32(#co)),
+            "I64" => quote!(Const::u64(#co)),
+            "F32" => quote!(Const::f32(f32::from_bits(#co))),
+            "F32i" => quote!(Const::f32i(#co)),
+            "F64" => quote!(Const::f64(f64::from_bits(#co))),
+            "F64i" => quote!(Const::f64i(#co)),
+            "V128" => quote!(Const::v128(#co)),
+            "V256" => quote!(Const::v256(#co)),
+            _ => quote!(compile_error!("Invalid type")),
+        }
+    }
+}
+
+impl ToTokens for ExprConst {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let co = self.to_const();
+        tokens.extend(quote!(Expr::const_(#co)));
+    }
+}
+
+impl ToTokens for ExprITE {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (cond, if_true, if_false) = (&self.cond, &self.if_true, &self.if_false);
+        tokens.extend(quote!(Expr::ite(#cond, #if_true, #if_false)))
+    }
+}
+
+impl ToTokens for ExprCCall {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (callee, args) = (&self.callee, &self.args);
+        let ty = type_ident(&self.ret_ty);
+        tokens.extend(quote!(Expr::ccall(#callee, Type::#ty, ExprVec::new(&[#args]))))
+    }
+}
+
+pub enum Stmt {
+    NoOp(StmtNoOp),
+    IMark(StmtIMark),
+    AbiHint(StmtAbiHint),
+    Put(StmtPut),
+    PutI(StmtPutI),
+    WrTmp(StmtWrTmp),
+    Store(StmtStore),
+    LoadG(StmtLoadG),
+    StoreG(StmtStoreG),
+    CAS(StmtCAS),
+    LLSC(StmtLLSC),
+    Dirty(StmtDirty),
+    MBE(StmtMBE),
+    Exit(StmtExit),
+}
+
+pub struct StmtNoOp {
+    _ir: kw::IR,
+    _dash: Token![-],
+    _no_op: kw::NoOp,
+}
+
+pub struct StmtIMark {
+    _imark: kw::IMark,
+    _paren: token::Paren,
+    info: Punctuated<LitInt, Token![,]>,
+}
+
+pub struct StmtAbiHint {
+    base: Expr,
+    len: LitInt,
+    nia: Expr,
+}
+
+pub struct StmtPut {
+    _put: kw::PUT,
+    _paren: token::Paren,
+    pub offset: Offset,
+    _eq: Token![=],
+    pub data: Expr,
+}
+
+pub struct StmtPutI {
+    descr: RegArray,
+    ix: Expr,
+    bias: SignedInt,
+    data: Expr,
+}
+
+pub struct StmtWrTmp {
+    tmp: Ident,
+    _eq: Token![=],
+    data: Expr,
+}
+
+pub struct StmtStore {
+    end: Endness,
+    _paren: token::Paren,
+    addr: Expr,
+    _eq: Token![=],
+    data: Expr,
+}
+
+/// `t = if-strict (guard) cvt(LDle(addr)) else alt`
+pub struct StmtLoadG {
+    dst: Ident,
+    guard: Expr,
+    cvt: Name,
+    end: Endness,
+    addr: Expr,
+    alt: Expr,
+}
+
+/// `if (guard) { STle(addr) = data }`
+pub struct StmtStoreG {
+    guard: Expr,
+    end: Endness,
+    addr: Expr,
+    data: Expr,
+}
+
+/// `t = CASle(addr::expd->data)` or `tHi,tLo = CASle(addr::expdHi,expdLo->dataHi,dataLo)`
+pub struct StmtCAS {
+    old_hi: Option<Ident>,
+    old_lo: Ident,
+    end: Endness,
+    addr: Expr,
+    expd_hi: Option<Expr>,
+    expd_lo: Expr,
+    data_hi: Option<Expr>,
+    data_lo: Expr,
+}
+
+/// `t = LDle-Linked(addr)` or `t = ( STle-Cond(addr) = data )`
+pub struct StmtLLSC {
+    result: Ident,
+    end: Endness,
+    addr: Expr,
+    store_data: Option<Expr>,
+}
+
+/// `t = DIRTY guard RdFX-mem(addr,size) MoFX-gst(off,size[,repsN,stepM]) ::: callee(args)`
+pub struct StmtDirty {
+    tmp: Option<Ident>,
+    guard: Expr,
+    m_fx: Option<(Ident, Expr, LitInt)>,
+    fx_state: Vec<FxState>,
+    callee: Callee,
+    args: Punctuated<Expr, Token![,]>,
+}
+
+pub struct FxState {
+    fx: Ident,
+    offset: LitInt,
+    size: LitInt,
+    n_repeats: Option<LitInt>,
+    repeat_len: Option<LitInt>,
+}
+
+/// `IR-Fence` or `IR-CancelReservation`
+pub struct StmtMBE {
+    event: Ident,
+}
+
+/// `if (guard) { PUT(offs_ip) = dst; exit-Kind }`
+pub struct StmtExit {
+    guard: Expr,
+    offs_ip: Offset,
+    dst: ExprConst,
+    kind: Ident,
+}
+
+impl Parse for Stmt {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::IR) {
+            if input.peek3(kw::NoOp) {
+                input.parse().map(Self::NoOp)
+            } else {
+                input.parse().map(Self::MBE)
+            }
+        } else if lookahead.peek(Token![-]) || lookahead.peek(kw::IMark) {
+            input.parse().map(Self::IMark)
+        } else if lookahead.peek(Token![=]) || lookahead.peek(kw::AbiHint) {
+            input.parse().map(Self::AbiHint)
+        } else if lookahead.peek(kw::PUT) {
+            input.parse().map(Self::Put)
+        } else if lookahead.peek(kw::PUTI) {
+            input.parse().map(Self::PutI)
+        } else if lookahead.peek(kw::STbe) || lookahead.peek(kw::STle) {
+            input.parse().map(Self::Store)
+        } else if lookahead.peek(kw::DIRTY) {
+            input.parse().map(Self::Dirty)
+        } else if lookahead.peek(Token![if]) {
+            // Both exits and guarded stores are `if (guard) { ... }`.
+            let ahead = input.fork();
+            ahead.parse::<Token![if]>()?;
+            let _guard;
+            parenthesized!(_guard in ahead);
+            let body;
+            braced!(body in ahead);
+            if body.peek(kw::PUT) {
+                input.parse().map(Self::Exit)
+            } else {
+                input.parse().map(Self::StoreG)
+            }
+        } else if lookahead.peek(Ident) && input.peek2(Token![,]) {
+            input.parse().map(Self::CAS)
+        } else if lookahead.peek(Ident) {
+            let ahead = input.fork();
+            ahead.parse::<Ident>()?;
+            ahead.parse::<Token![=]>()?;
+            if ahead.peek(Token![if]) {
+                input.parse().map(Self::LoadG)
+            } else if ahead.peek(kw::CASle) || ahead.peek(kw::CASbe) {
+                input.parse().map(Self::CAS)
+            } else if ((ahead.peek(kw::LDle) || ahead.peek(kw::LDbe)) && ahead.peek2(Token![-]))
+                || ahead.peek(token::Paren)
+            {
+                input.parse().map(Self::LLSC)
+            } else if ahead.peek(kw::DIRTY) {
+                input.parse().map(Self::Dirty)
+            } else {
+                input.parse().map(Self::WrTmp)
+            }
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for StmtNoOp {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            _ir: input.parse()?,
+            _dash: input.parse()?,
+            _no_op: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtIMark {
+    fn parse(input: ParseStream) -> Result<Self> {
+        while input.parse::<Token![-]>().is_ok() {}
+        let info;
+        let res = Self {
+            _imark: input.parse()?,
+            _paren: parenthesized!(info in input),
+            info: info.parse_terminated(LitInt::parse)?,
+        };
+        if res.info.len() != 3 {
+            return Err(Error::new(
+                res._imark.span,
+                "expected `IMark(addr, len, delta)`",
+            ));
+        }
+        while input.parse::<Token![-]>().is_ok() {}
+        Ok(res)
+    }
+}
+
+impl Parse for StmtAbiHint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        while input.parse::<Token![=]>().is_ok() {}
+        input.parse::<kw::AbiHint>()?;
+        let info;
+        parenthesized!(info in input);
+        let base = info.parse()?;
+        info.parse::<Token![,]>()?;
+        let len = info.parse()?;
+        info.parse::<Token![,]>()?;
+        let res = Self {
+            base,
+            len,
+            nia: info.parse()?,
+        };
+        if !info.is_empty() {
+            return Err(info.error("expected `)`"));
+        }
+        while input.parse::<Token![=]>().is_ok() {}
+        Ok(res)
+    }
+}
+
+impl Parse for StmtPut {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let offset;
+        Ok(Self {
+            _put: input.parse()?,
+            _paren: parenthesized!(offset in input),
+            offset: offset.parse()?,
+            _eq: input.parse()?,
+            data: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtPutI {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::PUTI>()?;
+        let descr = input.parse()?;
+        let index;
+        bracketed!(index in input);
+        let ix = index.parse()?;
+        index.parse::<Token![,]>()?;
+        let bias = index.parse()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self {
+            descr,
+            ix,
+            bias,
+            data: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtWrTmp {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            tmp: input.parse()?,
+            _eq: input.parse()?,
+            data: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtStore {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let addr;
+        Ok(Self {
+            end: Endness::parse_with(input, "ST")?,
+            _paren: parenthesized!(addr in input),
+            addr: addr.parse()?,
+            _eq: input.parse()?,
+            data: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtLoadG {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let dst = input.parse()?;
+        input.parse::<Token![=]>()?;
+        input.parse::<Token![if]>()?;
+        input.parse::<Token![-]>()?;
+        input.parse::<kw::strict>()?;
+        let guard;
+        parenthesized!(guard in input);
+        let guard = guard.parse()?;
+        let cvt = input.parse()?;
+        let (load, addr);
+        parenthesized!(load in input);
+        let end = Endness::parse_with(&load, "LD")?;
+        parenthesized!(addr in load);
+        let addr = addr.parse()?;
+        input.parse::<Token![else]>()?;
+        Ok(Self {
+            dst,
+            guard,
+            cvt,
+            end,
+            addr,
+            alt: input.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtStoreG {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![if]>()?;
+        let (guard, body, addr);
+        parenthesized!(guard in input);
+        braced!(body in input);
+        let end = Endness::parse_with(&body, "ST")?;
+        parenthesized!(addr in body);
+        body.parse::<Token![=]>()?;
+        Ok(Self {
+            guard: guard.parse()?,
+            end,
+            addr: addr.parse()?,
+            data: body.parse()?,
+        })
+    }
+}
+
+impl Parse for StmtCAS {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let first = input.parse()?;
+        let (old_hi, old_lo) = if input.parse::<Option<Token![,]>>()?.is_some() {
+            (Some(first), input.parse()?)
+        } else {
+            (None, first)
+        };
+        input.parse::<Token![=]>()?;
+        let end = Endness::parse_with(input, "CAS")?;
+        let args;
+        parenthesized!(args in input);
+        let addr = args.parse()?;
+        args.parse::<Token![::]>()?;
+        let (expd_hi, expd_lo) = hi_lo(&args)?;
+        args.parse::<Token![->]>()?;
+        let (data_hi, data_lo) = hi_lo(&args)?;
+        Ok(Self {
+            old_hi,
+            old_lo,
+            end,
+            addr,
+            expd_hi,
+            expd_lo,
+            data_hi,
+            data_lo,
+        })
+    }
+}
+
+// `lo` or `hi,lo`
+fn hi_lo(input: ParseStream) -> Result<(Option<Expr>, Expr)> {
+    let first = input.parse()?;
+    if input.parse::<Option<Token![,]>>()?.is_some() {
+        Ok((Some(first), input.parse()?))
+    } else {
+        Ok((None, first))
+    }
+}
+
+impl Parse for StmtLLSC {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let result = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let addr;
+        if input.peek(token::Paren) {
+            let store;
+            parenthesized!(store in input);
+            let end = Endness::parse_with(&store, "ST")?;
+            store.parse::<Token![-]>()?;
+            store.parse::<kw::Cond>()?;
+            parenthesized!(addr in store);
+            store.parse::<Token![=]>()?;
+            Ok(Self {
+                result,
+                end,
+                addr: addr.parse()?,
+                store_data: Some(store.parse()?),
+            })
+        } else {
+            let end = Endness::parse_with(input, "LD")?;
+            input.parse::<Token![-]>()?;
+            input.parse::<kw::Linked>()?;
+            parenthesized!(addr in input);
+            Ok(Self {
+                result,
+                end,
+                addr: addr.parse()?,
+                store_data: None,
+            })
+        }
+    }
+}
+
+impl Parse for StmtDirty {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let tmp = if input.peek(Ident) && input.peek2(Token![=]) {
+            let tmp = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(tmp)
+        } else {
+            None
+        };
+        input.parse::<kw::DIRTY>()?;
+        let guard = input.parse()?;
+        let mut m_fx = None;
+        let mut fx_state = Vec::new();
+        // `:::` is lexed as `::` followed by `:`
+        while !input.peek(Token![::]) {
+            let fx: Ident = input.parse()?;
+            input.parse::<Token![-]>()?;
+            let content;
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::mem) {
+                input.parse::<kw::mem>()?;
+                parenthesized!(content in input);
+                let addr = content.parse()?;
+                content.parse::<Token![,]>()?;
+                m_fx = Some((fx, addr, content.parse()?));
+            } else if lookahead.peek(kw::gst) {
+                input.parse::<kw::gst>()?;
+                parenthesized!(content in input);
+                let offset = content.parse()?;
+                content.parse::<Token![,]>()?;
+                let size = content.parse()?;
+                let (mut n_repeats, mut repeat_len) = (None, None);
+                if content.parse::<Option<Token![,]>>()?.is_some() {
+                    n_repeats = Some(prefixed_int(&content, "reps")?);
+                    content.parse::<Token![,]>()?;
+                    repeat_len = Some(prefixed_int(&content, "step")?);
+                }
+                fx_state.push(FxState {
+                    fx,
+                    offset,
+                    size,
+                    n_repeats,
+                    repeat_len,
+                });
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+        input.parse::<Token![::]>()?;
+        input.parse::<Token![:]>()?;
+        let callee = input.parse()?;
+        let args;
+        parenthesized!(args in input);
+        Ok(Self {
+            tmp,
+            guard,
+            m_fx,
+            fx_state,
+            callee,
+            args: args.parse_terminated(Expr::parse)?,
+        })
+    }
+}
+
+// An identifier like `reps4`, giving `4`.
+fn prefixed_int(input: ParseStream, prefix: &str) -> Result<LitInt> {
+    let ident: Ident = input.parse()?;
+    match ident.to_string().strip_prefix(prefix) {
+        Some(n) if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => {
+            Ok(LitInt::new(n, ident.span()))
+        }
+        _ => Err(Error::new(
+            ident.span(),
+            format!("expected `{}<number>`", prefix),
+        )),
+    }
+}
+
+fn effect_ident(fx: &Ident) -> TokenStream {
+    let effect = match fx.to_string().as_str() {
+        "noFX" => "Ifx_None",
+        "RdFX" => "Ifx_Read",
+        "WrFX" => "Ifx_Write",
+        "MoFX" => "Ifx_Modify",
+        _ => return Error::new(fx.span(), "unknown effect").to_compile_error(),
+    };
+    let effect = Ident::new(effect, fx.span());
+    quote!(Effect::#effect)
+}
+
+impl Parse for StmtMBE {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::IR>()?;
+        input.parse::<Token![-]>()?;
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::Fence) || lookahead.peek(kw::CancelReservation) {
+            Ok(Self {
+                event: input.parse()?,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for StmtExit {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![if]>()?;
+        let (guard, body, offs_ip);
+        parenthesized!(guard in input);
+        braced!(body in input);
+        body.parse::<kw::PUT>()?;
+        parenthesized!(offs_ip in body);
+        body.parse::<Token![=]>()?;
+        let dst = body.parse()?;
+        body.parse::<Token![;]>()?;
+        body.parse::<kw::exit>()?;
+        body.parse::<Token![-]>()?;
+        Ok(Self {
+            guard: guard.parse()?,
+            offs_ip: offs_ip.parse()?,
+            dst,
+            kind: body.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Stmt {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::NoOp(..) => tokens.extend(quote!(Stmt::no_op())),
+            Self::IMark(imark) => imark.to_tokens(tokens),
+            Self::AbiHint(hint) => hint.to_tokens(tokens),
+            Self::Put(put) => put.to_tokens(tokens),
+            Self::PutI(put_i) => put_i.to_tokens(tokens),
+            Self::WrTmp(wr_tmp) => wr_tmp.to_tokens(tokens),
+            Self::Store(store) => store.to_tokens(tokens),
+            Self::LoadG(load_g) => load_g.to_tokens(tokens),
+            Self::StoreG(store_g) => store_g.to_tokens(tokens),
+            Self::CAS(cas) => cas.to_tokens(tokens),
+            Self::LLSC(llsc) => llsc.to_tokens(tokens),
+            Self::Dirty(dirty) => dirty.to_tokens(tokens),
+            Self::MBE(mbe) => mbe.to_tokens(tokens),
+            Self::Exit(exit) => exit.to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for StmtIMark {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let info = &self.info;
+        tokens.extend(quote!(Stmt::imark(#info)))
+    }
+}
+
+impl ToTokens for StmtAbiHint {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let StmtAbiHint { base, len, nia } = self;
+        tokens.extend(quote!(Stmt::abi_hint(#base, #len, #nia)))
+    }
+}
+
+impl ToTokens for StmtPut {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (offset, data) = (&self.offset, &self.data);
+        tokens.extend(quote!(Stmt::put(#offset, #data)))
+    }
+}
+
+impl ToTokens for StmtPutI {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let StmtPutI {
+            descr,
+            ix,
+            bias,
+            data,
+        } = self;
+        tokens.extend(quote!(Stmt::put_i(#descr, #ix, #bias, #data)))
+    }
+}
+
+impl ToTokens for StmtWrTmp {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (tmp, data) = (&self.tmp, &self.data);
+        tokens.extend(quote!(Stmt::wr_tmp(#tmp, #data)))
+    }
+}
+
+impl ToTokens for StmtStore {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (end, addr, data) = (&self.end, &self.addr, &self.data);
+        tokens.extend(quote!(Stmt::store(#end, #addr, #data)))
+    }
+}
+
+impl ToTokens for StmtLoadG {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let StmtLoadG {
+            dst,
+            guard,
+            cvt,
+            end,
+            addr,
+            alt,
+        } = self;
+        let cvt = format_ident!("ILGop_{}", cvt.text, span = cvt.span);
+        tokens.extend(quote!(Stmt::load_g(#end, LoadGOp::#cvt, #dst, #addr, #alt, #guard)))
+    }
+}
+
+impl ToTokens for StmtStoreG {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let StmtStoreG {
+            guard,
+            end,
+            addr,
+            data,
+        } = self;
+        tokens.extend(quote!(Stmt::store_g(#end, #addr, #data, #guard)))
+    }
+}
+
+fn option<T: ToTokens>(value: &Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+impl ToTokens for StmtCAS {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let StmtCAS {
+            old_lo,
+            end,
+            addr,
+            expd_lo,
+            data_lo,
+            ..
+        } = self;
+        let old_hi = option(&self.old_hi);
+        let expd_hi = option(&self.expd_hi);
+        let data_hi = option(&self.data_hi);
+        tokens.extend(quote!(Stmt::cas(
+            #old_hi, #old_lo, #end, #addr, #expd_hi, #expd_lo, #data_hi, #data_lo
+        )))
+    }
+}
+
+impl ToTokens for StmtLLSC {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (end, result, addr) = (&self.end, &self.result, &self.addr);
+        let store_data = option(&self.store_data);
+        tokens.extend(quote!(Stmt::llsc(#end, #result, #addr, #store_data)))
+    }
+}
+
+impl ToTokens for StmtDirty {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (callee, guard, args) = (&self.callee, &self.guard, &self.args);
+        let tmp = option(&self.tmp);
+        let (m_fx, m_addr, m_size) = match &self.m_fx {
+            Some((fx, addr, size)) => (effect_ident(fx), quote!(Some(#addr)), quote!(#size)),
+            None => (quote!(Effect::Ifx_None), quote!(None), quote!(0)),
+        };
+        let fx_state = self.fx_state.iter().map(|fx_state| {
+            let fx = effect_ident(&fx_state.fx);
+            let (offset, size) = (&fx_state.offset, &fx_state.size);
+            let zero = || LitInt::new("0", Span::call_site());
+            let n_repeats = fx_state.n_repeats.clone().unwrap_or_else(zero);
+            let repeat_len = fx_state.repeat_len.clone().unwrap_or_else(zero);
+            quote!(FxState {
+                fx: #fx,
+                offset: #offset,
+                size: #size,
+                n_repeats: #n_repeats,
+                repeat_len: #repeat_len,
+            })
+        });
+        tokens.extend(quote!(Stmt::dirty(
+            #callee,
+            #guard,
+            ExprVec::new(&[#args]),
+            #tmp,
+            #m_fx,
+            #m_addr,
+            #m_size,
+            &[#(#fx_state),*],
+        )))
+    }
+}
+
+impl ToTokens for StmtMBE {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let event = format_ident!("Imbe_{}", self.event, span = self.event.span());
+        tokens.extend(quote!(Stmt::mbe(MBusEvent::#event)))
+    }
+}
+
+impl ToTokens for StmtExit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (guard, offs_ip) = (&self.guard, &self.offs_ip);
+        let dst = self.dst.to_const();
+        let jk = jump_kind_ident(&self.kind);
+        tokens.extend(quote!(Stmt::exit(#guard, JumpKind::#jk, #dst, #offs_ip)))
+    }
+}
+
+pub struct ExitKind {
+    _semi: Token![;],
+    _exit: kw::exit,
+    _dash: Token![-],
+    pub kind: Ident,
+}
+
+impl Parse for ExitKind {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            _semi: input.parse()?,
+            _exit: input.parse()?,
+            _dash: input.parse()?,
+            kind: input.parse()?,
+        })
+    }
+}
+
+pub struct IRSB {
+    pub arch: Option<ArchHeader>,
+    pub ty_env: TypeEnv,
+    pub stmts: Vec<Stmt>,
+    pub exit: ExitKind,
+}
+
+impl Parse for IRSB {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let arch = if input.peek(kw::arch) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        // ppIRSB wraps the block in `IRSB { ... }`
+        if input.peek(kw::IRSB) && input.peek2(token::Brace) {
+            input.parse::<kw::IRSB>()?;
+            let content;
+            braced!(content in input);
+            return IRSB::parse_body(&content, arch);
+        }
+        IRSB::parse_body(input, arch)
+    }
+}
+
+impl IRSB {
+    fn parse_body(input: ParseStream, arch: Option<ArchHeader>) -> Result<Self> {
+        let ty_env = input.parse()?;
+        let mut stmts = Vec::new();
+        while !(input.is_empty() || input.peek(Token![;])) {
+            stmts.push(input.parse()?);
+        }
+        Ok(IRSB {
+            arch,
+            ty_env,
+            stmts,
+            exit: input.parse()?,
+        })
+    }
+}
+
+impl Stmt {
+    /// The temps assigned by this statement.
+    pub fn defs(&self) -> Vec<&Ident> {
+        match self {
+            Self::WrTmp(wr_tmp) => vec![&wr_tmp.tmp],
+            Self::LoadG(load_g) => vec![&load_g.dst],
+            Self::CAS(cas) => cas.old_hi.iter().chain(Some(&cas.old_lo)).collect(),
+            Self::LLSC(llsc) => vec![&llsc.result],
+            Self::Dirty(dirty) => dirty.tmp.iter().collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// The block given to a fragment macro, which is anything up to the first comma.
+pub struct Block(TokenStream);
+
+impl Parse for Block {
+    fn parse(input: ParseStream) -> Result<Self> {
+        parse_until_comma(input).map(Self)
+    }
+}
+
+// A Rust expression given before the IR, without requiring syn's `full` feature to parse it.
+fn parse_until_comma(input: ParseStream) -> Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    while !input.peek(Token![,]) {
+        tokens.extend(Some(input.parse::<TokenTree>()?));
+    }
+    input.parse::<Token![,]>()?;
+    Ok(tokens)
+}
+
+impl ToTokens for Block {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let block = &self.0;
+        // The binding is hygienic, so it can't clash with the user's temps.
+        let irsb = Ident::new("irsb", Span::mixed_site());
+        tokens.extend(quote!(#[allow(unused_variables)] let #irsb: &IRSB = &#block;))
+    }
+}
+
+/// `irsb, [arch amd64;] expr`
+pub struct ExprFragment {
+    pub irsb: Block,
+    pub arch: Option<ArchHeader>,
+    pub expr: Expr,
+}
+
+impl Parse for ExprFragment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            irsb: input.parse()?,
+            arch: parse_arch(input)?,
+            expr: input.parse()?,
+        })
+    }
+}
+
+/// `irsb, [arch amd64;] [t0:I64 ...] stmt; stmt; ...`, where the `;`s are optional.
+pub struct StmtsFragment {
+    pub irsb: Block,
+    pub arch: Option<ArchHeader>,
+    pub ty_env: TypeEnv,
+    pub stmts: Vec<Stmt>,
+}
+
+impl Parse for StmtsFragment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let irsb = input.parse()?;
+        let arch = parse_arch(input)?;
+        let ty_env = input.parse()?;
+        let mut stmts = Vec::new();
+        while !input.is_empty() {
+            stmts.push(input.parse()?);
+            input.parse::<Option<Token![;]>>()?;
+        }
+        Ok(Self {
+            irsb,
+            arch,
+            ty_env,
+            stmts,
+        })
+    }
+}
+
+fn parse_arch(input: ParseStream) -> Result<Option<ArchHeader>> {
+    if input.peek(kw::arch) {
+        input.parse().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+impl ToTokens for ExprFragment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (irsb, arch, expr) = (&self.irsb, &self.arch, &self.expr);
+        tokens.extend(quote!({
+            #[allow(unused_imports)]
+            use libvex::ir::{
+                Callee, Const, Effect, Expr, ExprVec, FxState, IREndness, IRSB, JumpKind,
+                LoadGOp, MBusEvent, Op, RegArray, Stmt, Type,
+            };
+            #arch
+            #irsb
+            unsafe { #expr }
+        }))
+    }
+}
+
+fn new_tmp(irsb: &Ident, ty: vex::Type) -> TokenStream {
+    let ty = Ident::new(&format!("{:?}", ty), Span::call_site());
+    quote!(#irsb.type_env().new_tmp(Type::#ty))
+}
+
+impl StmtsFragment {
+    /// Add the statements to the block, allocating the temps they assign. `checker` is the
+    /// one that checked this fragment.
+    ///
+    /// The result is a `let` of the new temps, so that they are usable after the macro.
+    pub fn expand(&self, checker: &check::Checker) -> TokenStream {
+        let irsb = Ident::new("irsb", Span::mixed_site());
+        let mut body = quote!();
+        let mut tmps: Vec<&Ident> = Vec::new();
+        for (tmp, _colon, _ty) in &self.ty_env.tmps {
+            let ty = checker.tmp_type(tmp).unwrap();
+            let new_tmp = new_tmp(&irsb, ty);
+            body.extend(quote!(let #tmp = #new_tmp;));
+            tmps.push(tmp);
+        }
+        for stmt in &self.stmts {
+            for tmp in stmt.defs() {
+                if self
+                    .ty_env
+                    .tmps
+                    .iter()
+                    .any(|(declared, ..)| declared == tmp)
+                {
+                    continue;
+                }
+                tmps.retain(|&other| other != tmp);
+                tmps.push(tmp);
+                match (checker.tmp_type(tmp), stmt) {
+                    (Some(ty), _) => {
+                        let new_tmp = new_tmp(&irsb, ty);
+                        body.extend(quote!(let #tmp = #new_tmp;));
+                    }
+                    // The checker only leaves the type of an assigned temp unknown when it
+                    // can be found at runtime.
+                    (None, Stmt::WrTmp(wr_tmp)) => {
+                        let (data, value) = (Ident::new("data", Span::mixed_site()), &wr_tmp.data);
+                        body.extend(quote!(
+                            let #data = #value;
+                            let #tmp = #irsb.type_env().new_tmp(#irsb.type_env().type_of_expr(#data));
+                            #irsb.add_stmt(Stmt::wr_tmp(#tmp, #data));
+                        ));
+                    }
+                    (None, _) => unreachable!(),
+                }
+            }
+            if !matches!(stmt, Stmt::WrTmp(wr_tmp) if checker.tmp_type(&wr_tmp.tmp).is_none()) {
+                body.extend(quote!(#irsb.add_stmt(#stmt);));
+            }
+        }
+        let (irsb_block, arch) = (&self.irsb, &self.arch);
+        quote!(
+            #[allow(unused_variables)]
+            let (#(#tmps,)*) = unsafe {
+                #[allow(unused_imports)]
+                use libvex::ir::{
+                    Callee, Const, Effect, Expr, ExprVec, FxState, IREndness, IRSB, JumpKind,
+                    LoadGOp, MBusEvent, Op, RegArray, Stmt, Type,
+                };
+                #arch
+                #irsb_block
+                #body
+                (#(#tmps,)*)
+            };
+        )
+    }
+}
@@ -0,0 +1,696 @@
+//! Compile time type checking of the IR given to the macros, so that mistakes are reported at
+//! the offending token instead of by VEX's sanity checks at runtime.
+
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use proc_macro2::Span;
+use syn::{Error, Ident, Result};
+
+use super::vex::{Type, ALL_OPS, ALL_TYPES};
+use super::*;
+
+lazy_static! {
+    // By their names as printed by VEX (and so by `IRSB`'s `Display`), e.g. `Add64`, as
+    // `(C name, result type, argument types)`.
+    static ref OPS: HashMap<&'static str, (&'static str, Type, &'static [Type])> = ALL_OPS
+        .iter()
+        .map(|&(name, c_name, ret, args)| (name, (c_name, ret, args)))
+        .collect();
+    static ref TYPES: HashMap<String, Type> =
+        ALL_TYPES.iter().map(|&ty| (type_name(ty), ty)).collect();
+}
+
+fn type_name(ty: Type) -> String {
+    format!("{:?}", ty)["Ity_".len()..].to_owned()
+}
+
+/// The `IROp` variant of the op VEX prints as `name`, e.g. `Iop_Add64` for `Add64`.
+pub fn op_ident(name: &str, span: Span) -> Ident {
+    match OPS.get(name) {
+        Some(&(c_name, ..)) => Ident::new(c_name, span),
+        // Unknown ops are reported by the checker.
+        None => format_ident!("Iop_{}", name, span = span),
+    }
+}
+
+fn is_addr(ty: Type) -> bool {
+    matches!(ty, Type::Ity_I32 | Type::Ity_I64)
+}
+
+/// Type checks IR, collecting every error it finds.
+///
+/// Expressions whose type can't be known (binders, `VECRET` and `GSPTR`, or anything that
+/// already had an error) are given the type `None`, and aren't checked any further.
+///
+/// In a fragment (see `ir_stmts!`), temps that aren't declared are Rust variables holding
+/// temps of the block, whose types are unknown, and assigned temps are new temps whose types
+/// are inferred.
+///
+/// In a pattern (see `ir_match!`), temps are Rust variables too, and captures are allowed.
+pub struct Checker {
+    tmps: HashMap<String, Type>,
+    has_arch: bool,
+    fragment: bool,
+    captures: Option<HashSet<String>>,
+    errors: Option<Error>,
+}
+
+impl Checker {
+    /// `has_arch` is whether there's an [ArchHeader], so that registers can be named.
+    pub fn new(has_arch: bool) -> Self {
+        Self {
+            tmps: HashMap::new(),
+            has_arch,
+            fragment: false,
+            captures: None,
+            errors: None,
+        }
+    }
+
+    pub fn fragment(has_arch: bool) -> Self {
+        Self {
+            fragment: true,
+            ..Self::new(has_arch)
+        }
+    }
+
+    pub fn pattern(has_arch: bool) -> Self {
+        Self {
+            captures: Some(HashSet::new()),
+            ..Self::fragment(has_arch)
+        }
+    }
+
+    pub fn error(&mut self, span: Span, message: impl std::fmt::Display) {
+        let error = Error::new(span, message);
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        self.errors.map_or(Ok(()), Err)
+    }
+
+    pub fn ty(&mut self, ty: &Ident) -> Option<Type> {
+        let found = TYPES.get(&ty.to_string()).copied();
+        if found.is_none() {
+            self.error(ty.span(), format!("unknown type `{}`", ty));
+        }
+        found
+    }
+
+    pub fn declare(&mut self, tmp: &Ident, ty: &Ident) {
+        if let Some(ty) = self.ty(ty) {
+            if self.tmps.insert(tmp.to_string(), ty).is_some() {
+                self.error(tmp.span(), format!("`{}` is declared more than once", tmp));
+            }
+        }
+    }
+
+    /// The type of a declared (or inferred) temp.
+    pub fn tmp_type(&self, tmp: &Ident) -> Option<Type> {
+        self.tmps.get(&tmp.to_string()).copied()
+    }
+
+    fn tmp(&mut self, tmp: &Ident) -> Option<Type> {
+        let found = self.tmp_type(tmp);
+        if found.is_none() && !self.fragment {
+            self.error(tmp.span(), format!("undeclared temporary `{}`", tmp));
+        }
+        found
+    }
+
+    // An assigned temp. In a fragment, an undeclared temp gets the `inferred` type, which
+    // may only be unknown if it can be found at runtime.
+    fn def(&mut self, tmp: &Ident, inferred: Option<Type>, at_runtime: bool) -> Option<Type> {
+        if !self.fragment || self.tmp_type(tmp).is_some() {
+            return self.tmp(tmp);
+        }
+        match inferred {
+            Some(ty) => {
+                self.tmps.insert(tmp.to_string(), ty);
+            }
+            None if !at_runtime => self.error(
+                tmp.span(),
+                format!(
+                    "can't infer the type of `{0}`, declare it as `{0}:<type>`",
+                    tmp
+                ),
+            ),
+            None => {}
+        }
+        inferred
+    }
+
+    fn offset(&mut self, offset: &Offset) {
+        match offset {
+            Offset::Reg(reg) if !self.has_arch => self.error(
+                reg.span(),
+                "naming registers requires an `arch` header, such as `arch amd64;`",
+            ),
+            Offset::Capture(dollar, name) => self.capture(dollar.span, name),
+            _ => {}
+        }
+    }
+
+    fn capture(&mut self, span: Span, name: &Ident) {
+        match &mut self.captures {
+            Some(captures) => {
+                if !captures.insert(name.to_string()) {
+                    self.error(
+                        name.span(),
+                        format!("`${}` is captured more than once", name),
+                    );
+                }
+            }
+            None => self.error(span, "captures are only allowed in `ir_match!`"),
+        }
+    }
+
+    // The type of the captured value, which is only known for constants.
+    fn capture_kind(&mut self, capture: &Capture) -> Option<Type> {
+        self.capture(capture.dollar.span, &capture.name);
+        let kind = capture.kind.as_ref()?;
+        match kind.to_string().as_str() {
+            "Const" | "Temp" => None,
+            "I1" | "I8" | "I16" | "I32" | "I64" => self.ty(kind),
+            _ => {
+                self.error(
+                    kind.span(),
+                    format!(
+                        "unknown capture kind `{}`, expected `Const`, `Temp` or an integer type",
+                        kind
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    fn expect_type(&mut self, span: Span, found: Option<Type>, expected: Option<Type>) {
+        if let (Some(found), Some(expected)) = (found, expected) {
+            if found != expected {
+                self.error(
+                    span,
+                    format!(
+                        "expected {}, found {}",
+                        type_name(expected),
+                        type_name(found)
+                    ),
+                );
+            }
+        }
+    }
+
+    fn expect(&mut self, expr: &Expr, expected: Option<Type>) {
+        let found = self.expr(expr);
+        self.expect_type(expr.span(), found, expected);
+    }
+
+    fn guard(&mut self, guard: &Expr) {
+        self.expect(guard, Some(Type::Ity_I1));
+    }
+
+    fn addr(&mut self, addr: &Expr) {
+        if let Some(ty) = self.expr(addr) {
+            if !is_addr(ty) {
+                self.error(
+                    addr.span(),
+                    format!("expected an address (I32 or I64), found {}", type_name(ty)),
+                );
+            }
+        }
+    }
+
+    // Data that is stored to memory or to the guest state.
+    fn data(&mut self, data: &Expr) {
+        if self.expr(data) == Some(Type::Ity_I1) {
+            self.error(data.span(), "I1 values can't be stored");
+        }
+    }
+
+    fn constant(&mut self, co: &ExprConst) -> Option<Type> {
+        let name = co.ty.to_string();
+        let braced = BRACED_CONSTS.contains(&name.as_str());
+        if braced != co.brace.is_some() {
+            let form = if braced {
+                format!("{}{{0x..}}", name)
+            } else {
+                format!("0x..:{}", name)
+            };
+            self.error(
+                co.ty.span(),
+                format!("constants of type {} are written `{}`", name, form),
+            );
+            return None;
+        }
+        // Floats and vectors are given as bits. The bits of a V128 (V256) are bytes (words).
+        let (ty, bits) = match name.as_str() {
+            "F32i" => (Type::Ity_F32, 32),
+            "F64i" => (Type::Ity_F64, 64),
+            _ => {
+                let ty = self.ty(&co.ty)?;
+                let bits = match ty {
+                    Type::Ity_I1 => 1,
+                    Type::Ity_I8 => 8,
+                    Type::Ity_I16 | Type::Ity_V128 => 16,
+                    Type::Ity_I32 | Type::Ity_F32 | Type::Ity_V256 => 32,
+                    Type::Ity_I64 | Type::Ity_F64 => 64,
+                    _ => {
+                        self.error(co.ty.span(), format!("constants can't have type {}", name));
+                        return None;
+                    }
+                };
+                (ty, bits)
+            }
+        };
+        match co.co.base10_parse::<u64>() {
+            Ok(value) if bits == 64 || value >> bits == 0 => {}
+            Ok(_) => self.error(co.co.span(), format!("constant doesn't fit in {}", name)),
+            Err(err) => self.error(co.co.span(), err),
+        }
+        Some(ty)
+    }
+
+    pub fn expr(&mut self, expr: &Expr) -> Option<Type> {
+        if self.captures.is_some()
+            && matches!(
+                expr,
+                Expr::Binder(_) | Expr::GetI(_) | Expr::CCall(_) | Expr::VecRet(_) | Expr::GsPtr(_)
+            )
+        {
+            self.error(
+                expr.span(),
+                "only GET, loads, ITE, ops, constants and temps can be matched",
+            );
+            return None;
+        }
+        match expr {
+            Expr::Binder(_) | Expr::VecRet(_) | Expr::GsPtr(_) => None,
+            Expr::Get(get) => {
+                self.offset(&get.offset);
+                self.ty(&get.size)
+            }
+            Expr::GetI(get_i) => {
+                self.expect(&get_i.ix, Some(Type::Ity_I32));
+                self.ty(&get_i.descr.elem_ty)
+            }
+            Expr::RdTmp(tmp) => self.tmp(tmp),
+            Expr::Op(op) => self.op(op),
+            Expr::Load(load) => {
+                self.addr(&load.addr);
+                self.ty(&load.ty)
+            }
+            Expr::Const(co) => self.constant(co),
+            Expr::ITE(ite) => {
+                self.guard(&ite.cond);
+                let ty = self.expr(&ite.if_true);
+                self.expect(&ite.if_false, ty);
+                ty
+            }
+            Expr::CCall(ccall) => {
+                for arg in &ccall.args {
+                    self.expr(arg);
+                }
+                self.ty(&ccall.ret_ty)
+            }
+            Expr::Capture(capture) => self.capture_kind(capture),
+            Expr::Wildcard(wildcard) => {
+                if self.captures.is_none() {
+                    self.error(wildcard.span, "`_` is only allowed in `ir_match!`");
+                }
+                None
+            }
+        }
+    }
+
+    fn op(&mut self, op: &ExprOp) -> Option<Type> {
+        let (ret, args) = match OPS.get(op.op.text.as_str()) {
+            Some(&(_, ret, args)) => (ret, args),
+            None => {
+                self.error(op.op.span, format!("unknown op `{}`", op.op.text));
+                for arg in &op.args {
+                    self.expr(arg);
+                }
+                return None;
+            }
+        };
+        if args.len() != op.args.len() {
+            self.error(
+                op.op.span,
+                format!(
+                    "`{}` takes {} arguments, found {}",
+                    op.op.text,
+                    args.len(),
+                    op.args.len()
+                ),
+            );
+        }
+        for (arg, &expected) in op.args.iter().zip(args) {
+            self.expect(arg, Some(expected));
+        }
+        Some(ret)
+    }
+
+    pub fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::NoOp(_) | Stmt::IMark(_) | Stmt::MBE(_) => {}
+            Stmt::AbiHint(hint) => {
+                self.addr(&hint.base);
+                self.addr(&hint.nia);
+            }
+            Stmt::Put(put) => {
+                self.offset(&put.offset);
+                self.data(&put.data);
+            }
+            Stmt::PutI(put_i) => {
+                self.expect(&put_i.ix, Some(Type::Ity_I32));
+                let ty = self.ty(&put_i.descr.elem_ty);
+                self.expect(&put_i.data, ty);
+            }
+            Stmt::WrTmp(wr_tmp) => {
+                let found = self.expr(&wr_tmp.data);
+                let ty = self.def(&wr_tmp.tmp, found, true);
+                self.expect_type(wr_tmp.data.span(), found, ty);
+            }
+            Stmt::Store(store) => {
+                self.addr(&store.addr);
+                self.data(&store.data);
+            }
+            Stmt::LoadG(load_g) => self.load_g(load_g),
+            Stmt::StoreG(store_g) => {
+                self.guard(&store_g.guard);
+                self.addr(&store_g.addr);
+                self.data(&store_g.data);
+            }
+            Stmt::CAS(cas) => {
+                self.addr(&cas.addr);
+                let found = self.expr(&cas.expd_lo);
+                let ty = self.def(&cas.old_lo, found, false);
+                self.expect_type(cas.expd_lo.span(), found, ty);
+                self.expect(&cas.data_lo, ty);
+                if let Some(old_hi) = &cas.old_hi {
+                    let ty = self.def(old_hi, ty, false);
+                    for expr in cas.expd_hi.iter().chain(&cas.data_hi) {
+                        self.expect(expr, ty);
+                    }
+                }
+            }
+            Stmt::LLSC(llsc) => {
+                self.addr(&llsc.addr);
+                if let Some(store_data) = &llsc.store_data {
+                    let ty = self.def(&llsc.result, Some(Type::Ity_I1), false);
+                    self.expect_type(llsc.result.span(), ty, Some(Type::Ity_I1));
+                    self.data(store_data);
+                } else {
+                    self.def(&llsc.result, None, false);
+                }
+            }
+            Stmt::Dirty(dirty) => {
+                self.guard(&dirty.guard);
+                if let Some(tmp) = &dirty.tmp {
+                    self.def(tmp, None, false);
+                }
+                if let Some((_, addr, _)) = &dirty.m_fx {
+                    self.addr(addr);
+                }
+                for arg in &dirty.args {
+                    self.expr(arg);
+                }
+            }
+            Stmt::Exit(exit) => {
+                self.guard(&exit.guard);
+                self.offset(&exit.offs_ip);
+                if let Some(ty) = self.constant(&exit.dst) {
+                    if !is_addr(ty) {
+                        self.error(exit.dst.ty.span(), "exits must go to an address");
+                    }
+                }
+            }
+        }
+    }
+
+    fn load_g(&mut self, load_g: &StmtLoadG) {
+        self.guard(&load_g.guard);
+        self.addr(&load_g.addr);
+        let ty = match load_g.cvt.text.as_str() {
+            "IdentV128" => Type::Ity_V128,
+            "Ident64" => Type::Ity_I64,
+            "Ident32" | "16Uto32" | "16Sto32" | "8Uto32" | "8Sto32" => Type::Ity_I32,
+            _ => {
+                self.error(
+                    load_g.cvt.span,
+                    format!("unknown load conversion `{}`", load_g.cvt.text),
+                );
+                return;
+            }
+        };
+        let dst = self.def(&load_g.dst, Some(ty), false);
+        self.expect_type(load_g.dst.span(), dst, Some(ty));
+        self.expect(&load_g.alt, Some(ty));
+    }
+}
+
+/// Type check a whole block.
+pub fn check(irsb: &IRSB) -> Result<()> {
+    let mut checker = Checker::new(irsb.arch.is_some());
+    for (tmp, _colon, ty) in &irsb.ty_env.tmps {
+        checker.declare(tmp, ty);
+    }
+    let (next, stmts) = match irsb.stmts.split_last() {
+        Some((Stmt::Put(put), stmts)) => {
+            checker.offset(&put.offset);
+            (&put.data, stmts)
+        }
+        // The macro reports a missing `next`.
+        _ => return checker.finish(),
+    };
+    for stmt in stmts {
+        checker.stmt(stmt);
+    }
+    let word = checker.expr(next);
+    if let Some(word) = word {
+        if !is_addr(word) {
+            checker.error(next.span(), "the next address must be an I32 or an I64");
+        }
+        // All the exits of a block go to addresses of the same size.
+        for stmt in stmts {
+            if let Stmt::Exit(exit) = stmt {
+                let dst = TYPES.get(&exit.dst.ty.to_string()).copied();
+                checker.expect_type(exit.dst.co.span(), dst, Some(word));
+            }
+        }
+    }
+    checker.finish()
+}
+
+/// Type check an `ir_expr!` fragment.
+pub fn check_expr(fragment: &ExprFragment) -> Result<()> {
+    let mut checker = Checker::fragment(fragment.arch.is_some());
+    checker.expr(&fragment.expr);
+    checker.finish()
+}
+
+/// Type check an `ir_stmts!` fragment. The checker is returned for the types of the new temps.
+pub fn check_stmts(fragment: &StmtsFragment) -> Result<Checker> {
+    let mut checker = Checker::fragment(fragment.arch.is_some());
+    for (tmp, _colon, ty) in &fragment.ty_env.tmps {
+        checker.declare(tmp, ty);
+    }
+    for stmt in &fragment.stmts {
+        checker.stmt(stmt);
+    }
+    match checker.errors.take() {
+        Some(errors) => Err(errors),
+        None => Ok(checker),
+    }
+}
+
+/// Type check an `ir_match!` pattern.
+pub fn check_match(pattern: &Match) -> Result<()> {
+    let mut checker = Checker::pattern(pattern.arch.is_some());
+    checker.expr(&pattern.pattern);
+    checker.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+
+    use super::*;
+
+    fn errors(tokens: proc_macro2::TokenStream) -> Vec<String> {
+        let irsb = syn::parse2(tokens).unwrap();
+        match check(&irsb) {
+            Ok(()) => vec![],
+            Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn valid() {
+        let errors = errors(quote! {
+            t0:I64   t1:I32   t2:I1
+
+            ------ IMark(0x1000, 4, 0) ------
+            t0 = GET:I64(48)
+            t1 = 64to32(t0)
+            t2 = CmpEQ64(t0,0x0:I64)
+            STle(t0) = t1
+            if (t2) { PUT(184) = 0x1004:I64; exit-Boring }
+            PUT(184) = 32Uto64(t1); exit-Return
+        });
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn registers() {
+        let unnamed = errors(quote! {
+            PUT(rax) = GET:I64(rsp)
+            PUT(184) = 0x0:I64; exit-Boring
+        });
+        assert_eq!(
+            unnamed,
+            [
+                "naming registers requires an `arch` header, such as `arch amd64;`",
+                "naming registers requires an `arch` header, such as `arch amd64;`",
+            ]
+        );
+
+        let named = errors(quote! {
+            arch amd64;
+            PUT(rax) = GET:I64(rsp)
+            PUT(rip) = 0x0:I64; exit-Boring
+        });
+        assert!(named.is_empty(), "{:?}", named);
+
+        assert!(syn::parse2::<IRSB>(quote!(arch amd65; PUT(184) = 0x0:I64; exit-Boring)).is_err());
+    }
+
+    #[test]
+    fn constants() {
+        let errors = errors(quote! {
+            t0:F64   t1:V128
+
+            t0 = AddF64(0x0:I32,F64{0x3ff0000000000000},F64i{0x0})
+            PUT(216) = V128{0xff00}
+            STle(0x1000:I64) = F32{0x3f800000}
+            t0 = AddF64(0x0:I32,t0,0x0:F64)
+            t1 = V128{0x10000}
+            PUT(184) = 0x0:I64; exit-Boring
+        });
+        assert_eq!(
+            errors,
+            [
+                "constants of type F64 are written `F64{0x..}`",
+                "constant doesn't fit in V128",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let errors = errors(quote! {
+            t0:I64   t1:I32   t2:I65   t1:I32
+
+            t1 = Add64(t0,t0)
+            t0 = Add64(t0)
+            t3 = GET:I64(16)
+            STle(t1) = CmpEQ64(t0,t0)
+            PUT(16) = 0x100:I8
+            if (t0) { PUT(184) = 0x10:I32; exit-Boring }
+            PUT(184) = t0; exit-Boring
+        });
+        assert_eq!(
+            errors,
+            [
+                "unknown type `I65`",
+                "`t1` is declared more than once",
+                "expected I32, found I64",
+                "`Add64` takes 2 arguments, found 1",
+                "undeclared temporary `t3`",
+                "I1 values can't be stored",
+                "constant doesn't fit in I8",
+                "expected I1, found I64",
+                "expected I64, found I32",
+            ]
+        );
+    }
+
+    #[test]
+    fn fragments() {
+        let check = |tokens| match check_stmts(&syn::parse2(tokens).unwrap()) {
+            Ok(_) => vec![],
+            Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+        };
+        assert!(check(quote!(irsb, t9 = LDle:I64(t3); STle(t3) = t9)).is_empty());
+        assert_eq!(
+            check(
+                quote!(irsb, t0 = Add64(t1,0x1:I32) t2 = DIRTY 1:I1 ::: f{0x1000}() PUT(rax) = t0)
+            ),
+            [
+                "expected I64, found I32",
+                "can't infer the type of `t2`, declare it as `t2:<type>`",
+                "naming registers requires an `arch` header, such as `arch amd64;`",
+            ]
+        );
+
+        let checker = check_stmts(
+            &syn::parse2(quote!(irsb, t0:I32 t0 = 64to32(t1) t1 = t0 t2 = t3)).unwrap(),
+        );
+        let checker = checker.ok().unwrap();
+        let ty = |tmp| checker.tmp_type(&Ident::new(tmp, Span::call_site()));
+        assert_eq!(
+            (ty("t0"), ty("t1"), ty("t2")),
+            (Some(Type::Ity_I32), Some(Type::Ity_I32), None)
+        );
+
+        let expr = syn::parse2(quote!(irsb.clone(), Add64(GET:I64(16),0x8:I32))).unwrap();
+        assert_eq!(
+            check_expr(&expr).unwrap_err().to_string(),
+            "expected I64, found I32"
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        let check = |tokens| match check_match(&syn::parse2(tokens).unwrap()) {
+            Ok(()) => vec![],
+            Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+        };
+        assert!(check(quote!(expr, Add64(GET:I64($off), $c:Const))).is_empty());
+        assert!(check(quote!(expr, ITE(_, LDle:I64(t3), $c:I64))).is_empty());
+        assert_eq!(
+            check(quote!(expr, Add64($a:I32, Sub64($a, $b:Foo)))),
+            [
+                "expected I64, found I32",
+                "`$a` is captured more than once",
+                "unknown capture kind `Foo`, expected `Const`, `Temp` or an integer type",
+            ]
+        );
+        assert_eq!(
+            check(quote!(expr, Add64(GETI(136:8xI64)[t3,0], GET:I64(rsp)))),
+            [
+                "only GET, loads, ITE, ops, constants and temps can be matched",
+                "naming registers requires an `arch` header, such as `arch amd64;`",
+            ]
+        );
+
+        let irsb = quote! {
+            t0:I64
+            t0 = Add64($a, _)
+            PUT($ip) = t0; exit-Boring
+        };
+        assert_eq!(
+            errors(irsb),
+            [
+                "captures are only allowed in `ir_match!`",
+                "captures are only allowed in `ir_match!`",
+                "`_` is only allowed in `ir_match!`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! `ir_match!`, which matches an `Expr` against a pattern in the textual IR, and yields what
+//! the pattern captures.
+
+use quote::quote_spanned;
+
+use super::*;
+
+// Quotes with mixed site hygiene, so that the bindings in the generated code can't shadow
+// the user's temps (and the user's captures can't shadow them).
+macro_rules! hygienic {
+    ($($tt:tt)*) => {
+        quote_spanned!(Span::mixed_site()=> $($tt)*)
+    };
+}
+
+/// `expr, [arch amd64;] pattern`
+pub struct Match {
+    pub expr: TokenStream,
+    pub arch: Option<ArchHeader>,
+    pub pattern: Expr,
+}
+
+impl Parse for Match {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            expr: parse_until_comma(input)?,
+            arch: parse_arch(input)?,
+            pattern: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Match {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut captures = Vec::new();
+        self.pattern.captures(&mut captures);
+        let captures = match captures.as_slice() {
+            [capture] => quote!(#capture),
+            captures => quote!((#(#captures),*)),
+        };
+        let mut matcher = Matcher(0);
+        let expr = matcher.scrutinee();
+        let body = matcher.expr(&self.pattern, &expr, quote!(Some(#captures)));
+        let (value, arch) = (&self.expr, &self.arch);
+        tokens.extend(hygienic!({
+            let #expr = #value;
+            #[allow(unused_imports)]
+            use libvex::ir::{ConstEnum, ExprEnum, IREndness, Op, Type};
+            #arch
+            #body
+        }))
+    }
+}
+
+impl Expr {
+    // The names of the captures, in order.
+    fn captures<'a>(&'a self, captures: &mut Vec<&'a Ident>) {
+        match self {
+            Self::Capture(capture) => captures.push(&capture.name),
+            Self::Get(ExprGet {
+                offset: Offset::Capture(_, name),
+                ..
+            }) => captures.push(name),
+            Self::Op(op) => op.args.iter().for_each(|arg| arg.captures(captures)),
+            Self::Load(load) => load.addr.captures(captures),
+            Self::ITE(ite) => {
+                ite.cond.captures(captures);
+                ite.if_true.captures(captures);
+                ite.if_false.captures(captures);
+            }
+            _ => {}
+        }
+    }
+}
+
+// Generates nested matches, each evaluating to `None` if its expression doesn't match, and
+// to `then` (the match of the rest of the pattern) otherwise.
+struct Matcher(usize);
+
+impl Matcher {
+    // A new variable for a matched expression.
+    fn scrutinee(&mut self) -> Ident {
+        self.0 += 1;
+        Ident::new(&format!("expr{}", self.0), Span::mixed_site())
+    }
+
+    fn expr(&mut self, pattern: &Expr, expr: &Ident, then: TokenStream) -> TokenStream {
+        let (arm, then) = match pattern {
+            Expr::Wildcard(_) => return then,
+            Expr::Capture(Capture {
+                name, kind: None, ..
+            }) => return hygienic!({ let #name = #expr; #then }),
+            Expr::Capture(Capture {
+                name,
+                kind: Some(kind),
+                ..
+            }) => match kind.to_string().as_str() {
+                "Const" => (hygienic!(ExprEnum::Const(#name)), then),
+                "Temp" => (
+                    hygienic!(ExprEnum::RdTmp(tmp)),
+                    hygienic!({ let #name = tmp.tmp(); #then }),
+                ),
+                ty => {
+                    let value = const_variant(ty);
+                    (
+                        hygienic!(ExprEnum::Const(co)),
+                        hygienic!(match co.as_enum() {
+                            ConstEnum::#value(#name) => #then,
+                            _ => None,
+                        }),
+                    )
+                }
+            },
+            Expr::RdTmp(tmp) => (hygienic!(ExprEnum::RdTmp(tmp) if tmp.tmp() == #tmp), then),
+            Expr::Get(get) => {
+                let ty = type_ident(&get.size);
+                match &get.offset {
+                    Offset::Capture(_, name) => (
+                        hygienic!(ExprEnum::Get(get) if get.ty() == Type::#ty),
+                        hygienic!({ let #name = get.offset(); #then }),
+                    ),
+                    offset => (
+                        hygienic!(ExprEnum::Get(get) if get.ty() == Type::#ty && get.offset() == #offset),
+                        then,
+                    ),
+                }
+            }
+            Expr::Load(load) => {
+                let (end, ty) = (&load.end, type_ident(&load.ty));
+                let addr = self.scrutinee();
+                let then = self.expr(&load.addr, &addr, then);
+                (
+                    hygienic!(ExprEnum::Load(load) if load.end() == #end && load.ty() == Type::#ty),
+                    hygienic!({
+                        #[allow(unused_variables)]
+                        let #addr = load.addr();
+                        #then
+                    }),
+                )
+            }
+            Expr::Op(op) => {
+                let name = super::check::op_ident(&op.op.text, op.op.span);
+                let (variant, getters) = match op.args.len() {
+                    1 => ("Unop", &["arg"][..]),
+                    2 => ("Binop", &["arg1", "arg2"][..]),
+                    3 => ("Triop", &["arg1", "arg2", "arg3"][..]),
+                    4 => ("Qop", &["arg1", "arg2", "arg3", "arg4"][..]),
+                    _ => unreachable!(),
+                };
+                let variant = Ident::new(variant, Span::call_site());
+                let getters = getters
+                    .iter()
+                    .map(|getter| Ident::new(getter, Span::call_site()));
+                let args: Vec<_> = op.args.iter().map(|_| self.scrutinee()).collect();
+                let then = self.exprs(op.args.iter().zip(&args), then);
+                (
+                    hygienic!(ExprEnum::#variant(op) if op.op() == Op::#name),
+                    hygienic!({ #(#[allow(unused_variables)] let #args = op.#getters();)* #then }),
+                )
+            }
+            Expr::ITE(ite) => {
+                let args = [self.scrutinee(), self.scrutinee(), self.scrutinee()];
+                let patterns = [&*ite.cond, &*ite.if_true, &*ite.if_false];
+                let then = self.exprs(patterns.iter().copied().zip(&args), then);
+                let [cond, if_true, if_false] = &args;
+                (
+                    hygienic!(ExprEnum::ITE(ite)),
+                    hygienic!({
+                        #[allow(unused_variables)]
+                        let (#cond, #if_true, #if_false) = (ite.cond(), ite.if_true(), ite.if_false());
+                        #then
+                    }),
+                )
+            }
+            Expr::Const(co) => {
+                let value = &co.co;
+                let ty = co.ty.to_string();
+                let variant = const_variant(&ty);
+                let guard = match ty.as_str() {
+                    "I1" => {
+                        let value = value.base10_digits() != "0";
+                        hygienic!(value == #value)
+                    }
+                    "F32" | "F64" => hygienic!(value.to_bits() == #value),
+                    // All the lanes of a vector constant are the same.
+                    "V128" => {
+                        hygienic!(unsafe { value.w16[0] } == #value)
+                    }
+                    "V256" => {
+                        hygienic!(unsafe { value.w32[0] } == #value)
+                    }
+                    _ => hygienic!(value == #value),
+                };
+                (
+                    hygienic!(ExprEnum::Const(co)
+                        if matches!(co.as_enum(), ConstEnum::#variant(value) if #guard)),
+                    then,
+                )
+            }
+            // The checker rejects everything else.
+            _ => unreachable!(),
+        };
+        hygienic!(match #expr.as_enum() {
+            #arm => #then,
+            _ => None,
+        })
+    }
+
+    // Matches each expression in turn.
+    fn exprs<'a>(
+        &mut self,
+        patterns: impl DoubleEndedIterator<Item = (&'a Expr, &'a Ident)>,
+        then: TokenStream,
+    ) -> TokenStream {
+        patterns
+            .rev()
+            .fold(then, |then, (pattern, expr)| self.expr(pattern, expr, then))
+    }
+}
+
+// The `ConstEnum` variant of constants of the given type.
+fn const_variant(ty: &str) -> Ident {
+    let variant = match ty {
+        "I1" => "U1",
+        "I8" => "U8",
+        "I16" => "U16",
+        "I32" => "U32",
+        "I64" => "U64",
+        ty => ty,
+    };
+    Ident::new(variant, Span::call_site())
+}
@@ -0,0 +1,1147 @@
+// @generated by libvex's `ir::op::test::macro_tables` test from VEX's
+// `ppIROp` and `typeOfPrimop`.
+
+/// VEX's `IRType`.
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Type {
+    Ity_INVALID,
+    Ity_I1,
+    Ity_I8,
+    Ity_I16,
+    Ity_I32,
+    Ity_I64,
+    Ity_I128,
+    Ity_F16,
+    Ity_F32,
+    Ity_F64,
+    Ity_D32,
+    Ity_D64,
+    Ity_D128,
+    Ity_F128,
+    Ity_V128,
+    Ity_V256,
+}
+
+/// Every type but `Ity_INVALID`.
+pub const ALL_TYPES: &[Type] = &[
+    Type::Ity_I1,
+    Type::Ity_I8,
+    Type::Ity_I16,
+    Type::Ity_I32,
+    Type::Ity_I64,
+    Type::Ity_I128,
+    Type::Ity_F16,
+    Type::Ity_F32,
+    Type::Ity_F64,
+    Type::Ity_D32,
+    Type::Ity_D64,
+    Type::Ity_D128,
+    Type::Ity_F128,
+    Type::Ity_V128,
+    Type::Ity_V256,
+];
+
+/// Every op, as `(name as printed by VEX, C name, result type, argument types)`.
+pub const ALL_OPS: &[(&str, &str, Type, &[Type])] = &[
+    ("Add8", "Iop_Add8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Add16", "Iop_Add16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("Add32", "Iop_Add32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Add64", "Iop_Add64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sub8", "Iop_Sub8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Sub16", "Iop_Sub16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("Sub32", "Iop_Sub32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Sub64", "Iop_Sub64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mul8", "Iop_Mul8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Mul16", "Iop_Mul16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("Mul32", "Iop_Mul32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Mul64", "Iop_Mul64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Or8", "Iop_Or8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Or16", "Iop_Or16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("Or32", "Iop_Or32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Or64", "Iop_Or64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("And8", "Iop_And8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("And16", "Iop_And16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("And32", "Iop_And32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("And64", "Iop_And64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Xor8", "Iop_Xor8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Xor16", "Iop_Xor16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I16]),
+    ("Xor32", "Iop_Xor32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Xor64", "Iop_Xor64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shl8", "Iop_Shl8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Shl16", "Iop_Shl16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I8]),
+    ("Shl32", "Iop_Shl32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I8]),
+    ("Shl64", "Iop_Shl64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("Shr8", "Iop_Shr8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Shr16", "Iop_Shr16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I8]),
+    ("Shr32", "Iop_Shr32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I8]),
+    ("Shr64", "Iop_Shr64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("Sar8", "Iop_Sar8", Type::Ity_I8, &[Type::Ity_I8, Type::Ity_I8]),
+    ("Sar16", "Iop_Sar16", Type::Ity_I16, &[Type::Ity_I16, Type::Ity_I8]),
+    ("Sar32", "Iop_Sar32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I8]),
+    ("Sar64", "Iop_Sar64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("CmpEQ8", "Iop_CmpEQ8", Type::Ity_I1, &[Type::Ity_I8, Type::Ity_I8]),
+    ("CmpEQ16", "Iop_CmpEQ16", Type::Ity_I1, &[Type::Ity_I16, Type::Ity_I16]),
+    ("CmpEQ32", "Iop_CmpEQ32", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpEQ64", "Iop_CmpEQ64", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpNE8", "Iop_CmpNE8", Type::Ity_I1, &[Type::Ity_I8, Type::Ity_I8]),
+    ("CmpNE16", "Iop_CmpNE16", Type::Ity_I1, &[Type::Ity_I16, Type::Ity_I16]),
+    ("CmpNE32", "Iop_CmpNE32", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpNE64", "Iop_CmpNE64", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Not8", "Iop_Not8", Type::Ity_I8, &[Type::Ity_I8]),
+    ("Not16", "Iop_Not16", Type::Ity_I16, &[Type::Ity_I16]),
+    ("Not32", "Iop_Not32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("Not64", "Iop_Not64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("CasCmpEQ8", "Iop_CasCmpEQ8", Type::Ity_I1, &[Type::Ity_I8, Type::Ity_I8]),
+    ("CasCmpEQ16", "Iop_CasCmpEQ16", Type::Ity_I1, &[Type::Ity_I16, Type::Ity_I16]),
+    ("CasCmpEQ32", "Iop_CasCmpEQ32", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CasCmpEQ64", "Iop_CasCmpEQ64", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CasCmpNE8", "Iop_CasCmpNE8", Type::Ity_I1, &[Type::Ity_I8, Type::Ity_I8]),
+    ("CasCmpNE16", "Iop_CasCmpNE16", Type::Ity_I1, &[Type::Ity_I16, Type::Ity_I16]),
+    ("CasCmpNE32", "Iop_CasCmpNE32", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CasCmpNE64", "Iop_CasCmpNE64", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("ExpCmpNE8", "Iop_ExpCmpNE8", Type::Ity_I1, &[Type::Ity_I8, Type::Ity_I8]),
+    ("ExpCmpNE16", "Iop_ExpCmpNE16", Type::Ity_I1, &[Type::Ity_I16, Type::Ity_I16]),
+    ("ExpCmpNE32", "Iop_ExpCmpNE32", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("ExpCmpNE64", "Iop_ExpCmpNE64", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("MullS8", "Iop_MullS8", Type::Ity_I16, &[Type::Ity_I8, Type::Ity_I8]),
+    ("MullS16", "Iop_MullS16", Type::Ity_I32, &[Type::Ity_I16, Type::Ity_I16]),
+    ("MullS32", "Iop_MullS32", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_I32]),
+    ("MullS64", "Iop_MullS64", Type::Ity_I128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("MullU8", "Iop_MullU8", Type::Ity_I16, &[Type::Ity_I8, Type::Ity_I8]),
+    ("MullU16", "Iop_MullU16", Type::Ity_I32, &[Type::Ity_I16, Type::Ity_I16]),
+    ("MullU32", "Iop_MullU32", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_I32]),
+    ("MullU64", "Iop_MullU64", Type::Ity_I128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Clz64", "Iop_Clz64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Clz32", "Iop_Clz32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("Ctz64", "Iop_Ctz64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Ctz32", "Iop_Ctz32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("ClzNat64", "Iop_ClzNat64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("ClzNat32", "Iop_ClzNat32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("CtzNat64", "Iop_CtzNat64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("CtzNat32", "Iop_CtzNat32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("PopCount64", "Iop_PopCount64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PopCount32", "Iop_PopCount32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("CmpLT32S", "Iop_CmpLT32S", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpLT64S", "Iop_CmpLT64S", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpLE32S", "Iop_CmpLE32S", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpLE64S", "Iop_CmpLE64S", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpLT32U", "Iop_CmpLT32U", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpLT64U", "Iop_CmpLT64U", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpLE32U", "Iop_CmpLE32U", Type::Ity_I1, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpLE64U", "Iop_CmpLE64U", Type::Ity_I1, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpNEZ8", "Iop_CmpNEZ8", Type::Ity_I1, &[Type::Ity_I8]),
+    ("CmpNEZ16", "Iop_CmpNEZ16", Type::Ity_I1, &[Type::Ity_I16]),
+    ("CmpNEZ32", "Iop_CmpNEZ32", Type::Ity_I1, &[Type::Ity_I32]),
+    ("CmpNEZ64", "Iop_CmpNEZ64", Type::Ity_I1, &[Type::Ity_I64]),
+    ("CmpwNEZ32", "Iop_CmpwNEZ32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("CmpwNEZ64", "Iop_CmpwNEZ64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Left8", "Iop_Left8", Type::Ity_I8, &[Type::Ity_I8]),
+    ("Left16", "Iop_Left16", Type::Ity_I16, &[Type::Ity_I16]),
+    ("Left32", "Iop_Left32", Type::Ity_I32, &[Type::Ity_I32]),
+    ("Left64", "Iop_Left64", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Max32U", "Iop_Max32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpORD32U", "Iop_CmpORD32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpORD64U", "Iop_CmpORD64U", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpORD32S", "Iop_CmpORD32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpORD64S", "Iop_CmpORD64S", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivU32", "Iop_DivU32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivS32", "Iop_DivS32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivU64", "Iop_DivU64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivS64", "Iop_DivS64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivU64E", "Iop_DivU64E", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivS64E", "Iop_DivS64E", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivU32E", "Iop_DivU32E", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivS32E", "Iop_DivS32E", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivModU64to32", "Iop_DivModU64to32", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I32]),
+    ("DivModS64to32", "Iop_DivModS64to32", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I32]),
+    ("DivModU128to64", "Iop_DivModU128to64", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I64]),
+    ("DivModS128to64", "Iop_DivModS128to64", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I64]),
+    ("DivModS64to64", "Iop_DivModS64to64", Type::Ity_I128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivModU64to64", "Iop_DivModU64to64", Type::Ity_I128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("DivModS32to32", "Iop_DivModS32to32", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivModU32to32", "Iop_DivModU32to32", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_I32]),
+    ("DivU128", "Iop_DivU128", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("DivS128", "Iop_DivS128", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("DivU128E", "Iop_DivU128E", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("DivS128E", "Iop_DivS128E", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("ModU128", "Iop_ModU128", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("ModS128", "Iop_ModS128", Type::Ity_I128, &[Type::Ity_I128, Type::Ity_I128]),
+    ("8Uto16", "Iop_8Uto16", Type::Ity_I16, &[Type::Ity_I8]),
+    ("8Uto32", "Iop_8Uto32", Type::Ity_I32, &[Type::Ity_I8]),
+    ("8Uto64", "Iop_8Uto64", Type::Ity_I64, &[Type::Ity_I8]),
+    ("16Uto32", "Iop_16Uto32", Type::Ity_I32, &[Type::Ity_I16]),
+    ("16Uto64", "Iop_16Uto64", Type::Ity_I64, &[Type::Ity_I16]),
+    ("32Uto64", "Iop_32Uto64", Type::Ity_I64, &[Type::Ity_I32]),
+    ("8Sto16", "Iop_8Sto16", Type::Ity_I16, &[Type::Ity_I8]),
+    ("8Sto32", "Iop_8Sto32", Type::Ity_I32, &[Type::Ity_I8]),
+    ("8Sto64", "Iop_8Sto64", Type::Ity_I64, &[Type::Ity_I8]),
+    ("16Sto32", "Iop_16Sto32", Type::Ity_I32, &[Type::Ity_I16]),
+    ("16Sto64", "Iop_16Sto64", Type::Ity_I64, &[Type::Ity_I16]),
+    ("32Sto64", "Iop_32Sto64", Type::Ity_I64, &[Type::Ity_I32]),
+    ("64to8", "Iop_64to8", Type::Ity_I8, &[Type::Ity_I64]),
+    ("32to8", "Iop_32to8", Type::Ity_I8, &[Type::Ity_I32]),
+    ("64to16", "Iop_64to16", Type::Ity_I16, &[Type::Ity_I64]),
+    ("16to8", "Iop_16to8", Type::Ity_I8, &[Type::Ity_I16]),
+    ("16HIto8", "Iop_16HIto8", Type::Ity_I8, &[Type::Ity_I16]),
+    ("8HLto16", "Iop_8HLto16", Type::Ity_I16, &[Type::Ity_I8, Type::Ity_I8]),
+    ("32to16", "Iop_32to16", Type::Ity_I16, &[Type::Ity_I32]),
+    ("32HIto16", "Iop_32HIto16", Type::Ity_I16, &[Type::Ity_I32]),
+    ("16HLto32", "Iop_16HLto32", Type::Ity_I32, &[Type::Ity_I16, Type::Ity_I16]),
+    ("64to32", "Iop_64to32", Type::Ity_I32, &[Type::Ity_I64]),
+    ("64HIto32", "Iop_64HIto32", Type::Ity_I32, &[Type::Ity_I64]),
+    ("32HLto64", "Iop_32HLto64", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_I32]),
+    ("128to64", "Iop_128to64", Type::Ity_I64, &[Type::Ity_I128]),
+    ("128HIto64", "Iop_128HIto64", Type::Ity_I64, &[Type::Ity_I128]),
+    ("64HLto128", "Iop_64HLto128", Type::Ity_I128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Not1", "Iop_Not1", Type::Ity_I1, &[Type::Ity_I1]),
+    ("And1", "Iop_And1", Type::Ity_I1, &[Type::Ity_I1, Type::Ity_I1]),
+    ("Or1", "Iop_Or1", Type::Ity_I1, &[Type::Ity_I1, Type::Ity_I1]),
+    ("32to1", "Iop_32to1", Type::Ity_I1, &[Type::Ity_I32]),
+    ("64to1", "Iop_64to1", Type::Ity_I1, &[Type::Ity_I64]),
+    ("1Uto8", "Iop_1Uto8", Type::Ity_I8, &[Type::Ity_I1]),
+    ("1Uto32", "Iop_1Uto32", Type::Ity_I32, &[Type::Ity_I1]),
+    ("1Uto64", "Iop_1Uto64", Type::Ity_I64, &[Type::Ity_I1]),
+    ("1Sto8", "Iop_1Sto8", Type::Ity_I8, &[Type::Ity_I1]),
+    ("1Sto16", "Iop_1Sto16", Type::Ity_I16, &[Type::Ity_I1]),
+    ("1Sto32", "Iop_1Sto32", Type::Ity_I32, &[Type::Ity_I1]),
+    ("1Sto64", "Iop_1Sto64", Type::Ity_I64, &[Type::Ity_I1]),
+    ("AddF64", "Iop_AddF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("SubF64", "Iop_SubF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("MulF64", "Iop_MulF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("DivF64", "Iop_DivF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("AddF32", "Iop_AddF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32]),
+    ("SubF32", "Iop_SubF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32]),
+    ("MulF32", "Iop_MulF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32]),
+    ("DivF32", "Iop_DivF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32]),
+    ("AddF64r32", "Iop_AddF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("SubF64r32", "Iop_SubF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("MulF64r32", "Iop_MulF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("DivF64r32", "Iop_DivF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("NegF64", "Iop_NegF64", Type::Ity_F64, &[Type::Ity_F64]),
+    ("AbsF64", "Iop_AbsF64", Type::Ity_F64, &[Type::Ity_F64]),
+    ("NegF32", "Iop_NegF32", Type::Ity_F32, &[Type::Ity_F32]),
+    ("AbsF32", "Iop_AbsF32", Type::Ity_F32, &[Type::Ity_F32]),
+    ("SqrtF64", "Iop_SqrtF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("SqrtF32", "Iop_SqrtF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("CmpF64", "Iop_CmpF64", Type::Ity_I32, &[Type::Ity_F64, Type::Ity_F64]),
+    ("CmpF32", "Iop_CmpF32", Type::Ity_I32, &[Type::Ity_F32, Type::Ity_F32]),
+    ("CmpF128", "Iop_CmpF128", Type::Ity_I32, &[Type::Ity_F128, Type::Ity_F128]),
+    ("F64toI16S", "Iop_F64toI16S", Type::Ity_I16, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toI32S", "Iop_F64toI32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toI64S", "Iop_F64toI64S", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toI64U", "Iop_F64toI64U", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toI32U", "Iop_F64toI32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F64]),
+    ("I32StoF64", "Iop_I32StoF64", Type::Ity_F64, &[Type::Ity_I32]),
+    ("I64StoF64", "Iop_I64StoF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_I64]),
+    ("I64UtoF64", "Iop_I64UtoF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_I64]),
+    ("I64UtoF32", "Iop_I64UtoF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_I64]),
+    ("I32UtoF32", "Iop_I32UtoF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("I32UtoF64", "Iop_I32UtoF64", Type::Ity_F64, &[Type::Ity_I32]),
+    ("F32toI32S", "Iop_F32toI32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F32toI64S", "Iop_F32toI64S", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F32toI32U", "Iop_F32toI32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F32toI64U", "Iop_F32toI64U", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F32]),
+    ("I32StoF32", "Iop_I32StoF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("I64StoF32", "Iop_I64StoF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_I64]),
+    ("F32toF64", "Iop_F32toF64", Type::Ity_F64, &[Type::Ity_F32]),
+    ("F64toF32", "Iop_F64toF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F64]),
+    ("ReinterpF64asI64", "Iop_ReinterpF64asI64", Type::Ity_I64, &[Type::Ity_F64]),
+    ("ReinterpI64asF64", "Iop_ReinterpI64asF64", Type::Ity_F64, &[Type::Ity_I64]),
+    ("ReinterpF32asI32", "Iop_ReinterpF32asI32", Type::Ity_I32, &[Type::Ity_F32]),
+    ("ReinterpI32asF32", "Iop_ReinterpI32asF32", Type::Ity_F32, &[Type::Ity_I32]),
+    ("F64HLtoF128", "Iop_F64HLtoF128", Type::Ity_F128, &[Type::Ity_F64, Type::Ity_F64]),
+    ("F128HItoF64", "Iop_F128HItoF64", Type::Ity_F64, &[Type::Ity_F128]),
+    ("F128LOtoF64", "Iop_F128LOtoF64", Type::Ity_F64, &[Type::Ity_F128]),
+    ("AddF128", "Iop_AddF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128]),
+    ("SubF128", "Iop_SubF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128]),
+    ("MulF128", "Iop_MulF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128]),
+    ("DivF128", "Iop_DivF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128]),
+    ("MAddF128", "Iop_MAddF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128, Type::Ity_F128]),
+    ("MSubF128", "Iop_MSubF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128, Type::Ity_F128]),
+    ("NegMAddF128", "Iop_NegMAddF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128, Type::Ity_F128]),
+    ("NegMSubF128", "Iop_NegMSubF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128, Type::Ity_F128, Type::Ity_F128]),
+    ("NegF128", "Iop_NegF128", Type::Ity_F128, &[Type::Ity_F128]),
+    ("AbsF128", "Iop_AbsF128", Type::Ity_F128, &[Type::Ity_F128]),
+    ("SqrtF128", "Iop_SqrtF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128]),
+    ("I32StoF128", "Iop_I32StoF128", Type::Ity_F128, &[Type::Ity_I32]),
+    ("I64StoF128", "Iop_I64StoF128", Type::Ity_F128, &[Type::Ity_I64]),
+    ("I32UtoF128", "Iop_I32UtoF128", Type::Ity_F128, &[Type::Ity_I32]),
+    ("I64UtoF128", "Iop_I64UtoF128", Type::Ity_F128, &[Type::Ity_I64]),
+    ("F32toF128", "Iop_F32toF128", Type::Ity_F128, &[Type::Ity_F32]),
+    ("F64toF128", "Iop_F64toF128", Type::Ity_F128, &[Type::Ity_F64]),
+    ("F128toI32S", "Iop_F128toI32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toI64S", "Iop_F128toI64S", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toI32U", "Iop_F128toI32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toI64U", "Iop_F128toI64U", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toI128S", "Iop_F128toI128S", Type::Ity_I128, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toF64", "Iop_F128toF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toF32", "Iop_F128toF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F128]),
+    ("TruncF128toI32S", "Iop_TruncF128toI32S", Type::Ity_I32, &[Type::Ity_F128]),
+    ("TruncF128toI64S", "Iop_TruncF128toI64S", Type::Ity_I64, &[Type::Ity_F128]),
+    ("TruncF128toI32U", "Iop_TruncF128toI32U", Type::Ity_I32, &[Type::Ity_F128]),
+    ("TruncF128toI64U", "Iop_TruncF128toI64U", Type::Ity_I64, &[Type::Ity_F128]),
+    ("TruncF128toI128U", "Iop_TruncF128toI128U", Type::Ity_I128, &[Type::Ity_F128]),
+    ("TruncF128toI128S", "Iop_TruncF128toI128S", Type::Ity_I128, &[Type::Ity_F128]),
+    ("AtanF64", "Iop_AtanF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("Yl2xF64", "Iop_Yl2xF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("Yl2xp1F64", "Iop_Yl2xp1F64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("PRemF64", "Iop_PRemF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("PRemC3210F64", "Iop_PRemC3210F64", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("PRem1F64", "Iop_PRem1F64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("PRem1C3210F64", "Iop_PRem1C3210F64", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("ScaleF64", "Iop_ScaleF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64]),
+    ("SinF64", "Iop_SinF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("CosF64", "Iop_CosF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("TanF64", "Iop_TanF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("2xm1F64", "Iop_2xm1F64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("RoundF64toInt", "Iop_RoundF64toInt", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("RoundF128toInt", "Iop_RoundF128toInt", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_F128]),
+    ("RoundF32toInt", "Iop_RoundF32toInt", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("MAddF32", "Iop_MAddF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32, Type::Ity_F32]),
+    ("MSubF32", "Iop_MSubF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32, Type::Ity_F32, Type::Ity_F32]),
+    ("MAddF64", "Iop_MAddF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64, Type::Ity_F64]),
+    ("MSubF64", "Iop_MSubF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64, Type::Ity_F64]),
+    ("MAddF64r32", "Iop_MAddF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64, Type::Ity_F64]),
+    ("MSubF64r32", "Iop_MSubF64r32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64, Type::Ity_F64, Type::Ity_F64]),
+    ("RSqrtEst5GoodF64", "Iop_RSqrtEst5GoodF64", Type::Ity_F64, &[Type::Ity_F64]),
+    ("RoundF64toF64_NEAREST", "Iop_RoundF64toF64_NEAREST", Type::Ity_F64, &[Type::Ity_F64]),
+    ("RoundF64toF64_NegINF", "Iop_RoundF64toF64_NegINF", Type::Ity_F64, &[Type::Ity_F64]),
+    ("RoundF64toF64_PosINF", "Iop_RoundF64toF64_PosINF", Type::Ity_F64, &[Type::Ity_F64]),
+    ("RoundF64toF64_ZERO", "Iop_RoundF64toF64_ZERO", Type::Ity_F64, &[Type::Ity_F64]),
+    ("TruncF64asF32", "Iop_TruncF64asF32", Type::Ity_F32, &[Type::Ity_F64]),
+    ("RoundF64toF32", "Iop_RoundF64toF32", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("RecpExpF64", "Iop_RecpExpF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("RecpExpF32", "Iop_RecpExpF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F16toF64", "Iop_F16toF64", Type::Ity_F64, &[Type::Ity_F16]),
+    ("F64toF16", "Iop_F64toF16", Type::Ity_F16, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F16toF32", "Iop_F16toF32", Type::Ity_F32, &[Type::Ity_F16]),
+    ("F32toF16", "Iop_F32toF16", Type::Ity_F16, &[Type::Ity_I32, Type::Ity_F32]),
+    ("MaxNumF64", "Iop_MaxNumF64", Type::Ity_F64, &[Type::Ity_F64, Type::Ity_F64]),
+    ("MinNumF64", "Iop_MinNumF64", Type::Ity_F64, &[Type::Ity_F64, Type::Ity_F64]),
+    ("MaxNumF32", "Iop_MaxNumF32", Type::Ity_F32, &[Type::Ity_F32, Type::Ity_F32]),
+    ("MinNumF32", "Iop_MinNumF32", Type::Ity_F32, &[Type::Ity_F32, Type::Ity_F32]),
+    ("QAdd32S", "Iop_QAdd32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QSub32S", "Iop_QSub32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Add16x2", "Iop_Add16x2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Sub16x2", "Iop_Sub16x2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QAdd16Sx2", "Iop_QAdd16Sx2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QAdd16Ux2", "Iop_QAdd16Ux2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QSub16Sx2", "Iop_QSub16Sx2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QSub16Ux2", "Iop_QSub16Ux2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HAdd16Ux2", "Iop_HAdd16Ux2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HAdd16Sx2", "Iop_HAdd16Sx2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HSub16Ux2", "Iop_HSub16Ux2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HSub16Sx2", "Iop_HSub16Sx2", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Add8x4", "Iop_Add8x4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Sub8x4", "Iop_Sub8x4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QAdd8Sx4", "Iop_QAdd8Sx4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QAdd8Ux4", "Iop_QAdd8Ux4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QSub8Sx4", "Iop_QSub8Sx4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("QSub8Ux4", "Iop_QSub8Ux4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HAdd8Ux4", "Iop_HAdd8Ux4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HAdd8Sx4", "Iop_HAdd8Sx4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HSub8Ux4", "Iop_HSub8Ux4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("HSub8Sx4", "Iop_HSub8Sx4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("Sad8Ux4", "Iop_Sad8Ux4", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I32]),
+    ("CmpNEZ16x2", "Iop_CmpNEZ16x2", Type::Ity_I32, &[Type::Ity_I32]),
+    ("CmpNEZ8x4", "Iop_CmpNEZ8x4", Type::Ity_I32, &[Type::Ity_I32]),
+    ("I32UtoF32x2_DEP", "Iop_I32UtoF32x2_DEP", Type::Ity_I64, &[Type::Ity_I64]),
+    ("I32StoF32x2_DEP", "Iop_I32StoF32x2_DEP", Type::Ity_I64, &[Type::Ity_I64]),
+    ("F32toI32Ux2_RZ", "Iop_F32toI32Ux2_RZ", Type::Ity_I64, &[Type::Ity_I64]),
+    ("F32toI32Sx2_RZ", "Iop_F32toI32Sx2_RZ", Type::Ity_I64, &[Type::Ity_I64]),
+    ("F32ToFixed32Ux2_RZ", "Iop_F32ToFixed32Ux2_RZ", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("F32ToFixed32Sx2_RZ", "Iop_F32ToFixed32Sx2_RZ", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("Fixed32UToF32x2_RN", "Iop_Fixed32UToF32x2_RN", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("Fixed32SToF32x2_RN", "Iop_Fixed32SToF32x2_RN", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("Max32Fx2", "Iop_Max32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min32Fx2", "Iop_Min32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax32Fx2", "Iop_PwMax32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin32Fx2", "Iop_PwMin32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpEQ32Fx2", "Iop_CmpEQ32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT32Fx2", "Iop_CmpGT32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGE32Fx2", "Iop_CmpGE32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("RecipEst32Fx2", "Iop_RecipEst32Fx2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("RecipStep32Fx2", "Iop_RecipStep32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("RSqrtEst32Fx2", "Iop_RSqrtEst32Fx2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("RSqrtStep32Fx2", "Iop_RSqrtStep32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Neg32Fx2", "Iop_Neg32Fx2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Abs32Fx2", "Iop_Abs32Fx2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("CmpNEZ8x8", "Iop_CmpNEZ8x8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("CmpNEZ16x4", "Iop_CmpNEZ16x4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("CmpNEZ32x2", "Iop_CmpNEZ32x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Add8x8", "Iop_Add8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Add16x4", "Iop_Add16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Add32x2", "Iop_Add32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd8Ux8", "Iop_QAdd8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd16Ux4", "Iop_QAdd16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd32Ux2", "Iop_QAdd32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd64Ux1", "Iop_QAdd64Ux1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd8Sx8", "Iop_QAdd8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd16Sx4", "Iop_QAdd16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd32Sx2", "Iop_QAdd32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QAdd64Sx1", "Iop_QAdd64Sx1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwAdd8x8", "Iop_PwAdd8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwAdd16x4", "Iop_PwAdd16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwAdd32x2", "Iop_PwAdd32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax8Sx8", "Iop_PwMax8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax16Sx4", "Iop_PwMax16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax32Sx2", "Iop_PwMax32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax8Ux8", "Iop_PwMax8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax16Ux4", "Iop_PwMax16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMax32Ux2", "Iop_PwMax32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin8Sx8", "Iop_PwMin8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin16Sx4", "Iop_PwMin16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin32Sx2", "Iop_PwMin32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin8Ux8", "Iop_PwMin8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin16Ux4", "Iop_PwMin16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwMin32Ux2", "Iop_PwMin32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwAddL8Ux8", "Iop_PwAddL8Ux8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PwAddL16Ux4", "Iop_PwAddL16Ux4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PwAddL32Ux2", "Iop_PwAddL32Ux2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PwAddL8Sx8", "Iop_PwAddL8Sx8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PwAddL16Sx4", "Iop_PwAddL16Sx4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("PwAddL32Sx2", "Iop_PwAddL32Sx2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Sub8x8", "Iop_Sub8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sub16x4", "Iop_Sub16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sub32x2", "Iop_Sub32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub8Ux8", "Iop_QSub8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub16Ux4", "Iop_QSub16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub32Ux2", "Iop_QSub32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub64Ux1", "Iop_QSub64Ux1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub8Sx8", "Iop_QSub8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub16Sx4", "Iop_QSub16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub32Sx2", "Iop_QSub32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSub64Sx1", "Iop_QSub64Sx1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Abs8x8", "Iop_Abs8x8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Abs16x4", "Iop_Abs16x4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Abs32x2", "Iop_Abs32x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Mul8x8", "Iop_Mul8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mul16x4", "Iop_Mul16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mul32x2", "Iop_Mul32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mul32Fx2", "Iop_Mul32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("MulHi16Ux4", "Iop_MulHi16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("MulHi16Sx4", "Iop_MulHi16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PolynomialMul8x8", "Iop_PolynomialMul8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QDMulHi16Sx4", "Iop_QDMulHi16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QDMulHi32Sx2", "Iop_QDMulHi32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QRDMulHi16Sx4", "Iop_QRDMulHi16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QRDMulHi32Sx2", "Iop_QRDMulHi32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Avg8Ux8", "Iop_Avg8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Avg16Ux4", "Iop_Avg16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max8Sx8", "Iop_Max8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max16Sx4", "Iop_Max16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max32Sx2", "Iop_Max32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max8Ux8", "Iop_Max8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max16Ux4", "Iop_Max16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Max32Ux2", "Iop_Max32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min8Sx8", "Iop_Min8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min16Sx4", "Iop_Min16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min32Sx2", "Iop_Min32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min8Ux8", "Iop_Min8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min16Ux4", "Iop_Min16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Min32Ux2", "Iop_Min32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpEQ8x8", "Iop_CmpEQ8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpEQ16x4", "Iop_CmpEQ16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpEQ32x2", "Iop_CmpEQ32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT8Ux8", "Iop_CmpGT8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT16Ux4", "Iop_CmpGT16Ux4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT32Ux2", "Iop_CmpGT32Ux2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT8Sx8", "Iop_CmpGT8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT16Sx4", "Iop_CmpGT16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CmpGT32Sx2", "Iop_CmpGT32Sx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Cnt8x8", "Iop_Cnt8x8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Clz8x8", "Iop_Clz8x8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Clz16x4", "Iop_Clz16x4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Clz32x2", "Iop_Clz32x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Cls8x8", "Iop_Cls8x8", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Cls16x4", "Iop_Cls16x4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Cls32x2", "Iop_Cls32x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Clz64x2", "Iop_Clz64x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Shl8x8", "Iop_Shl8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shl16x4", "Iop_Shl16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shl32x2", "Iop_Shl32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shr8x8", "Iop_Shr8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shr16x4", "Iop_Shr16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Shr32x2", "Iop_Shr32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sar8x8", "Iop_Sar8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sar16x4", "Iop_Sar16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sar32x2", "Iop_Sar32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sal8x8", "Iop_Sal8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sal16x4", "Iop_Sal16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sal32x2", "Iop_Sal32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Sal64x1", "Iop_Sal64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("ShlN8x8", "Iop_ShlN8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("ShlN16x4", "Iop_ShlN16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("ShlN32x2", "Iop_ShlN32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("ShrN8x8", "Iop_ShrN8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("ShrN16x4", "Iop_ShrN16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("ShrN32x2", "Iop_ShrN32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("SarN8x8", "Iop_SarN8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("SarN16x4", "Iop_SarN16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("SarN32x2", "Iop_SarN32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShl8x8", "Iop_QShl8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QShl16x4", "Iop_QShl16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QShl32x2", "Iop_QShl32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QShl64x1", "Iop_QShl64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSal8x8", "Iop_QSal8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSal16x4", "Iop_QSal16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSal32x2", "Iop_QSal32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QSal64x1", "Iop_QSal64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QShlNsatSU8x8", "Iop_QShlNsatSU8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSU16x4", "Iop_QShlNsatSU16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSU32x2", "Iop_QShlNsatSU32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSU64x1", "Iop_QShlNsatSU64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatUU8x8", "Iop_QShlNsatUU8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatUU16x4", "Iop_QShlNsatUU16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatUU32x2", "Iop_QShlNsatUU32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatUU64x1", "Iop_QShlNsatUU64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSS8x8", "Iop_QShlNsatSS8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSS16x4", "Iop_QShlNsatSS16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSS32x2", "Iop_QShlNsatSS32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QShlNsatSS64x1", "Iop_QShlNsatSS64x1", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8]),
+    ("QNarrowBin16Sto8Ux8", "Iop_QNarrowBin16Sto8Ux8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QNarrowBin16Sto8Sx8", "Iop_QNarrowBin16Sto8Sx8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QNarrowBin32Sto16Sx4", "Iop_QNarrowBin32Sto16Sx4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("NarrowBin16to8x8", "Iop_NarrowBin16to8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("NarrowBin32to16x4", "Iop_NarrowBin32to16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveHI8x8", "Iop_InterleaveHI8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveHI16x4", "Iop_InterleaveHI16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveHI32x2", "Iop_InterleaveHI32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveLO8x8", "Iop_InterleaveLO8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveLO16x4", "Iop_InterleaveLO16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveLO32x2", "Iop_InterleaveLO32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveOddLanes8x8", "Iop_InterleaveOddLanes8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveEvenLanes8x8", "Iop_InterleaveEvenLanes8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveOddLanes16x4", "Iop_InterleaveOddLanes16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("InterleaveEvenLanes16x4", "Iop_InterleaveEvenLanes16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CatOddLanes8x8", "Iop_CatOddLanes8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CatOddLanes16x4", "Iop_CatOddLanes16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CatEvenLanes8x8", "Iop_CatEvenLanes8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("CatEvenLanes16x4", "Iop_CatEvenLanes16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("GetElem8x8", "Iop_GetElem8x8", Type::Ity_I8, &[Type::Ity_I64, Type::Ity_I8]),
+    ("GetElem16x4", "Iop_GetElem16x4", Type::Ity_I16, &[Type::Ity_I64, Type::Ity_I8]),
+    ("GetElem32x2", "Iop_GetElem32x2", Type::Ity_I32, &[Type::Ity_I64, Type::Ity_I8]),
+    ("SetElem8x8", "Iop_SetElem8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8, Type::Ity_I8]),
+    ("SetElem16x4", "Iop_SetElem16x4", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8, Type::Ity_I16]),
+    ("SetElem32x2", "Iop_SetElem32x2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8, Type::Ity_I32]),
+    ("Dup8x8", "Iop_Dup8x8", Type::Ity_I64, &[Type::Ity_I8]),
+    ("Dup16x4", "Iop_Dup16x4", Type::Ity_I64, &[Type::Ity_I16]),
+    ("Dup32x2", "Iop_Dup32x2", Type::Ity_I64, &[Type::Ity_I32]),
+    ("Slice64", "Iop_Slice64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64, Type::Ity_I8]),
+    ("Reverse8sIn16_x4", "Iop_Reverse8sIn16_x4", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Reverse8sIn32_x2", "Iop_Reverse8sIn32_x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Reverse16sIn32_x2", "Iop_Reverse16sIn32_x2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Reverse8sIn64_x1", "Iop_Reverse8sIn64_x1", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Reverse16sIn64_x1", "Iop_Reverse16sIn64_x1", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Reverse32sIn64_x1", "Iop_Reverse32sIn64_x1", Type::Ity_I64, &[Type::Ity_I64]),
+    ("Perm8x8", "Iop_Perm8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PermOrZero8x8", "Iop_PermOrZero8x8", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("GetMSBs8x8", "Iop_GetMSBs8x8", Type::Ity_I8, &[Type::Ity_I64]),
+    ("RecipEst32Ux2", "Iop_RecipEst32Ux2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("RSqrtEst32Ux2", "Iop_RSqrtEst32Ux2", Type::Ity_I64, &[Type::Ity_I64]),
+    ("AddD64", "Iop_AddD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64, Type::Ity_D64]),
+    ("SubD64", "Iop_SubD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64, Type::Ity_D64]),
+    ("MulD64", "Iop_MulD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64, Type::Ity_D64]),
+    ("DivD64", "Iop_DivD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64, Type::Ity_D64]),
+    ("AddD128", "Iop_AddD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128, Type::Ity_D128]),
+    ("SubD128", "Iop_SubD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128, Type::Ity_D128]),
+    ("MulD128", "Iop_MulD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128, Type::Ity_D128]),
+    ("DivD128", "Iop_DivD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128, Type::Ity_D128]),
+    ("ShlD64", "Iop_ShlD64", Type::Ity_D64, &[Type::Ity_D64, Type::Ity_I8]),
+    ("ShrD64", "Iop_ShrD64", Type::Ity_D64, &[Type::Ity_D64, Type::Ity_I8]),
+    ("ShlD128", "Iop_ShlD128", Type::Ity_D128, &[Type::Ity_D128, Type::Ity_I8]),
+    ("ShrD128", "Iop_ShrD128", Type::Ity_D128, &[Type::Ity_D128, Type::Ity_I8]),
+    ("D32toD64", "Iop_D32toD64", Type::Ity_D64, &[Type::Ity_D32]),
+    ("D64toD128", "Iop_D64toD128", Type::Ity_D128, &[Type::Ity_D64]),
+    ("I32StoD128", "Iop_I32StoD128", Type::Ity_D128, &[Type::Ity_I32]),
+    ("I32UtoD128", "Iop_I32UtoD128", Type::Ity_D128, &[Type::Ity_I32]),
+    ("I64StoD128", "Iop_I64StoD128", Type::Ity_D128, &[Type::Ity_I64]),
+    ("I64UtoD128", "Iop_I64UtoD128", Type::Ity_D128, &[Type::Ity_I64]),
+    ("D64toD32", "Iop_D64toD32", Type::Ity_D32, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D128toD64", "Iop_D128toD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D128]),
+    ("I32StoD64", "Iop_I32StoD64", Type::Ity_D64, &[Type::Ity_I32]),
+    ("I32UtoD64", "Iop_I32UtoD64", Type::Ity_D64, &[Type::Ity_I32]),
+    ("I64StoD64", "Iop_I64StoD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_I64]),
+    ("I64UtoD64", "Iop_I64UtoD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_I64]),
+    ("D64toI32S", "Iop_D64toI32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D64toI32U", "Iop_D64toI32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D64toI64S", "Iop_D64toI64S", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D64toI64U", "Iop_D64toI64U", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D128toI32S", "Iop_D128toI32S", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_D128]),
+    ("D128toI32U", "Iop_D128toI32U", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_D128]),
+    ("D128toI64S", "Iop_D128toI64S", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_D128]),
+    ("D128toI64U", "Iop_D128toI64U", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_D128]),
+    ("F32toD32", "Iop_F32toD32", Type::Ity_D32, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F32toD64", "Iop_F32toD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F32toD128", "Iop_F32toD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_F32]),
+    ("F64toD32", "Iop_F64toD32", Type::Ity_D32, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toD64", "Iop_F64toD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F64toD128", "Iop_F64toD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_F64]),
+    ("F128toD32", "Iop_F128toD32", Type::Ity_D32, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toD64", "Iop_F128toD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_F128]),
+    ("F128toD128", "Iop_F128toD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_F128]),
+    ("D32toF32", "Iop_D32toF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_D32]),
+    ("D32toF64", "Iop_D32toF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_D32]),
+    ("D32toF128", "Iop_D32toF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_D32]),
+    ("D64toF32", "Iop_D64toF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D64toF64", "Iop_D64toF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D64toF128", "Iop_D64toF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_D64]),
+    ("D128toF32", "Iop_D128toF32", Type::Ity_F32, &[Type::Ity_I32, Type::Ity_D128]),
+    ("D128toF64", "Iop_D128toF64", Type::Ity_F64, &[Type::Ity_I32, Type::Ity_D128]),
+    ("D128toF128", "Iop_D128toF128", Type::Ity_F128, &[Type::Ity_I32, Type::Ity_D128]),
+    ("RoundD64toInt", "Iop_RoundD64toInt", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64]),
+    ("RoundD128toInt", "Iop_RoundD128toInt", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128]),
+    ("CmpD64", "Iop_CmpD64", Type::Ity_I32, &[Type::Ity_D64, Type::Ity_D64]),
+    ("CmpD128", "Iop_CmpD128", Type::Ity_I32, &[Type::Ity_D128, Type::Ity_D128]),
+    ("CmpExpD64", "Iop_CmpExpD64", Type::Ity_I32, &[Type::Ity_D64, Type::Ity_D64]),
+    ("CmpExpD128", "Iop_CmpExpD128", Type::Ity_I32, &[Type::Ity_D128, Type::Ity_D128]),
+    ("QuantizeD64", "Iop_QuantizeD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_D64, Type::Ity_D64]),
+    ("QuantizeD128", "Iop_QuantizeD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_D128, Type::Ity_D128]),
+    ("SignificanceRoundD64", "Iop_SignificanceRoundD64", Type::Ity_D64, &[Type::Ity_I32, Type::Ity_I8, Type::Ity_D64]),
+    ("SignificanceRoundD128", "Iop_SignificanceRoundD128", Type::Ity_D128, &[Type::Ity_I32, Type::Ity_I8, Type::Ity_D128]),
+    ("ExtractExpD64", "Iop_ExtractExpD64", Type::Ity_I64, &[Type::Ity_D64]),
+    ("ExtractExpD128", "Iop_ExtractExpD128", Type::Ity_I64, &[Type::Ity_D128]),
+    ("ExtractSigD64", "Iop_ExtractSigD64", Type::Ity_I64, &[Type::Ity_D64]),
+    ("ExtractSigD128", "Iop_ExtractSigD128", Type::Ity_I64, &[Type::Ity_D128]),
+    ("InsertExpD64", "Iop_InsertExpD64", Type::Ity_D64, &[Type::Ity_I64, Type::Ity_D64]),
+    ("InsertExpD128", "Iop_InsertExpD128", Type::Ity_D128, &[Type::Ity_I64, Type::Ity_D128]),
+    ("D64HLtoD128", "Iop_D64HLtoD128", Type::Ity_D128, &[Type::Ity_D64, Type::Ity_D64]),
+    ("D128HItoD64", "Iop_D128HItoD64", Type::Ity_D64, &[Type::Ity_D128]),
+    ("D128LOtoD64", "Iop_D128LOtoD64", Type::Ity_D64, &[Type::Ity_D128]),
+    ("DPBtoBCD", "Iop_DPBtoBCD", Type::Ity_I64, &[Type::Ity_I64]),
+    ("BCDtoDPB", "Iop_BCDtoDPB", Type::Ity_I64, &[Type::Ity_I64]),
+    ("BCDAdd", "Iop_BCDAdd", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128, Type::Ity_I8]),
+    ("BCDSub", "Iop_BCDSub", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128, Type::Ity_I8]),
+    ("I128StoBCD128", "Iop_I128StoBCD128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("BCD128toI128S", "Iop_BCD128toI128S", Type::Ity_V128, &[Type::Ity_V128]),
+    ("ReinterpI64asD64", "Iop_ReinterpI64asD64", Type::Ity_D64, &[Type::Ity_I64]),
+    ("ReinterpD64asI64", "Iop_ReinterpD64asI64", Type::Ity_I64, &[Type::Ity_D64]),
+    ("Add32Fx4", "Iop_Add32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Sub32Fx4", "Iop_Sub32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Mul32Fx4", "Iop_Mul32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Div32Fx4", "Iop_Div32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Max32Fx4", "Iop_Max32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min32Fx4", "Iop_Min32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ32Fx4", "Iop_CmpEQ32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLT32Fx4", "Iop_CmpLT32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLE32Fx4", "Iop_CmpLE32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpUN32Fx4", "Iop_CmpUN32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT32Fx4", "Iop_CmpGT32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGE32Fx4", "Iop_CmpGE32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwMax32Fx4", "Iop_PwMax32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwMin32Fx4", "Iop_PwMin32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Abs32Fx4", "Iop_Abs32Fx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Neg32Fx4", "Iop_Neg32Fx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Sqrt32Fx4", "Iop_Sqrt32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128]),
+    ("RecipEst32Fx4", "Iop_RecipEst32Fx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RecipStep32Fx4", "Iop_RecipStep32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("RSqrtEst32Fx4", "Iop_RSqrtEst32Fx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Scale2_32Fx4", "Iop_Scale2_32Fx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Log2_32Fx4", "Iop_Log2_32Fx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RSqrtStep32Fx4", "Iop_RSqrtStep32Fx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("I32UtoF32x4_DEP", "Iop_I32UtoF32x4_DEP", Type::Ity_V128, &[Type::Ity_V128]),
+    ("I32StoF32x4_DEP", "Iop_I32StoF32x4_DEP", Type::Ity_V128, &[Type::Ity_V128]),
+    ("I32StoF32x4", "Iop_I32StoF32x4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128]),
+    ("F32toI32Sx4", "Iop_F32toI32Sx4", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128]),
+    ("F32toI32Ux4_RZ", "Iop_F32toI32Ux4_RZ", Type::Ity_V128, &[Type::Ity_V128]),
+    ("F32toI32Sx4_RZ", "Iop_F32toI32Sx4_RZ", Type::Ity_V128, &[Type::Ity_V128]),
+    ("QF32toI32Ux4_RZ", "Iop_QF32toI32Ux4_RZ", Type::Ity_V128, &[Type::Ity_V128]),
+    ("QF32toI32Sx4_RZ", "Iop_QF32toI32Sx4_RZ", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RoundF32x4_RM", "Iop_RoundF32x4_RM", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RoundF32x4_RP", "Iop_RoundF32x4_RP", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RoundF32x4_RN", "Iop_RoundF32x4_RN", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RoundF32x4_RZ", "Iop_RoundF32x4_RZ", Type::Ity_V128, &[Type::Ity_V128]),
+    ("F32ToFixed32Ux4_RZ", "Iop_F32ToFixed32Ux4_RZ", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("F32ToFixed32Sx4_RZ", "Iop_F32ToFixed32Sx4_RZ", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("Fixed32UToF32x4_RN", "Iop_Fixed32UToF32x4_RN", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("Fixed32SToF32x4_RN", "Iop_Fixed32SToF32x4_RN", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("F32toF16x4_DEP", "Iop_F32toF16x4_DEP", Type::Ity_I64, &[Type::Ity_V128]),
+    ("F32toF16x4", "Iop_F32toF16x4", Type::Ity_I64, &[Type::Ity_I32, Type::Ity_V128]),
+    ("F16toF32x4", "Iop_F16toF32x4", Type::Ity_V128, &[Type::Ity_I64]),
+    ("F64toF16x2_DEP", "Iop_F64toF16x2_DEP", Type::Ity_I32, &[Type::Ity_V128]),
+    ("F16toF64x2", "Iop_F16toF64x2", Type::Ity_V128, &[Type::Ity_I32]),
+    ("F32x4_2toQ16x8", "Iop_F32x4_2toQ16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add32F0x4", "Iop_Add32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub32F0x4", "Iop_Sub32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mul32F0x4", "Iop_Mul32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Div32F0x4", "Iop_Div32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max32F0x4", "Iop_Max32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min32F0x4", "Iop_Min32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ32F0x4", "Iop_CmpEQ32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLT32F0x4", "Iop_CmpLT32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLE32F0x4", "Iop_CmpLE32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpUN32F0x4", "Iop_CmpUN32F0x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("RecipEst32F0x4", "Iop_RecipEst32F0x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Sqrt32F0x4", "Iop_Sqrt32F0x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RSqrtEst32F0x4", "Iop_RSqrtEst32F0x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Add64Fx2", "Iop_Add64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Sub64Fx2", "Iop_Sub64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Mul64Fx2", "Iop_Mul64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Div64Fx2", "Iop_Div64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Max64Fx2", "Iop_Max64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min64Fx2", "Iop_Min64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ64Fx2", "Iop_CmpEQ64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLT64Fx2", "Iop_CmpLT64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLE64Fx2", "Iop_CmpLE64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpUN64Fx2", "Iop_CmpUN64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Abs64Fx2", "Iop_Abs64Fx2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Neg64Fx2", "Iop_Neg64Fx2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Sqrt64Fx2", "Iop_Sqrt64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128]),
+    ("Scale2_64Fx2", "Iop_Scale2_64Fx2", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V128, Type::Ity_V128]),
+    ("Log2_64Fx2", "Iop_Log2_64Fx2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RecipEst64Fx2", "Iop_RecipEst64Fx2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RecipStep64Fx2", "Iop_RecipStep64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("RSqrtEst64Fx2", "Iop_RSqrtEst64Fx2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RSqrtStep64Fx2", "Iop_RSqrtStep64Fx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("F64x2_2toQ32x4", "Iop_F64x2_2toQ32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add64F0x2", "Iop_Add64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub64F0x2", "Iop_Sub64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mul64F0x2", "Iop_Mul64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Div64F0x2", "Iop_Div64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max64F0x2", "Iop_Max64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min64F0x2", "Iop_Min64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ64F0x2", "Iop_CmpEQ64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLT64F0x2", "Iop_CmpLT64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpLE64F0x2", "Iop_CmpLE64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpUN64F0x2", "Iop_CmpUN64F0x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sqrt64F0x2", "Iop_Sqrt64F0x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("V128to64", "Iop_V128to64", Type::Ity_I64, &[Type::Ity_V128]),
+    ("V128HIto64", "Iop_V128HIto64", Type::Ity_I64, &[Type::Ity_V128]),
+    ("64HLtoV128", "Iop_64HLtoV128", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("64UtoV128", "Iop_64UtoV128", Type::Ity_V128, &[Type::Ity_I64]),
+    ("SetV128lo64", "Iop_SetV128lo64", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I64]),
+    ("ZeroHI64ofV128", "Iop_ZeroHI64ofV128", Type::Ity_V128, &[Type::Ity_V128]),
+    ("ZeroHI96ofV128", "Iop_ZeroHI96ofV128", Type::Ity_V128, &[Type::Ity_V128]),
+    ("ZeroHI112ofV128", "Iop_ZeroHI112ofV128", Type::Ity_V128, &[Type::Ity_V128]),
+    ("ZeroHI120ofV128", "Iop_ZeroHI120ofV128", Type::Ity_V128, &[Type::Ity_V128]),
+    ("32UtoV128", "Iop_32UtoV128", Type::Ity_V128, &[Type::Ity_I32]),
+    ("V128to32", "Iop_V128to32", Type::Ity_I32, &[Type::Ity_V128]),
+    ("SetV128lo32", "Iop_SetV128lo32", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I32]),
+    ("NotV128", "Iop_NotV128", Type::Ity_V128, &[Type::Ity_V128]),
+    ("AndV128", "Iop_AndV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("OrV128", "Iop_OrV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("XorV128", "Iop_XorV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("ShlV128", "Iop_ShlV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShrV128", "Iop_ShrV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SarV128", "Iop_SarV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("CmpNEZ8x16", "Iop_CmpNEZ8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("CmpNEZ16x8", "Iop_CmpNEZ16x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("CmpNEZ32x4", "Iop_CmpNEZ32x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("CmpNEZ64x2", "Iop_CmpNEZ64x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("CmpNEZ128x1", "Iop_CmpNEZ128x1", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Add8x16", "Iop_Add8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add16x8", "Iop_Add16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add32x4", "Iop_Add32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add64x2", "Iop_Add64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Add128x1", "Iop_Add128x1", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd8Ux16", "Iop_QAdd8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd16Ux8", "Iop_QAdd16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd32Ux4", "Iop_QAdd32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd64Ux2", "Iop_QAdd64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd8Sx16", "Iop_QAdd8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd16Sx8", "Iop_QAdd16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd32Sx4", "Iop_QAdd32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAdd64Sx2", "Iop_QAdd64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtUSsatSS8x16", "Iop_QAddExtUSsatSS8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtUSsatSS16x8", "Iop_QAddExtUSsatSS16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtUSsatSS32x4", "Iop_QAddExtUSsatSS32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtUSsatSS64x2", "Iop_QAddExtUSsatSS64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtSUsatUU8x16", "Iop_QAddExtSUsatUU8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtSUsatUU16x8", "Iop_QAddExtSUsatUU16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtSUsatUU32x4", "Iop_QAddExtSUsatUU32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QAddExtSUsatUU64x2", "Iop_QAddExtSUsatUU64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub8x16", "Iop_Sub8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub16x8", "Iop_Sub16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub32x4", "Iop_Sub32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub64x2", "Iop_Sub64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sub128x1", "Iop_Sub128x1", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub8Ux16", "Iop_QSub8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub16Ux8", "Iop_QSub16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub32Ux4", "Iop_QSub32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub64Ux2", "Iop_QSub64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub8Sx16", "Iop_QSub8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub16Sx8", "Iop_QSub16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub32Sx4", "Iop_QSub32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSub64Sx2", "Iop_QSub64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mul8x16", "Iop_Mul8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mul16x8", "Iop_Mul16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mul32x4", "Iop_Mul32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi8Ux16", "Iop_MulHi8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi16Ux8", "Iop_MulHi16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi32Ux4", "Iop_MulHi32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi8Sx16", "Iop_MulHi8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi16Sx8", "Iop_MulHi16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulHi32Sx4", "Iop_MulHi32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven8Ux16", "Iop_MullEven8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven16Ux8", "Iop_MullEven16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven32Ux4", "Iop_MullEven32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven8Sx16", "Iop_MullEven8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven16Sx8", "Iop_MullEven16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MullEven32Sx4", "Iop_MullEven32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Mull8Ux8", "Iop_Mull8Ux8", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mull8Sx8", "Iop_Mull8Sx8", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mull16Ux4", "Iop_Mull16Ux4", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mull16Sx4", "Iop_Mull16Sx4", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mull32Ux2", "Iop_Mull32Ux2", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("Mull32Sx2", "Iop_Mull32Sx2", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QDMull16Sx4", "Iop_QDMull16Sx4", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QDMull32Sx2", "Iop_QDMull32Sx2", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("QDMulHi16Sx8", "Iop_QDMulHi16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QDMulHi32Sx4", "Iop_QDMulHi32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QRDMulHi16Sx8", "Iop_QRDMulHi16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QRDMulHi32Sx4", "Iop_QRDMulHi32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PolynomialMul8x16", "Iop_PolynomialMul8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PolynomialMull8x8", "Iop_PolynomialMull8x8", Type::Ity_V128, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PolynomialMulAdd8x16", "Iop_PolynomialMulAdd8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PolynomialMulAdd16x8", "Iop_PolynomialMulAdd16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PolynomialMulAdd32x4", "Iop_PolynomialMulAdd32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PolynomialMulAdd64x2", "Iop_PolynomialMulAdd64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwAdd8x16", "Iop_PwAdd8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwAdd16x8", "Iop_PwAdd16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwAdd32x4", "Iop_PwAdd32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PwAdd32Fx2", "Iop_PwAdd32Fx2", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I64]),
+    ("PwAddL8Ux16", "Iop_PwAddL8Ux16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL16Ux8", "Iop_PwAddL16Ux8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL32Ux4", "Iop_PwAddL32Ux4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL64Ux2", "Iop_PwAddL64Ux2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL8Sx16", "Iop_PwAddL8Sx16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL16Sx8", "Iop_PwAddL16Sx8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwAddL32Sx4", "Iop_PwAddL32Sx4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("PwExtUSMulQAdd8x16", "Iop_PwExtUSMulQAdd8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("BitMatrixTranspose64x2", "Iop_PwBitMtxXpose64x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Abs8x16", "Iop_Abs8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Abs16x8", "Iop_Abs16x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Abs32x4", "Iop_Abs32x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Abs64x2", "Iop_Abs64x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Avg8Ux16", "Iop_Avg8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg16Ux8", "Iop_Avg16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg32Ux4", "Iop_Avg32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg64Ux2", "Iop_Avg64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg8Sx16", "Iop_Avg8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg16Sx8", "Iop_Avg16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg32Sx4", "Iop_Avg32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Avg64Sx2", "Iop_Avg64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max8Sx16", "Iop_Max8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max16Sx8", "Iop_Max16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max32Sx4", "Iop_Max32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max64Sx2", "Iop_Max64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max8Ux16", "Iop_Max8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max16Ux8", "Iop_Max16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max32Ux4", "Iop_Max32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Max64Ux2", "Iop_Max64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min8Sx16", "Iop_Min8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min16Sx8", "Iop_Min16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min32Sx4", "Iop_Min32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min64Sx2", "Iop_Min64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min8Ux16", "Iop_Min8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min16Ux8", "Iop_Min16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min32Ux4", "Iop_Min32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Min64Ux2", "Iop_Min64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ8x16", "Iop_CmpEQ8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ16x8", "Iop_CmpEQ16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ32x4", "Iop_CmpEQ32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpEQ64x2", "Iop_CmpEQ64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT8Sx16", "Iop_CmpGT8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT16Sx8", "Iop_CmpGT16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT32Sx4", "Iop_CmpGT32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT64Sx2", "Iop_CmpGT64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT8Ux16", "Iop_CmpGT8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT16Ux8", "Iop_CmpGT16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT32Ux4", "Iop_CmpGT32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CmpGT64Ux2", "Iop_CmpGT64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Cnt8x16", "Iop_Cnt8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Clz8x16", "Iop_Clz8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Clz16x8", "Iop_Clz16x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Clz32x4", "Iop_Clz32x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Cls8x16", "Iop_Cls8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Cls16x8", "Iop_Cls16x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Cls32x4", "Iop_Cls32x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Ctz8x16", "Iop_Ctz8x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Ctz16x8", "Iop_Ctz16x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Ctz32x4", "Iop_Ctz32x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Ctz64x2", "Iop_Ctz64x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("ShlN8x16", "Iop_ShlN8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShlN16x8", "Iop_ShlN16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShlN32x4", "Iop_ShlN32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShlN64x2", "Iop_ShlN64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShrN8x16", "Iop_ShrN8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShrN16x8", "Iop_ShrN16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShrN32x4", "Iop_ShrN32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("ShrN64x2", "Iop_ShrN64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SarN8x16", "Iop_SarN8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SarN16x8", "Iop_SarN16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SarN32x4", "Iop_SarN32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SarN64x2", "Iop_SarN64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("Shl8x16", "Iop_Shl8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shl16x8", "Iop_Shl16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shl32x4", "Iop_Shl32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shl64x2", "Iop_Shl64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shr8x16", "Iop_Shr8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shr16x8", "Iop_Shr16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shr32x4", "Iop_Shr32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Shr64x2", "Iop_Shr64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sar8x16", "Iop_Sar8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sar16x8", "Iop_Sar16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sar32x4", "Iop_Sar32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sar64x2", "Iop_Sar64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sal8x16", "Iop_Sal8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sal16x8", "Iop_Sal16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sal32x4", "Iop_Sal32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sal64x2", "Iop_Sal64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rol8x16", "Iop_Rol8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rol16x8", "Iop_Rol16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rol32x4", "Iop_Rol32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rol64x2", "Iop_Rol64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QShl8x16", "Iop_QShl8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QShl16x8", "Iop_QShl16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QShl32x4", "Iop_QShl32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QShl64x2", "Iop_QShl64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSal8x16", "Iop_QSal8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSal16x8", "Iop_QSal16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSal32x4", "Iop_QSal32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QSal64x2", "Iop_QSal64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QShlNsatSU8x16", "Iop_QShlNsatSU8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSU16x8", "Iop_QShlNsatSU16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSU32x4", "Iop_QShlNsatSU32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSU64x2", "Iop_QShlNsatSU64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatUU8x16", "Iop_QShlNsatUU8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatUU16x8", "Iop_QShlNsatUU16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatUU32x4", "Iop_QShlNsatUU32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatUU64x2", "Iop_QShlNsatUU64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSS8x16", "Iop_QShlNsatSS8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSS16x8", "Iop_QShlNsatSS16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSS32x4", "Iop_QShlNsatSS32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QShlNsatSS64x2", "Iop_QShlNsatSS64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandUQsh8x16", "Iop_QandUQsh8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQsh16x8", "Iop_QandUQsh16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQsh32x4", "Iop_QandUQsh32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQsh64x2", "Iop_QandUQsh64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQsh8x16", "Iop_QandSQsh8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQsh16x8", "Iop_QandSQsh16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQsh32x4", "Iop_QandSQsh32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQsh64x2", "Iop_QandSQsh64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQRsh8x16", "Iop_QandUQRsh8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQRsh16x8", "Iop_QandUQRsh16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQRsh32x4", "Iop_QandUQRsh32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandUQRsh64x2", "Iop_QandUQRsh64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQRsh8x16", "Iop_QandSQRsh8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQRsh16x8", "Iop_QandSQRsh16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQRsh32x4", "Iop_QandSQRsh32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandSQRsh64x2", "Iop_QandSQRsh64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh8Sx16", "Iop_Sh8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh16Sx8", "Iop_Sh16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh32Sx4", "Iop_Sh32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh64Sx2", "Iop_Sh64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh8Ux16", "Iop_Sh8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh16Ux8", "Iop_Sh16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh32Ux4", "Iop_Sh32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Sh64Ux2", "Iop_Sh64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh8Sx16", "Iop_Rsh8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh16Sx8", "Iop_Rsh16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh32Sx4", "Iop_Rsh32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh64Sx2", "Iop_Rsh64Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh8Ux16", "Iop_Rsh8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh16Ux8", "Iop_Rsh16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh32Ux4", "Iop_Rsh32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Rsh64Ux2", "Iop_Rsh64Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QandQShrNnarrow16Uto8Ux8", "Iop_QandQShrNnarrow16Uto8Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQShrNnarrow32Uto16Ux4", "Iop_QandQShrNnarrow32Uto16Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQShrNnarrow64Uto32Ux2", "Iop_QandQShrNnarrow64Uto32Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow16Sto8Sx8", "Iop_QandQSarNnarrow16Sto8Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow32Sto16Sx4", "Iop_QandQSarNnarrow32Sto16Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow64Sto32Sx2", "Iop_QandQSarNnarrow64Sto32Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow16Sto8Ux8", "Iop_QandQSarNnarrow16Sto8Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow32Sto16Ux4", "Iop_QandQSarNnarrow32Sto16Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQSarNnarrow64Sto32Ux2", "Iop_QandQSarNnarrow64Sto32Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRShrNnarrow16Uto8Ux8", "Iop_QandQRShrNnarrow16Uto8Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRShrNnarrow32Uto16Ux4", "Iop_QandQRShrNnarrow32Uto16Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRShrNnarrow64Uto32Ux2", "Iop_QandQRShrNnarrow64Uto32Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow16Sto8Sx8", "Iop_QandQRSarNnarrow16Sto8Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow32Sto16Sx4", "Iop_QandQRSarNnarrow32Sto16Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow64Sto32Sx2", "Iop_QandQRSarNnarrow64Sto32Sx2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow16Sto8Ux8", "Iop_QandQRSarNnarrow16Sto8Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow32Sto16Ux4", "Iop_QandQRSarNnarrow32Sto16Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QandQRSarNnarrow64Sto32Ux2", "Iop_QandQRSarNnarrow64Sto32Ux2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("QNarrowBin16Sto8Ux16", "Iop_QNarrowBin16Sto8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin32Sto16Ux8", "Iop_QNarrowBin32Sto16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin16Sto8Sx16", "Iop_QNarrowBin16Sto8Sx16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin32Sto16Sx8", "Iop_QNarrowBin32Sto16Sx8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin16Uto8Ux16", "Iop_QNarrowBin16Uto8Ux16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin32Uto16Ux8", "Iop_QNarrowBin32Uto16Ux8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NarrowBin16to8x16", "Iop_NarrowBin16to8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NarrowBin32to16x8", "Iop_NarrowBin32to16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin64Sto32Sx4", "Iop_QNarrowBin64Sto32Sx4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("QNarrowBin64Uto32Ux4", "Iop_QNarrowBin64Uto32Ux4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NarrowBin64to32x4", "Iop_NarrowBin64to32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NarrowUn16to8x8", "Iop_NarrowUn16to8x8", Type::Ity_I64, &[Type::Ity_V128]),
+    ("NarrowUn32to16x4", "Iop_NarrowUn32to16x4", Type::Ity_I64, &[Type::Ity_V128]),
+    ("NarrowUn64to32x2", "Iop_NarrowUn64to32x2", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn16Sto8Sx8", "Iop_QNarrowUn16Sto8Sx8", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn32Sto16Sx4", "Iop_QNarrowUn32Sto16Sx4", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn64Sto32Sx2", "Iop_QNarrowUn64Sto32Sx2", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn16Sto8Ux8", "Iop_QNarrowUn16Sto8Ux8", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn32Sto16Ux4", "Iop_QNarrowUn32Sto16Ux4", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn64Sto32Ux2", "Iop_QNarrowUn64Sto32Ux2", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn16Uto8Ux8", "Iop_QNarrowUn16Uto8Ux8", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn32Uto16Ux4", "Iop_QNarrowUn32Uto16Ux4", Type::Ity_I64, &[Type::Ity_V128]),
+    ("QNarrowUn64Uto32Ux2", "Iop_QNarrowUn64Uto32Ux2", Type::Ity_I64, &[Type::Ity_V128]),
+    ("Widen8Uto16x8", "Iop_Widen8Uto16x8", Type::Ity_V128, &[Type::Ity_I64]),
+    ("Widen16Uto32x4", "Iop_Widen16Uto32x4", Type::Ity_V128, &[Type::Ity_I64]),
+    ("Widen32Uto64x2", "Iop_Widen32Uto64x2", Type::Ity_V128, &[Type::Ity_I64]),
+    ("Widen8Sto16x8", "Iop_Widen8Sto16x8", Type::Ity_V128, &[Type::Ity_I64]),
+    ("Widen16Sto32x4", "Iop_Widen16Sto32x4", Type::Ity_V128, &[Type::Ity_I64]),
+    ("Widen32Sto64x2", "Iop_Widen32Sto64x2", Type::Ity_V128, &[Type::Ity_I64]),
+    ("InterleaveHI8x16", "Iop_InterleaveHI8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveHI16x8", "Iop_InterleaveHI16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveHI32x4", "Iop_InterleaveHI32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveHI64x2", "Iop_InterleaveHI64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveLO8x16", "Iop_InterleaveLO8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveLO16x8", "Iop_InterleaveLO16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveLO32x4", "Iop_InterleaveLO32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveLO64x2", "Iop_InterleaveLO64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveOddLanes8x16", "Iop_InterleaveOddLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveEvenLanes8x16", "Iop_InterleaveEvenLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveOddLanes16x8", "Iop_InterleaveOddLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveEvenLanes16x8", "Iop_InterleaveEvenLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveOddLanes32x4", "Iop_InterleaveOddLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("InterleaveEvenLanes32x4", "Iop_InterleaveEvenLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackOddLanes8x16", "Iop_PackOddLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackEvenLanes8x16", "Iop_PackEvenLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackOddLanes16x8", "Iop_PackOddLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackEvenLanes16x8", "Iop_PackEvenLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackOddLanes32x4", "Iop_PackOddLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PackEvenLanes32x4", "Iop_PackEvenLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatOddLanes8x16", "Iop_CatOddLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatOddLanes16x8", "Iop_CatOddLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatOddLanes32x4", "Iop_CatOddLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatEvenLanes8x16", "Iop_CatEvenLanes8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatEvenLanes16x8", "Iop_CatEvenLanes16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CatEvenLanes32x4", "Iop_CatEvenLanes32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("GetElem8x16", "Iop_GetElem8x16", Type::Ity_I8, &[Type::Ity_V128, Type::Ity_I8]),
+    ("GetElem16x8", "Iop_GetElem16x8", Type::Ity_I16, &[Type::Ity_V128, Type::Ity_I8]),
+    ("GetElem32x4", "Iop_GetElem32x4", Type::Ity_I32, &[Type::Ity_V128, Type::Ity_I8]),
+    ("GetElem64x2", "Iop_GetElem64x2", Type::Ity_I64, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SetElem8x16", "Iop_SetElem8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8, Type::Ity_I8]),
+    ("SetElem16x8", "Iop_SetElem16x8", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8, Type::Ity_I16]),
+    ("SetElem32x4", "Iop_SetElem32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8, Type::Ity_I32]),
+    ("SetElem64x2", "Iop_SetElem64x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8, Type::Ity_I64]),
+    ("Dup8x16", "Iop_Dup8x16", Type::Ity_V128, &[Type::Ity_I8]),
+    ("Dup16x8", "Iop_Dup16x8", Type::Ity_V128, &[Type::Ity_I16]),
+    ("Dup32x4", "Iop_Dup32x4", Type::Ity_V128, &[Type::Ity_I32]),
+    ("SliceV128", "Iop_SliceV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128, Type::Ity_I8]),
+    ("Reverse8sIn16_x8", "Iop_Reverse8sIn16_x8", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse8sIn32_x4", "Iop_Reverse8sIn32_x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse16sIn32_x4", "Iop_Reverse16sIn32_x4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse8sIn64_x2", "Iop_Reverse8sIn64_x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse16sIn64_x2", "Iop_Reverse16sIn64_x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse32sIn64_x2", "Iop_Reverse32sIn64_x2", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Reverse1sIn8_x16", "Iop_Reverse1sIn8_x16", Type::Ity_V128, &[Type::Ity_V128]),
+    ("Perm8x16", "Iop_Perm8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Perm32x4", "Iop_Perm32x4", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("PermOrZero8x16", "Iop_PermOrZero8x16", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("Perm8x16x2", "Iop_Perm8x16x2", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128, Type::Ity_V128]),
+    ("GetMSBs8x16", "Iop_GetMSBs8x16", Type::Ity_I16, &[Type::Ity_V128]),
+    ("RecipEst32Ux4", "Iop_RecipEst32Ux4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("RSqrtEst32Ux4", "Iop_RSqrtEst32Ux4", Type::Ity_V128, &[Type::Ity_V128]),
+    ("MulI128by10", "Iop_MulI128by10", Type::Ity_V128, &[Type::Ity_V128]),
+    ("MulI128by10Carry", "Iop_MulI128by10Carry", Type::Ity_V128, &[Type::Ity_V128]),
+    ("MulI128by10E", "Iop_MulI128by10E", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("MulI128by10ECarry", "Iop_MulI128by10ECarry", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("V256to64_0", "Iop_V256to64_0", Type::Ity_I64, &[Type::Ity_V256]),
+    ("V256to64_1", "Iop_V256to64_1", Type::Ity_I64, &[Type::Ity_V256]),
+    ("V256to64_2", "Iop_V256to64_2", Type::Ity_I64, &[Type::Ity_V256]),
+    ("V256to64_3", "Iop_V256to64_3", Type::Ity_I64, &[Type::Ity_V256]),
+    ("64x4toV256", "Iop_64x4toV256", Type::Ity_V256, &[Type::Ity_I64, Type::Ity_I64, Type::Ity_I64, Type::Ity_I64]),
+    ("V256toV128_0", "Iop_V256toV128_0", Type::Ity_V128, &[Type::Ity_V256]),
+    ("V256toV128_1", "Iop_V256toV128_1", Type::Ity_V128, &[Type::Ity_V256]),
+    ("V128HLtoV256", "Iop_V128HLtoV256", Type::Ity_V256, &[Type::Ity_V128, Type::Ity_V128]),
+    ("AndV256", "Iop_AndV256", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("OrV256", "Iop_OrV256", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("XorV256", "Iop_XorV256", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("NotV256", "Iop_NotV256", Type::Ity_V256, &[Type::Ity_V256]),
+    ("CmpNEZ8x32", "Iop_CmpNEZ8x32", Type::Ity_V256, &[Type::Ity_V256]),
+    ("CmpNEZ16x16", "Iop_CmpNEZ16x16", Type::Ity_V256, &[Type::Ity_V256]),
+    ("CmpNEZ32x8", "Iop_CmpNEZ32x8", Type::Ity_V256, &[Type::Ity_V256]),
+    ("CmpNEZ64x4", "Iop_CmpNEZ64x4", Type::Ity_V256, &[Type::Ity_V256]),
+    ("Add8x32", "Iop_Add8x32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Add16x16", "Iop_Add16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Add32x8", "Iop_Add32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Add64x4", "Iop_Add64x4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Sub8x32", "Iop_Sub8x32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Sub16x16", "Iop_Sub16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Sub32x8", "Iop_Sub32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Sub64x4", "Iop_Sub64x4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpEQ8x32", "Iop_CmpEQ8x32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpEQ16x16", "Iop_CmpEQ16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpEQ32x8", "Iop_CmpEQ32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpEQ64x4", "Iop_CmpEQ64x4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpGT8Sx32", "Iop_CmpGT8Sx32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpGT16Sx16", "Iop_CmpGT16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpGT32Sx8", "Iop_CmpGT32Sx8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CmpGT64Sx4", "Iop_CmpGT64Sx4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("ShlN16x16", "Iop_ShlN16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("ShlN32x8", "Iop_ShlN32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("ShlN64x4", "Iop_ShlN64x4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("ShrN16x16", "Iop_ShrN16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("ShrN32x8", "Iop_ShrN32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("ShrN64x4", "Iop_ShrN64x4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("SarN16x16", "Iop_SarN16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("SarN32x8", "Iop_SarN32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_I8]),
+    ("Max8Sx32", "Iop_Max8Sx32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max16Sx16", "Iop_Max16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max32Sx8", "Iop_Max32Sx8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max8Ux32", "Iop_Max8Ux32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max16Ux16", "Iop_Max16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max32Ux8", "Iop_Max32Ux8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min8Sx32", "Iop_Min8Sx32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min16Sx16", "Iop_Min16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min32Sx8", "Iop_Min32Sx8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min8Ux32", "Iop_Min8Ux32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min16Ux16", "Iop_Min16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min32Ux8", "Iop_Min32Ux8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Mul16x16", "Iop_Mul16x16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Mul32x8", "Iop_Mul32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("MulHi16Ux16", "Iop_MulHi16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("MulHi16Sx16", "Iop_MulHi16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QAdd8Ux32", "Iop_QAdd8Ux32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QAdd16Ux16", "Iop_QAdd16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QAdd8Sx32", "Iop_QAdd8Sx32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QAdd16Sx16", "Iop_QAdd16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QSub8Ux32", "Iop_QSub8Ux32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QSub16Ux16", "Iop_QSub16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QSub8Sx32", "Iop_QSub8Sx32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("QSub16Sx16", "Iop_QSub16Sx16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Avg8Ux32", "Iop_Avg8Ux32", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Avg16Ux16", "Iop_Avg16Ux16", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Perm32x8", "Iop_Perm32x8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("CipherV128", "Iop_CipherV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CipherLV128", "Iop_CipherLV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("CipherSV128", "Iop_CipherSV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NCipherV128", "Iop_NCipherV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("NCipherLV128", "Iop_NCipherLV128", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_V128]),
+    ("SHA512", "Iop_SHA512", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("SHA256", "Iop_SHA256", Type::Ity_V128, &[Type::Ity_V128, Type::Ity_I8]),
+    ("Add64Fx4", "Iop_Add64Fx4", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Sub64Fx4", "Iop_Sub64Fx4", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Mul64Fx4", "Iop_Mul64Fx4", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Div64Fx4", "Iop_Div64Fx4", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Add32Fx8", "Iop_Add32Fx8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Sub32Fx8", "Iop_Sub32Fx8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Mul32Fx8", "Iop_Mul32Fx8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("Div32Fx8", "Iop_Div32Fx8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256, Type::Ity_V256]),
+    ("I32StoF32x8", "Iop_I32StoF32x8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256]),
+    ("F32toI32Sx8", "Iop_F32toI32Sx8", Type::Ity_V256, &[Type::Ity_I32, Type::Ity_V256]),
+    ("F32toF16x8", "Iop_F32toF16x8", Type::Ity_V128, &[Type::Ity_I32, Type::Ity_V256]),
+    ("F16toF32x8", "Iop_F16toF32x8", Type::Ity_V256, &[Type::Ity_V128]),
+    ("Sqrt32Fx8", "Iop_Sqrt32Fx8", Type::Ity_V256, &[Type::Ity_V256]),
+    ("Sqrt64Fx4", "Iop_Sqrt64Fx4", Type::Ity_V256, &[Type::Ity_V256]),
+    ("RecipEst32Fx8", "Iop_RecipEst32Fx8", Type::Ity_V256, &[Type::Ity_V256]),
+    ("RSqrtEst32Fx8", "Iop_RSqrtEst32Fx8", Type::Ity_V256, &[Type::Ity_V256]),
+    ("Max32Fx8", "Iop_Max32Fx8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min32Fx8", "Iop_Min32Fx8", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Max64Fx4", "Iop_Max64Fx4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Min64Fx4", "Iop_Min64Fx4", Type::Ity_V256, &[Type::Ity_V256, Type::Ity_V256]),
+    ("Rotx32", "Iop_Rotx32", Type::Ity_I32, &[Type::Ity_I32, Type::Ity_I8, Type::Ity_I8, Type::Ity_I8]),
+    ("Rotx64", "Iop_Rotx64", Type::Ity_I64, &[Type::Ity_I64, Type::Ity_I8, Type::Ity_I8, Type::Ity_I8]),
+];
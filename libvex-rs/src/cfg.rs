@@ -0,0 +1,336 @@
+//! Control flow graph recovery, by lifting every block reachable from an entry point.
+//!
+//! Blocks are lifted one at a time with [TranslateArgs::front_end] and kept as owned
+//! [Block]s, so the graph outlives the translations. A block is split wherever another
+//! block jumps into its middle, so that every instruction (that isn't overlapped by
+//! another) belongs to exactly one block.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::ir::owned::{Block, Const, Expr, Stmt};
+use crate::ir::JumpKind;
+use crate::{Addr, Arch, TranslateArgs, TranslateResult};
+
+mod functions;
+
+pub use functions::Function;
+
+// The longest instruction of any guest (s390x, amd64 and x86 are the only ones longer
+// than 4 bytes), so that decoding the last instruction of the image never reads past it.
+const PADDING: usize = 16;
+
+/// What an edge does, according to the [JumpKind] of the exit or the `next` of its block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EdgeKind {
+    Boring,
+    Call,
+    Ret,
+    Syscall,
+    /// The block ends with an instruction VEX can't decode; the edge goes to it.
+    NoDecode,
+    /// From a call to its return site, i.e. the instruction after the call.
+    CallReturn,
+    /// Any other jump kind, such as a trap or a client request.
+    Other(JumpKind),
+}
+
+impl From<JumpKind> for EdgeKind {
+    fn from(jk: JumpKind) -> Self {
+        match jk {
+            JumpKind::Ijk_Boring => Self::Boring,
+            JumpKind::Ijk_Call => Self::Call,
+            JumpKind::Ijk_Ret => Self::Ret,
+            JumpKind::Ijk_Sys_syscall => Self::Syscall,
+            JumpKind::Ijk_NoDecode => Self::NoDecode,
+            jk => Self::Other(jk),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Edge {
+    pub from: Addr,
+    /// `None` for indirect jumps (e.g. returns), whose targets are only known at runtime.
+    pub to: Option<Addr>,
+    pub kind: EdgeKind,
+    /// Whether this is a side exit, only taken when its guard holds.
+    pub conditional: bool,
+}
+
+/// A lifted block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub block: Block,
+    /// The number of guest bytes the block's instructions cover.
+    pub len: u64,
+}
+
+impl Node {
+    fn new(addr: Addr, block: Block) -> Self {
+        let end = instructions(&block)
+            .map(|(addr, len)| addr + len as Addr)
+            .max()
+            .unwrap_or(addr);
+        Self {
+            block,
+            len: end - addr,
+        }
+    }
+
+    /// Whether an instruction of this block starts at `addr`.
+    pub fn has_instruction(&self, addr: Addr) -> bool {
+        instructions(&self.block).any(|(insn, _)| insn == addr)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cfg {
+    pub entry: Addr,
+    /// The blocks, by their addresses.
+    pub blocks: BTreeMap<Addr, Node>,
+    pub edges: Vec<Edge>,
+}
+
+impl Cfg {
+    /// Lift every block reachable from `entry`, in the image `bytes` loaded at `base`.
+    ///
+    /// Jumps out of the image are kept as edges, but aren't followed, and neither are
+    /// edges to undecodable instructions.
+    pub fn recover(
+        vta: &mut TranslateArgs,
+        base: Addr,
+        bytes: &[u8],
+        entry: Addr,
+    ) -> TranslateResult<Self> {
+        let mut image = bytes.to_vec();
+        image.resize(bytes.len() + PADDING, 0);
+        let end = base + bytes.len() as Addr;
+        let word64 = matches!(
+            vta.0.arch_guest,
+            Arch::VexArchAMD64
+                | Arch::VexArchARM64
+                | Arch::VexArchPPC64
+                | Arch::VexArchS390X
+                | Arch::VexArchMIPS64
+        );
+
+        let mut blocks: BTreeMap<Addr, Node> = BTreeMap::new();
+        let mut work = vec![entry];
+        while let Some(addr) = work.pop() {
+            if blocks.contains_key(&addr) || addr < base || addr >= end {
+                continue;
+            }
+            // A jump into the middle of a block splits it. The tail is lifted below.
+            if let Some((&start, node)) = blocks
+                .iter_mut()
+                .find(|(_, node)| node.has_instruction(addr))
+            {
+                split(&mut node.block, addr, word64);
+                node.len = addr - start;
+            }
+
+            let irsb = vta.front_end(image[(addr - base) as usize..].as_ptr(), addr)?;
+            let mut block = Block::from(&irsb);
+            drop(irsb);
+            // Blocks end where another one starts, or before an instruction that doesn't
+            // fit in the image.
+            let cut = instructions(&block).find(|&(insn, len)| {
+                insn + len as Addr > end || (insn != addr && blocks.contains_key(&insn))
+            });
+            match cut {
+                Some((insn, _)) if insn == addr => continue,
+                Some((insn, _)) => split(&mut block, insn, word64),
+                None => {}
+            }
+
+            let node = Node::new(addr, block);
+            for edge in edges(addr, &node) {
+                match (edge.to, edge.kind) {
+                    (_, EdgeKind::NoDecode) | (None, _) => {}
+                    (Some(to), _) => work.push(to),
+                }
+            }
+            blocks.insert(addr, node);
+        }
+
+        let edges = blocks
+            .iter()
+            .flat_map(|(&addr, node)| edges(addr, node))
+            .collect();
+        Ok(Self {
+            entry,
+            blocks,
+            edges,
+        })
+    }
+
+    /// The edges out of the block at `addr`.
+    pub fn successors(&self, addr: Addr) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.from == addr)
+    }
+
+    /// The edges into the block at `addr`.
+    pub fn predecessors(&self, addr: Addr) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.to == Some(addr))
+    }
+
+    /// The addresses of jumps out of the image, or to blocks that weren't lifted.
+    pub fn external(&self) -> BTreeSet<Addr> {
+        self.edges
+            .iter()
+            .filter_map(|edge| edge.to)
+            .filter(|to| !self.blocks.contains_key(to))
+            .collect()
+    }
+}
+
+// The address and length of every instruction in the block.
+fn instructions(block: &Block) -> impl Iterator<Item = (Addr, u32)> + '_ {
+    block.stmts.iter().filter_map(|stmt| match stmt {
+        Stmt::IMark { addr, len, .. } => Some((*addr, *len)),
+        _ => None,
+    })
+}
+
+fn constant(co: &Const) -> Option<Addr> {
+    match *co {
+        Const::U32(addr) => Some(addr as Addr),
+        Const::U64(addr) => Some(addr),
+        _ => None,
+    }
+}
+
+fn edges(from: Addr, node: &Node) -> Vec<Edge> {
+    let block = &node.block;
+    let mut edges: Vec<_> = block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Exit { dst, jump_kind, .. } => Some(Edge {
+                from,
+                to: constant(dst),
+                kind: (*jump_kind).into(),
+                conditional: true,
+            }),
+            _ => None,
+        })
+        .collect();
+    let to = match &block.next {
+        Expr::Const(co) => constant(co),
+        _ => None,
+    };
+    edges.push(Edge {
+        from,
+        to,
+        kind: block.jump_kind.into(),
+        conditional: false,
+    });
+    if block.jump_kind == JumpKind::Ijk_Call {
+        edges.push(Edge {
+            from,
+            to: Some(from + node.len),
+            kind: EdgeKind::CallReturn,
+            conditional: false,
+        });
+    }
+    edges
+}
+
+// Ends the block just before the instruction at `addr`, falling through to it.
+fn split(block: &mut Block, addr: Addr, word64: bool) {
+    let at = block
+        .stmts
+        .iter()
+        .position(|stmt| matches!(stmt, Stmt::IMark { addr: insn, .. } if *insn == addr))
+        .unwrap();
+    block.stmts.truncate(at);
+    block.next = Expr::Const(if word64 {
+        Const::U64(addr)
+    } else {
+        Const::U32(addr as u32)
+    });
+    block.jump_kind = JumpKind::Ijk_Boring;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VexEndness;
+
+    //   0x1000: xor eax, eax
+    //   0x1002: inc eax
+    //   0x1004: cmp eax, edi
+    //   0x1006: jne 0x1002
+    //   0x1008: call 0x1010
+    //   0x100d: ret
+    //   0x100e: ud2
+    //   0x1010: syscall
+    //   0x1012: jmp 0x2000
+    const CODE: &[u8] = &[
+        0x31, 0xc0, 0xff, 0xc0, 0x39, 0xf8, 0x75, 0xfa, 0xe8, 0x03, 0x00, 0x00, 0x00, 0xc3, 0x0f,
+        0x0b, 0x0f, 0x05, 0xe9, 0xe9, 0x0f, 0x00, 0x00,
+    ];
+
+    fn edge(from: Addr, to: Option<Addr>, kind: EdgeKind, conditional: bool) -> Edge {
+        Edge {
+            from,
+            to,
+            kind,
+            conditional,
+        }
+    }
+
+    #[test]
+    fn recover() {
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let cfg = Cfg::recover(&mut vta, 0x1000, CODE, 0x1000).unwrap();
+
+        let addrs: Vec<_> = cfg.blocks.keys().copied().collect();
+        assert_eq!(addrs, [0x1000, 0x1002, 0x1008, 0x100d, 0x1010, 0x1012]);
+        // The loop jumps into the middle of the first block, which is split.
+        assert_eq!(cfg.blocks[&0x1000].len, 2);
+        assert_eq!(
+            cfg.successors(0x1000).collect::<Vec<_>>(),
+            [&edge(0x1000, Some(0x1002), EdgeKind::Boring, false)]
+        );
+        assert_eq!(cfg.predecessors(0x1002).count(), 2);
+
+        let call: Vec<_> = cfg.successors(0x1008).copied().collect();
+        assert_eq!(
+            call,
+            [
+                edge(0x1008, Some(0x1010), EdgeKind::Call, false),
+                edge(0x1008, Some(0x100d), EdgeKind::CallReturn, false),
+            ]
+        );
+        assert_eq!(
+            cfg.successors(0x100d).collect::<Vec<_>>(),
+            [&edge(0x100d, None, EdgeKind::Ret, false)]
+        );
+        assert!(cfg
+            .successors(0x1010)
+            .any(|edge| edge.kind == EdgeKind::Syscall));
+        assert!(cfg.external().contains(&0x2000));
+    }
+
+    #[test]
+    fn image_end() {
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        // `call` is cut off after its first 3 bytes.
+        let cfg = Cfg::recover(&mut vta, 0x1000, &CODE[..0xb], 0x1008).unwrap();
+        assert!(cfg.blocks.is_empty());
+
+        let cfg = Cfg::recover(&mut vta, 0x1000, &CODE[..0xb], 0x1000).unwrap();
+        let last = &cfg.blocks[&0x1002];
+        assert_eq!(last.len, 6);
+        assert!(cfg.external().contains(&0x1008));
+    }
+}
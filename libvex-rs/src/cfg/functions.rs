@@ -0,0 +1,141 @@
+//! Splitting a recovered graph into functions.
+
+use std::collections::BTreeSet;
+
+use super::*;
+
+/// A function, as the blocks reachable from its entry without calling or returning.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    pub entry: Addr,
+    /// The addresses of the function's blocks, including its entry. Blocks may be shared
+    /// by several functions.
+    pub blocks: BTreeSet<Addr>,
+    /// The functions it calls, or tail calls.
+    pub callees: BTreeSet<Addr>,
+}
+
+impl Cfg {
+    /// Split the graph into functions, which start at the entry and at every call target.
+    ///
+    /// A call is an `Ijk_Call`, or a `Boring` jump that VEX marked with an `AbiHint` whose
+    /// next address is the jump's target (as some guests call with plain branches). A
+    /// function is closed by its returns and indirect jumps. A jump to the start of another
+    /// function, or out of the image, is a tail call.
+    pub fn functions(&self) -> Vec<Function> {
+        let starts: BTreeSet<Addr> = std::iter::once(self.entry)
+            .chain(
+                self.edges
+                    .iter()
+                    .filter(|edge| self.is_call(edge))
+                    .filter_map(|edge| edge.to),
+            )
+            .filter(|start| self.blocks.contains_key(start))
+            .collect();
+
+        starts
+            .iter()
+            .map(|&entry| {
+                let mut function = Function {
+                    entry,
+                    blocks: BTreeSet::new(),
+                    callees: BTreeSet::new(),
+                };
+                let mut work = vec![entry];
+                while let Some(addr) = work.pop() {
+                    if !function.blocks.insert(addr) {
+                        continue;
+                    }
+                    for edge in self.successors(addr) {
+                        let to = match (edge.to, edge.kind) {
+                            (Some(to), _) if self.is_call(edge) => {
+                                function.callees.insert(to);
+                                continue;
+                            }
+                            (None, _) | (_, EdgeKind::Ret) | (_, EdgeKind::NoDecode) => continue,
+                            (Some(to), _) => to,
+                        };
+                        let other = to != entry && starts.contains(&to);
+                        if other || !self.blocks.contains_key(&to) {
+                            function.callees.insert(to);
+                        } else {
+                            work.push(to);
+                        }
+                    }
+                }
+                function
+            })
+            .collect()
+    }
+
+    fn is_call(&self, edge: &Edge) -> bool {
+        match edge.kind {
+            EdgeKind::Call => true,
+            EdgeKind::Boring if !edge.conditional => {
+                let to = match edge.to {
+                    Some(to) => to,
+                    None => return false,
+                };
+                let stmts = &self.blocks[&edge.from].block.stmts;
+                stmts.iter().any(|stmt| match stmt {
+                    Stmt::AbiHint {
+                        nia: Expr::Const(nia),
+                        ..
+                    } => constant(nia) == Some(to),
+                    _ => false,
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VexEndness;
+
+    //   0x1000: call 0x1010
+    //   0x1005: call 0x1020
+    //   0x100a: xor eax, eax
+    //   0x100c: ret
+    //   0x1010: test edi, edi
+    //   0x1012: je 0x1015
+    //   0x1014: ret
+    //   0x1015: jmp 0x1020
+    //   0x1020: inc edi
+    //   0x1022: ret
+    const CODE: &[u8] = &[
+        0xe8, 0x0b, 0x00, 0x00, 0x00, 0xe8, 0x16, 0x00, 0x00, 0x00, 0x31, 0xc0, 0xc3, 0xcc, 0xcc,
+        0xcc, 0x85, 0xff, 0x74, 0x01, 0xc3, 0xe9, 0x06, 0x00, 0x00, 0x00, 0xcc, 0xcc, 0xcc, 0xcc,
+        0xcc, 0xcc, 0xff, 0xc7, 0xc3,
+    ];
+
+    fn function(entry: Addr, blocks: &[Addr], callees: &[Addr]) -> Function {
+        Function {
+            entry,
+            blocks: blocks.iter().copied().collect(),
+            callees: callees.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn functions() {
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let cfg = Cfg::recover(&mut vta, 0x1000, CODE, 0x1000).unwrap();
+
+        assert_eq!(
+            cfg.functions(),
+            [
+                function(0x1000, &[0x1000, 0x1005, 0x100a], &[0x1010, 0x1020]),
+                // `jmp 0x1020` is a tail call.
+                function(0x1010, &[0x1010, 0x1014, 0x1015], &[0x1020]),
+                function(0x1020, &[0x1020], &[]),
+            ]
+        );
+    }
+}
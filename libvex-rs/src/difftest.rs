@@ -0,0 +1,224 @@
+//! Differential tests of the interpreter against translated code.
+//!
+//! A block is lifted for the amd64 guest, and run both in the interpreter and natively
+//! (translated for this host, and entered through the `jit` dispatcher), from the same
+//! guest state and memory. Any difference in the states they leave is a bug, either in
+//! the interpreter or in the IR wrappers it is built on.
+
+use vex_sys::Int;
+
+use crate::amd64;
+use crate::amd64::offset::*;
+use crate::interp::{FlatMemory, Guest, Interpreter};
+use crate::ir::JumpKind;
+use crate::jit::{self, Executable};
+use crate::{Addr, Arch, TranslateArgs, VexEndness};
+
+const CODE_ADDR: Addr = 0x1000;
+const RETURN_ADDR: Addr = 0x4000;
+const STACK_SIZE: usize = 0x1000;
+
+fn vta() -> TranslateArgs {
+    TranslateArgs::new(
+        Arch::VexArchAMD64,
+        Arch::VexArchAMD64,
+        VexEndness::VexEndnessLE,
+    )
+}
+
+// Translate `code` and run it once on `state`, returning its `VEX_TRC_JMP_*` code.
+fn native(code: &[u8], state: &mut amd64::State) -> u32 {
+    let mut vta = jit::translate_args(false);
+    let (block, _) =
+        Executable::translate(&mut vta, code.as_ptr(), CODE_ADDR, std::ptr::null_mut()).unwrap();
+    state.0.host_EvC_COUNTER = 1000;
+    unsafe { jit::enter(state, block.entry()).trc as u32 }
+}
+
+// The code the dispatcher is given for leaving with `kind`.
+fn trc(kind: JumpKind) -> u32 {
+    match kind {
+        JumpKind::Ijk_Boring | JumpKind::Ijk_Call | JumpKind::Ijk_Ret => {
+            vex_sys::VEX_TRC_JMP_BORING
+        }
+        JumpKind::Ijk_NoDecode => vex_sys::VEX_TRC_JMP_NODECODE,
+        JumpKind::Ijk_Sys_syscall => vex_sys::VEX_TRC_JMP_SYS_SYSCALL,
+        JumpKind::Ijk_SigTRAP => vex_sys::VEX_TRC_JMP_SIGTRAP,
+        kind => panic!("no dispatcher code for {:?}", kind),
+    }
+}
+
+fn put(state: &mut amd64::State, offset: Int, value: u64) {
+    let offset = offset as usize;
+    state.bytes()[offset..offset + 8].copy_from_slice(&value.to_ne_bytes());
+}
+
+// The differing 8 byte words of two states, named where they are registers.
+fn diff(native: &[u8], interp: &[u8]) -> Vec<String> {
+    let word = |bytes: &[u8], at: usize| {
+        let mut word = [0; 8];
+        let len = (bytes.len() - at).min(8);
+        word[..len].copy_from_slice(&bytes[at..at + len]);
+        u64::from_ne_bytes(word)
+    };
+    (0..native.len())
+        .step_by(8)
+        .filter(|&at| word(native, at) != word(interp, at))
+        .map(|at| {
+            let name = ALL
+                .iter()
+                .find(|&&(_, offset)| offset as usize == at)
+                .map_or_else(|| at.to_string(), |&(name, _)| name.to_owned());
+            format!(
+                "{}: {:#x} (native) != {:#x} (interpreted)",
+                name,
+                word(native, at),
+                word(interp, at)
+            )
+        })
+        .collect()
+}
+
+// Run `code` with the registers in `regs` set, both natively and in the interpreter, and
+// assert that they leave the same state and stack. `rsp` points at a return address.
+fn difftest(code: &[u8], regs: &[(Int, u64)]) {
+    let mut stack = vec![0; STACK_SIZE];
+    let base = stack.as_ptr() as Addr;
+    let rsp = STACK_SIZE / 2;
+    stack[rsp..rsp + 8].copy_from_slice(&RETURN_ADDR.to_ne_bytes());
+
+    let mut state = amd64::State::default();
+    put(&mut state, RSP, base + rsp as Addr);
+    for &(offset, value) in regs {
+        put(&mut state, offset, value);
+    }
+
+    // The translated code accesses the stack at its host address, so the interpreter is
+    // given a copy of it at the same address.
+    let mut interp = Interpreter::new(
+        amd64::State::default(),
+        FlatMemory {
+            base,
+            bytes: stack.clone(),
+        },
+    );
+    interp.state.bytes().copy_from_slice(state.bytes());
+    let jump = {
+        let mut vta = vta();
+        let irsb = vta.front_end(code.as_ptr(), CODE_ADDR).unwrap();
+        unsafe { interp.run(&irsb) }.unwrap()
+    };
+
+    let exit = native(code, &mut state);
+    assert_eq!(exit, trc(jump.kind), "exit of {:x?}", code);
+    // Only the native run counts down the event check counter.
+    state.0.host_EvC_FAILADDR = interp.state.0.host_EvC_FAILADDR;
+    state.0.host_EvC_COUNTER = interp.state.0.host_EvC_COUNTER;
+    let diffs = diff(state.bytes(), interp.state.bytes());
+    assert!(
+        diffs.is_empty(),
+        "{:x?} with {:x?}: {:#?}",
+        code,
+        regs,
+        diffs
+    );
+    assert!(stack == interp.memory.bytes, "stacks of {:x?} differ", code);
+}
+
+// Register values that are interesting to compare, add and divide by.
+const VALUES: &[u64] = &[
+    0,
+    1,
+    7,
+    0x7fff_ffff,
+    0x8000_0000,
+    0x1234_5678_9abc_def0,
+    0x7fff_ffff_ffff_ffff,
+    0x8000_0000_0000_0000,
+    u64::MAX,
+];
+
+fn pairs() -> impl Iterator<Item = (u64, u64)> {
+    VALUES
+        .iter()
+        .flat_map(|&a| VALUES.iter().map(move |&b| (a, b)))
+}
+
+#[test]
+fn arithmetic() {
+    //   mov rax, rdi
+    //   add rax, rsi
+    //   imul rax, rdx
+    //   shl rax, 3
+    //   sar rsi, 2
+    //   sub rdi, rax
+    //   ret
+    let code = [
+        0x48, 0x89, 0xf8, 0x48, 0x01, 0xf0, 0x48, 0x0f, 0xaf, 0xc2, 0x48, 0xc1, 0xe0, 0x03, 0x48,
+        0xc1, 0xfe, 0x02, 0x48, 0x29, 0xc7, 0xc3,
+    ];
+    for (a, b) in pairs() {
+        difftest(&code, &[(RDI, a), (RSI, b), (RDX, a ^ b)]);
+    }
+}
+
+#[test]
+fn flags() {
+    //   push rbx
+    //   mov rbx, [rsp]
+    //   cmp rdi, rsi
+    //   setl al
+    //   cmovb rcx, rdx
+    //   pop rdx
+    //   ret
+    let code = [
+        0x53, 0x48, 0x8b, 0x1c, 0x24, 0x48, 0x39, 0xf7, 0x0f, 0x9c, 0xc0, 0x48, 0x0f, 0x42, 0xca,
+        0x5a, 0xc3,
+    ];
+    for (a, b) in pairs() {
+        difftest(&code, &[(RDI, a), (RSI, b), (RBX, 0x55), (RDX, 0x66)]);
+    }
+}
+
+#[test]
+fn division() {
+    //   mov rax, rdi
+    //   xor edx, edx
+    //   div rsi
+    //   ret
+    let code = [0x48, 0x89, 0xf8, 0x31, 0xd2, 0x48, 0xf7, 0xf6, 0xc3];
+    for (a, b) in pairs().filter(|&(_, b)| b != 0) {
+        difftest(&code, &[(RDI, a), (RSI, b)]);
+    }
+}
+
+#[test]
+fn vectors() {
+    //   movq xmm0, rdi
+    //   movq xmm1, rsi
+    //   punpcklqdq xmm0, xmm1
+    //   paddd xmm0, xmm1
+    //   pxor xmm1, xmm0
+    //   pcmpeqd xmm2, xmm0
+    //   movq rax, xmm1
+    //   ret
+    let code = [
+        0x66, 0x48, 0x0f, 0x6e, 0xc7, 0x66, 0x48, 0x0f, 0x6e, 0xce, 0x66, 0x0f, 0x6c, 0xc1, 0x66,
+        0x0f, 0xfe, 0xc1, 0x66, 0x0f, 0xef, 0xc8, 0x66, 0x0f, 0x76, 0xd0, 0x66, 0x48, 0x0f, 0x7e,
+        0xc8, 0xc3,
+    ];
+    for (a, b) in pairs() {
+        difftest(&code, &[(RDI, a), (RSI, b)]);
+    }
+}
+
+#[test]
+fn branch() {
+    //   0x1000: lea rax, [rdi+rsi]
+    //   0x1004: cmp rax, 0x10
+    //   0x1008: jb 0x101a
+    let code = [0x48, 0x8d, 0x04, 0x37, 0x48, 0x83, 0xf8, 0x10, 0x72, 0x10];
+    for (a, b) in pairs() {
+        difftest(&code, &[(RDI, a), (RSI, b)]);
+    }
+}
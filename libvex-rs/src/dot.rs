@@ -0,0 +1,301 @@
+//! Graphviz DOT export of blocks and control flow graphs, to be rendered with `dot`.
+//!
+//! Each block is a record node with a field per guest instruction (i.e. per `IMark`), and
+//! every exit of the block is an edge from the field of the instruction it belongs to.
+
+use std::fmt::Write;
+
+use vex_sys::Int;
+
+use crate::cfg::{Cfg, EdgeKind};
+use crate::ir::owned::{Const, Expr, Stmt};
+use crate::ir::{JumpKind, IRSB};
+use crate::{Addr, Arch};
+
+/// How blocks are drawn.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Options {
+    /// Name the registers of this guest in `PUT` and `GET`, instead of showing offsets.
+    pub registers: Option<Arch>,
+}
+
+impl IRSB<'_> {
+    /// A DOT graph of this block, with its exits going to (unlifted) addresses.
+    pub fn to_dot(&self, options: &Options) -> String {
+        let block = Record::new(self, options);
+        let mut dot = header();
+        block.write_node(&mut dot, "block");
+        let exits = block.exits.iter().map(|&(port, to, jk)| {
+            let kind = EdgeKind::from(jk);
+            (port, to, label(kind), "dashed")
+        });
+        let next = (
+            block.last(),
+            block.next,
+            label(block.jump_kind.into()),
+            "solid",
+        );
+        for (i, (port, to, label, style)) in exits.chain(Some(next)).enumerate() {
+            let target = match to {
+                Some(to) => format!("a{:x}", to),
+                None => format!("indirect{}", i),
+            };
+            write_target(&mut dot, &target, to);
+            writeln!(
+                dot,
+                "    block:{} -> {} [label=\"{}\", style={}];",
+                port, target, label, style
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Cfg {
+    /// A DOT graph of every block, and of the edges between them. Jumps out of the graph
+    /// go to plain address nodes.
+    pub fn to_dot(&self, options: &Options) -> String {
+        let mut dot = header();
+        for (&addr, node) in &self.blocks {
+            let record = Record::new(&node.block.to_irsb(), options);
+            record.write_node(&mut dot, &format!("b{:x}", addr));
+
+            // The side exits are the conditional edges, in order.
+            let mut exits = record.exits.iter();
+            for (i, edge) in self.successors(addr).enumerate() {
+                let port = match (edge.conditional, exits.next()) {
+                    (true, Some(&(port, ..))) => port,
+                    _ => record.last(),
+                };
+                let target = match edge.to {
+                    Some(to) if self.blocks.contains_key(&to) => format!("b{:x}", to),
+                    Some(to) => format!("a{:x}", to),
+                    None => format!("indirect{:x}_{}", addr, i),
+                };
+                if !matches!(edge.to, Some(to) if self.blocks.contains_key(&to)) {
+                    write_target(&mut dot, &target, edge.to);
+                }
+                let style = match edge.kind {
+                    EdgeKind::CallReturn => "dotted",
+                    _ if edge.conditional => "dashed",
+                    _ => "solid",
+                };
+                writeln!(
+                    dot,
+                    "    b{:x}:{} -> {} [label=\"{}\", style={}];",
+                    addr,
+                    port,
+                    target,
+                    label(edge.kind),
+                    style
+                )
+                .unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn header() -> String {
+    "digraph {\n    node [shape=record, fontname=\"monospace\"];\n".to_owned()
+}
+
+fn write_target(dot: &mut String, node: &str, addr: Option<Addr>) {
+    let label = match addr {
+        Some(addr) => format!("{:#x}", addr),
+        None => "?".to_owned(),
+    };
+    writeln!(dot, "    {} [label=\"{}\", shape=plaintext];", node, label).unwrap();
+}
+
+fn label(kind: EdgeKind) -> String {
+    match kind {
+        EdgeKind::CallReturn => "return site".to_owned(),
+        EdgeKind::Other(jk) => format!("{:?}", jk)["Ijk_".len()..].to_owned(),
+        kind => format!("{:?}", kind),
+    }
+}
+
+// A block's instructions, and where its exits leave from.
+struct Record {
+    // The text of each instruction's statements, starting with its `IMark`.
+    fields: Vec<Vec<String>>,
+    exits: Vec<(usize, Option<Addr>, JumpKind)>,
+    next: Option<Addr>,
+    jump_kind: JumpKind,
+}
+
+impl Record {
+    fn new(irsb: &IRSB, options: &Options) -> Self {
+        let registers = options.registers.map_or(&[][..], registers);
+        let mut fields: Vec<Vec<String>> = Vec::new();
+        let mut exits = Vec::new();
+        for stmt in irsb.iter_stmts() {
+            let text = name_registers(&stmt.to_string(), registers);
+            let stmt = Stmt::from(stmt);
+            match (&stmt, fields.last_mut()) {
+                (Stmt::IMark { .. }, _) | (_, None) => fields.push(vec![text]),
+                (_, Some(field)) => field.push(text),
+            }
+            if let Stmt::Exit { dst, jump_kind, .. } = stmt {
+                exits.push((fields.len() - 1, address(&dst), jump_kind));
+            }
+        }
+        let next = match Expr::from(irsb.next()) {
+            Expr::Const(co) => address(&co),
+            _ => None,
+        };
+        if fields.is_empty() {
+            fields.push(vec![]);
+        }
+        Self {
+            fields,
+            exits,
+            next,
+            jump_kind: irsb.jump_kind(),
+        }
+    }
+
+    // The port of the last instruction, where `next` leaves from.
+    fn last(&self) -> usize {
+        self.fields.len() - 1
+    }
+
+    fn write_node(&self, dot: &mut String, id: &str) {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(port, lines)| {
+                let text: String = lines.iter().map(|line| escape(line) + "\\l").collect();
+                format!("<{}>{}", port, text)
+            })
+            .collect();
+        writeln!(dot, "    {} [label=\"{{{}}}\"];", id, fields.join("|")).unwrap();
+    }
+}
+
+// Escapes the characters that are special in record labels.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        if "{}|<>\"\\".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn address(co: &Const) -> Option<Addr> {
+    match *co {
+        Const::U32(addr) => Some(addr as Addr),
+        Const::U64(addr) => Some(addr),
+        _ => None,
+    }
+}
+
+fn registers(arch: Arch) -> &'static [(&'static str, Int)] {
+    match arch {
+        Arch::VexArchX86 => crate::x86::offset::ALL,
+        Arch::VexArchAMD64 => crate::amd64::offset::ALL,
+        Arch::VexArchARM => crate::arm::offset::ALL,
+        Arch::VexArchARM64 => crate::arm64::offset::ALL,
+        Arch::VexArchPPC32 => crate::ppc32::offset::ALL,
+        Arch::VexArchPPC64 => crate::ppc64::offset::ALL,
+        Arch::VexArchS390X => crate::s390x::offset::ALL,
+        Arch::VexArchMIPS32 => crate::mips32::offset::ALL,
+        Arch::VexArchMIPS64 => crate::mips64::offset::ALL,
+        _ => &[],
+    }
+}
+
+// Replaces the offsets in `PUT(16)` and `GET:I64(16)` with the names of the registers at
+// them, in lowercase (as `IRSB!` accepts them with an `arch` header).
+fn name_registers(text: &str, registers: &[(&str, Int)]) -> String {
+    if registers.is_empty() {
+        return text.to_owned();
+    }
+    let mut named = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = ["PUT(", "GET:"]
+        .iter()
+        .filter_map(|access| rest.find(access))
+        .min()
+    {
+        let open = at + rest[at..].find('(').unwrap() + 1;
+        named.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let name = rest[..digits].parse::<Int>().ok().and_then(|offset| {
+            registers
+                .iter()
+                .find(|&&(_, reg)| reg == offset)
+                .map(|(name, _)| name.to_lowercase())
+        });
+        if let Some(name) = name {
+            named.push_str(&name);
+            rest = &rest[digits..];
+        }
+    }
+    named.push_str(rest);
+    named
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{TranslateArgs, VexEndness};
+
+    #[test]
+    fn text() {
+        let registers = registers(Arch::VexArchAMD64);
+        assert_eq!(
+            name_registers("PUT(184) = GET:I64(48)", registers),
+            "PUT(rip) = GET:I64(rsp)"
+        );
+        assert_eq!(
+            name_registers("if (t2) { PUT(185) = 0x0:I64; exit-Boring }", registers),
+            "if (t2) { PUT(185) = 0x0:I64; exit-Boring }"
+        );
+        assert_eq!(
+            escape("  t7 = ( STle-Cond(t1) = t0 )"),
+            "t7 = ( STle-Cond(t1) = t0 )"
+        );
+        assert_eq!(escape("if (t2) { PUT(184) }"), "if (t2) \\{ PUT(184) \\}");
+    }
+
+    #[test]
+    fn cfg() {
+        // 0x1000: test edi, edi; je 0x1005; ret; 0x1005: jmp 0x2000
+        let code = [0x85, 0xff, 0x74, 0x01, 0xc3, 0xe9, 0xf6, 0x0f, 0x00, 0x00];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let cfg = Cfg::recover(&mut vta, 0x1000, &code, 0x1000).unwrap();
+        let options = Options {
+            registers: Some(Arch::VexArchAMD64),
+        };
+        let dot = cfg.to_dot(&options);
+
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("b1000 [label=\"{<0>------ IMark(0x1000, 2, 0) ------\\l"));
+        assert!(dot.contains("PUT(rip)"));
+        assert!(dot.contains("a2000 [label=\"0x2000\", shape=plaintext];"));
+        assert!(dot.contains("b1005:0 -> a2000 [label=\"Boring\", style=solid];"));
+        assert!(dot.contains("[label=\"Ret\", style=solid];"));
+        assert!(dot.contains("style=dashed"));
+
+        let irsb = cfg.blocks[&0x1000].block.to_irsb();
+        let dot = irsb.to_dot(&Options::default());
+        assert!(dot.contains("block:1 -> "));
+        assert!(!dot.contains("PUT(rip)"));
+    }
+}
@@ -0,0 +1,704 @@
+//! A concrete interpreter for the IR, which runs lifted blocks without generating host
+//! code.
+//!
+//! Blocks run against a guest state (such as [amd64::State](crate::amd64::State)) and a
+//! [Memory]. Clean helpers (e.g. `amd64g_calculate_condition`) and dirty helpers which
+//! only access the guest state are called natively, through the addresses VEX gave them,
+//! which is why [Interpreter::run] is unsafe.
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+use std::{mem, slice};
+
+use libc::c_void;
+
+use vex_sys::{typeOfIRLoadGOp, IRExprTag, Int};
+
+use crate::ir::owned::Const;
+use crate::ir::{
+    Callee, Effect, Expr, ExprEnum, IREndness, JumpKind, LoadGOp, Op, RegArray, Stmt, StmtEnum,
+    Temp, Type, IRSB,
+};
+use crate::Addr;
+
+mod op;
+
+/// The byte order of the guest state, which is the host's.
+const HOST: IREndness = if cfg!(target_endian = "big") {
+    IREndness::Iend_BE
+} else {
+    IREndness::Iend_LE
+};
+
+/// A value of the IR.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Value {
+    I1(bool),
+    I8(u8),
+    I16(u16),
+    I32(u32),
+    I64(u64),
+    I128(u128),
+    F32(f32),
+    F64(f64),
+    V128(u128),
+    /// The low half, then the high half.
+    V256([u128; 2]),
+}
+
+impl Value {
+    pub fn ty(&self) -> Type {
+        match self {
+            Self::I1(_) => Type::Ity_I1,
+            Self::I8(_) => Type::Ity_I8,
+            Self::I16(_) => Type::Ity_I16,
+            Self::I32(_) => Type::Ity_I32,
+            Self::I64(_) => Type::Ity_I64,
+            Self::I128(_) => Type::Ity_I128,
+            Self::F32(_) => Type::Ity_F32,
+            Self::F64(_) => Type::Ity_F64,
+            Self::V128(_) => Type::Ity_V128,
+            Self::V256(_) => Type::Ity_V256,
+        }
+    }
+
+    /// The value of type `ty` with the given bits, truncated to its width. `None` for
+    /// `V256` and for the types the interpreter doesn't support.
+    pub fn from_bits(ty: Type, bits: u128) -> Option<Self> {
+        Some(match ty {
+            Type::Ity_I1 => Self::I1(bits & 1 != 0),
+            Type::Ity_I8 => Self::I8(bits as u8),
+            Type::Ity_I16 => Self::I16(bits as u16),
+            Type::Ity_I32 => Self::I32(bits as u32),
+            Type::Ity_I64 => Self::I64(bits as u64),
+            Type::Ity_I128 => Self::I128(bits),
+            Type::Ity_F32 => Self::F32(f32::from_bits(bits as u32)),
+            Type::Ity_F64 => Self::F64(f64::from_bits(bits as u64)),
+            Type::Ity_V128 => Self::V128(bits),
+            _ => return None,
+        })
+    }
+
+    /// The bits of this value, zero extended. Only the low half of a `V256` fits.
+    pub fn bits(&self) -> u128 {
+        match *self {
+            Self::I1(val) => val as u128,
+            Self::I8(val) => val as u128,
+            Self::I16(val) => val as u128,
+            Self::I32(val) => val as u128,
+            Self::I64(val) => val as u128,
+            Self::I128(val) | Self::V128(val) => val,
+            Self::F32(val) => val.to_bits() as u128,
+            Self::F64(val) => val.to_bits() as u128,
+            Self::V256([lo, _]) => lo,
+        }
+    }
+
+    // `bytes` must be as long as `ty` is.
+    fn from_bytes(ty: Type, bytes: &[u8], end: IREndness) -> Self {
+        let mut bytes = bytes.to_vec();
+        if end == IREndness::Iend_BE {
+            bytes.reverse();
+        }
+        let le = |bytes: &[u8]| bytes.iter().rev().fold(0, |acc, &b| acc << 8 | b as u128);
+        match ty {
+            Type::Ity_V256 => Self::V256([le(&bytes[..16]), le(&bytes[16..])]),
+            ty => Self::from_bits(ty, le(&bytes)).unwrap(),
+        }
+    }
+
+    fn to_bytes(self, end: IREndness) -> Result<Vec<u8>, InterpError> {
+        let mut bytes = match self {
+            Self::V256([lo, hi]) => [lo.to_le_bytes(), hi.to_le_bytes()].concat(),
+            value => value.bits().to_le_bytes()[..size(value.ty())?].to_vec(),
+        };
+        if end == IREndness::Iend_BE {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+}
+
+impl From<Const> for Value {
+    fn from(co: Const) -> Self {
+        // Each bit of a vector constant is a whole byte.
+        let bytes = |mask: u32, n: u32| {
+            (0..n)
+                .filter(|&i| mask & (1 << i) != 0)
+                .fold(0, |acc, i| acc | 0xff << (i * 8))
+        };
+        match co {
+            Const::U1(val) => Self::I1(val),
+            Const::U8(val) => Self::I8(val),
+            Const::U16(val) => Self::I16(val),
+            Const::U32(val) => Self::I32(val),
+            Const::U64(val) => Self::I64(val),
+            Const::F32(val) | Const::F32i(val) => Self::F32(f32::from_bits(val)),
+            Const::F64(val) | Const::F64i(val) => Self::F64(f64::from_bits(val)),
+            Const::V128(mask) => Self::V128(bytes(mask as u32, 16)),
+            Const::V256(mask) => Self::V256([bytes(mask, 16), bytes(mask >> 16, 16)]),
+        }
+    }
+}
+
+// The size in bytes of values of type `ty`, in memory and in the guest state.
+fn size(ty: Type) -> Result<usize, InterpError> {
+    match ty {
+        Type::Ity_I8 => Ok(1),
+        Type::Ity_I16 => Ok(2),
+        Type::Ity_I32 | Type::Ity_F32 => Ok(4),
+        Type::Ity_I64 | Type::Ity_F64 => Ok(8),
+        Type::Ity_I128 | Type::Ity_V128 => Ok(16),
+        Type::Ity_V256 => Ok(32),
+        ty => Err(InterpError::UnsupportedType(ty)),
+    }
+}
+
+/// A problem which stopped [Interpreter::run].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterpError {
+    /// A memory access the [Memory] refused.
+    MemoryFault {
+        addr: Addr,
+        size: usize,
+    },
+    /// A `GET` or `PUT` outside of the guest state.
+    BadOffset(Int),
+    /// A temp that is read before it is assigned.
+    UndefinedTemp(Temp),
+    UnsupportedOp(Op),
+    UnsupportedType(Type),
+    /// A helper that can't be called: one with too many arguments, or a dirty helper which
+    /// accesses memory or returns a vector.
+    UnsupportedHelper(String),
+    /// An integer division by zero.
+    DivideByZero,
+}
+
+impl Display for InterpError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MemoryFault { addr, size } => {
+                write!(f, "can't access {} bytes at {:#x}", size, addr)
+            }
+            Self::BadOffset(offset) => write!(f, "offset {} is outside the guest state", offset),
+            Self::UndefinedTemp(tmp) => write!(f, "t{} is used before it is assigned", tmp),
+            Self::UnsupportedOp(op) => write!(f, "unsupported op {:?}", op),
+            Self::UnsupportedType(ty) => write!(f, "unsupported type {:?}", ty),
+            Self::UnsupportedHelper(name) => write!(f, "can't call helper {}", name),
+            Self::DivideByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for InterpError {}
+
+/// A guest state the interpreter can run blocks on.
+pub trait Guest {
+    /// The bytes of the state, which `GET` and `PUT` offsets index.
+    fn bytes(&mut self) -> &mut [u8];
+}
+
+macro_rules! guests {
+    ($($arch:ident),*) => {
+        $(
+            impl Guest for crate::$arch::State {
+                fn bytes(&mut self) -> &mut [u8] {
+                    // The states are plain C structs, padded explicitly.
+                    unsafe {
+                        slice::from_raw_parts_mut(
+                            &mut self.0 as *mut _ as *mut u8,
+                            mem::size_of_val(&self.0),
+                        )
+                    }
+                }
+            }
+        )*
+    };
+}
+
+guests!(amd64, arm, arm64, mips32, mips64, ppc32, ppc64, s390x, x86);
+
+/// The guest's memory.
+pub trait Memory {
+    /// Read `buf.len()` bytes at `addr`.
+    fn read(&mut self, addr: Addr, buf: &mut [u8]) -> Result<(), InterpError>;
+
+    /// Write `data` at `addr`.
+    fn write(&mut self, addr: Addr, data: &[u8]) -> Result<(), InterpError>;
+}
+
+/// A single contiguous range of memory, e.g. an image with room for its stack.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlatMemory {
+    pub base: Addr,
+    pub bytes: Vec<u8>,
+}
+
+impl FlatMemory {
+    fn range(&self, addr: Addr, size: usize) -> Result<Range<usize>, InterpError> {
+        let start = addr.wrapping_sub(self.base) as usize;
+        match start.checked_add(size) {
+            Some(end) if addr >= self.base && end <= self.bytes.len() => Ok(start..end),
+            _ => Err(InterpError::MemoryFault { addr, size }),
+        }
+    }
+}
+
+impl Memory for FlatMemory {
+    fn read(&mut self, addr: Addr, buf: &mut [u8]) -> Result<(), InterpError> {
+        let range = self.range(addr, buf.len())?;
+        buf.copy_from_slice(&self.bytes[range]);
+        Ok(())
+    }
+
+    fn write(&mut self, addr: Addr, data: &[u8]) -> Result<(), InterpError> {
+        let range = self.range(addr, data.len())?;
+        self.bytes[range].copy_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Where a block jumped to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Jump {
+    pub next: Addr,
+    pub kind: JumpKind,
+}
+
+/// Runs blocks on a guest state and memory.
+///
+/// Ops follow their descriptions in `libvex_ir.h`. Floating point rounding modes are only
+/// honoured when converting to integers; everything else rounds to nearest.
+pub struct Interpreter<G, M> {
+    pub state: G,
+    pub memory: M,
+}
+
+impl<G: Guest, M: Memory> Interpreter<G, M> {
+    pub fn new(state: G, memory: M) -> Self {
+        Self { state, memory }
+    }
+
+    /// Run `irsb` until its end, or until an exit is taken.
+    ///
+    /// The guest's instruction pointer (at the `offs_ip` of the block or of the exit) is
+    /// set to the returned address, as VEX's dispatcher would.
+    ///
+    /// # Safety
+    /// Helpers are called at the addresses in their callees, so these must be the helpers
+    /// VEX gave this process, as in blocks it lifted. A block parsed, deserialised or built
+    /// with the macros can call anything.
+    pub unsafe fn run(&mut self, irsb: &IRSB) -> Result<Jump, InterpError> {
+        let env = irsb.type_env();
+        let mut frame = Frame {
+            interp: self,
+            types: env.types(),
+            temps: vec![None; env.types().len()],
+        };
+        for stmt in irsb.iter_stmts() {
+            if let Some(jump) = frame.stmt(stmt)? {
+                return Ok(jump);
+            }
+        }
+        let next = frame.expr(irsb.next())?;
+        let offs_ip = unsafe { (*irsb.inner).offsIP };
+        frame.jump(next, irsb.jump_kind(), offs_ip)
+    }
+}
+
+// The state of a single run of a block.
+struct Frame<'i, G, M> {
+    interp: &'i mut Interpreter<G, M>,
+    types: &'i [Type],
+    temps: Vec<Option<Value>>,
+}
+
+impl<G: Guest, M: Memory> Frame<'_, G, M> {
+    fn def(&mut self, tmp: Temp, value: Value) -> Result<(), InterpError> {
+        let slot = self
+            .temps
+            .get_mut(tmp as usize)
+            .ok_or(InterpError::UndefinedTemp(tmp))?;
+        *slot = Some(value);
+        Ok(())
+    }
+
+    fn tmp_type(&self, tmp: Temp) -> Result<Type, InterpError> {
+        let ty = self.types.get(tmp as usize).copied();
+        ty.ok_or(InterpError::UndefinedTemp(tmp))
+    }
+
+    fn guest_range(&mut self, offset: Int, ty: Type) -> Result<Range<usize>, InterpError> {
+        let size = size(ty)?;
+        let len = self.interp.state.bytes().len();
+        match usize::try_from(offset) {
+            Ok(start) if start + size <= len => Ok(start..start + size),
+            _ => Err(InterpError::BadOffset(offset)),
+        }
+    }
+
+    fn get(&mut self, offset: Int, ty: Type) -> Result<Value, InterpError> {
+        let range = self.guest_range(offset, ty)?;
+        let bytes = &self.interp.state.bytes()[range];
+        Ok(Value::from_bytes(ty, bytes, HOST))
+    }
+
+    fn put(&mut self, offset: Int, value: Value) -> Result<(), InterpError> {
+        let range = self.guest_range(offset, value.ty())?;
+        self.interp.state.bytes()[range].copy_from_slice(&value.to_bytes(HOST)?);
+        Ok(())
+    }
+
+    fn load(&mut self, addr: Addr, ty: Type, end: IREndness) -> Result<Value, InterpError> {
+        let mut bytes = vec![0; size(ty)?];
+        self.interp.memory.read(addr, &mut bytes)?;
+        Ok(Value::from_bytes(ty, &bytes, end))
+    }
+
+    fn store(&mut self, addr: Addr, value: Value, end: IREndness) -> Result<(), InterpError> {
+        self.interp.memory.write(addr, &value.to_bytes(end)?)
+    }
+
+    fn addr(&mut self, expr: Expr) -> Result<Addr, InterpError> {
+        Ok(self.expr(expr)?.bits() as Addr)
+    }
+
+    fn guard(&mut self, expr: Expr) -> Result<bool, InterpError> {
+        Ok(self.expr(expr)? == Value::I1(true))
+    }
+
+    fn jump(&mut self, next: Value, kind: JumpKind, offs_ip: Int) -> Result<Jump, InterpError> {
+        self.put(offs_ip, next)?;
+        Ok(Jump {
+            next: next.bits() as Addr,
+            kind,
+        })
+    }
+
+    fn expr(&mut self, expr: Expr) -> Result<Value, InterpError> {
+        let op = |op: Op, args: &[Value]| op::apply(op, args);
+        match expr.as_enum() {
+            ExprEnum::Binder(_) => unreachable!("binders only appear in VEX's patterns"),
+            ExprEnum::Get(get) => self.get(get.offset(), get.ty()),
+            ExprEnum::GetI(mut get_i) => {
+                let ix = self.expr(get_i.ix())?;
+                let descr = get_i.descr();
+                self.get(element(descr, ix, get_i.bias())?, descr.elem_ty())
+            }
+            ExprEnum::RdTmp(rd_tmp) => {
+                let tmp = rd_tmp.tmp();
+                let value = self.temps.get(tmp as usize).copied().flatten();
+                value.ok_or(InterpError::UndefinedTemp(tmp))
+            }
+            ExprEnum::Qop(qop) => {
+                let args = [qop.arg1(), qop.arg2(), qop.arg3(), qop.arg4()];
+                op(qop.op(), &self.exprs(&args)?)
+            }
+            ExprEnum::Triop(triop) => {
+                let args = [triop.arg1(), triop.arg2(), triop.arg3()];
+                op(triop.op(), &self.exprs(&args)?)
+            }
+            ExprEnum::Binop(binop) => {
+                let args = [binop.arg1(), binop.arg2()];
+                op(binop.op(), &self.exprs(&args)?)
+            }
+            ExprEnum::Unop(unop) => op(unop.op(), &self.exprs(&[unop.arg()])?),
+            ExprEnum::Load(load) => {
+                let addr = self.addr(load.addr())?;
+                self.load(addr, load.ty(), load.end())
+            }
+            ExprEnum::Const(co) => Ok(Const::from(co).into()),
+            ExprEnum::CCall(ccall) => {
+                let args = ccall.args();
+                let args: Vec<_> = args.iter().collect();
+                let args: Vec<_> = self
+                    .exprs(&args)?
+                    .iter()
+                    .map(|arg| arg.bits() as u64)
+                    .collect();
+                let ret = call(ccall.callee(), &args)?;
+                Value::from_bits(ccall.ret_ty(), ret as u128)
+                    .ok_or(InterpError::UnsupportedType(ccall.ret_ty()))
+            }
+            ExprEnum::ITE(ite) => {
+                if self.guard(ite.cond())? {
+                    self.expr(ite.if_true())
+                } else {
+                    self.expr(ite.if_false())
+                }
+            }
+        }
+    }
+
+    fn exprs(&mut self, exprs: &[Expr]) -> Result<Vec<Value>, InterpError> {
+        exprs.iter().map(|&expr| self.expr(expr)).collect()
+    }
+
+    // Runs a statement, returning where it jumped to if it's a taken exit.
+    fn stmt(&mut self, mut stmt: Stmt) -> Result<Option<Jump>, InterpError> {
+        match stmt.as_enum() {
+            StmtEnum::NoOp | StmtEnum::IMark(_) | StmtEnum::AbiHint(_) | StmtEnum::MBE(_) => {}
+            StmtEnum::Put(put) => {
+                let data = self.expr(put.data())?;
+                self.put(put.offset(), data)?;
+            }
+            StmtEnum::PutI(put_i) => {
+                let ix = self.expr(put_i.ix())?;
+                let data = self.expr(put_i.data())?;
+                self.put(element(put_i.descr(), ix, put_i.bias())?, data)?;
+            }
+            StmtEnum::WrTmp(wr_tmp) => {
+                let data = self.expr(wr_tmp.data())?;
+                self.def(wr_tmp.tmp(), data)?;
+            }
+            StmtEnum::Store(store) => {
+                let addr = self.addr(store.addr())?;
+                let data = self.expr(store.data())?;
+                self.store(addr, data, store.end())?;
+            }
+            StmtEnum::StoreG(store_g) => {
+                if self.guard(store_g.guard())? {
+                    let addr = self.addr(store_g.addr())?;
+                    let data = self.expr(store_g.data())?;
+                    self.store(addr, data, store_g.end())?;
+                }
+            }
+            StmtEnum::LoadG(load_g) => {
+                let value = if self.guard(load_g.guard())? {
+                    let mut res = Type::Ity_INVALID;
+                    let mut arg = Type::Ity_INVALID;
+                    unsafe { typeOfIRLoadGOp(load_g.cvt(), &mut res, &mut arg) };
+                    let addr = self.addr(load_g.addr())?;
+                    let loaded = self.load(addr, arg, load_g.end())?;
+                    match widen(load_g.cvt()) {
+                        Some(op) => op::apply(op, &[loaded])?,
+                        None => loaded,
+                    }
+                } else {
+                    self.expr(load_g.alt())?
+                };
+                self.def(load_g.dst(), value)?;
+            }
+            StmtEnum::CAS(cas) => {
+                let addr = self.addr(cas.addr())?;
+                let end = cas.end();
+                let expd_lo = self.expr(cas.expd_lo())?;
+                let data_lo = self.expr(cas.data_lo())?;
+                let ty = expd_lo.ty();
+                match (cas.old_hi(), cas.expd_hi(), cas.data_hi()) {
+                    (Some(old_hi), Some(expd_hi), Some(data_hi)) => {
+                        let expd_hi = self.expr(expd_hi)?;
+                        let data_hi = self.expr(data_hi)?;
+                        // The high half is at the higher address on little endian guests.
+                        let other = addr + size(ty)? as Addr;
+                        let (lo, hi) = match end {
+                            IREndness::Iend_LE => (addr, other),
+                            IREndness::Iend_BE => (other, addr),
+                        };
+                        let old = (self.load(lo, ty, end)?, self.load(hi, ty, end)?);
+                        if old == (expd_lo, expd_hi) {
+                            self.store(lo, data_lo, end)?;
+                            self.store(hi, data_hi, end)?;
+                        }
+                        self.def(cas.old_lo(), old.0)?;
+                        self.def(old_hi, old.1)?;
+                    }
+                    _ => {
+                        let old = self.load(addr, ty, end)?;
+                        if old == expd_lo {
+                            self.store(addr, data_lo, end)?;
+                        }
+                        self.def(cas.old_lo(), old)?;
+                    }
+                }
+            }
+            StmtEnum::LLSC(llsc) => {
+                let addr = self.addr(llsc.addr())?;
+                match llsc.store_data() {
+                    None => {
+                        let ty = self.tmp_type(llsc.result())?;
+                        let value = self.load(addr, ty, llsc.end())?;
+                        self.def(llsc.result(), value)?;
+                    }
+                    // Nothing else runs, so store-conditionals always succeed.
+                    Some(data) => {
+                        let data = self.expr(data)?;
+                        self.store(addr, data, llsc.end())?;
+                        self.def(llsc.result(), Value::I1(true))?;
+                    }
+                }
+            }
+            StmtEnum::Dirty(dirty) => {
+                let callee = dirty.callee();
+                if dirty.m_fx() != Effect::Ifx_None {
+                    return Err(InterpError::UnsupportedHelper(callee.name().to_owned()));
+                }
+                // VEX defines the result of a call that isn't made as 0x555...
+                let mut ret = 0x5555_5555_5555_5555;
+                if self.guard(dirty.guard())? {
+                    let mut args = Vec::new();
+                    for arg in dirty.args().iter() {
+                        args.push(match arg.kind() {
+                            IRExprTag::Iex_GSPTR => self.interp.state.bytes().as_mut_ptr() as u64,
+                            IRExprTag::Iex_VECRET => {
+                                let name = callee.name().to_owned();
+                                return Err(InterpError::UnsupportedHelper(name));
+                            }
+                            _ => self.expr(arg)?.bits() as u64,
+                        });
+                    }
+                    ret = call(callee, &args)?;
+                }
+                if let Some(tmp) = dirty.tmp() {
+                    let ty = self.tmp_type(tmp)?;
+                    let value = Value::from_bits(ty, ret as u128);
+                    self.def(tmp, value.ok_or(InterpError::UnsupportedType(ty))?)?;
+                }
+            }
+            StmtEnum::Exit(exit) => {
+                if self.guard(exit.guard())? {
+                    let dst = Const::from(exit.dst()).into();
+                    return self.jump(dst, exit.jump_kind(), exit.offs_ip()).map(Some);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+// The offset of the element of a `GETI` or `PUTI`, which wraps around the array.
+fn element(descr: RegArray, ix: Value, bias: i32) -> Result<Int, InterpError> {
+    let ix = (ix.bits() as i32).wrapping_add(bias);
+    let elem = ix.rem_euclid(descr.n_elems());
+    Ok(descr.base() + elem * size(descr.elem_ty())? as Int)
+}
+
+// The op extending the loaded value of a guarded load.
+fn widen(cvt: LoadGOp) -> Option<Op> {
+    match cvt {
+        LoadGOp::ILGop_16Uto32 => Some(Op::Iop_16Uto32),
+        LoadGOp::ILGop_16Sto32 => Some(Op::Iop_16Sto32),
+        LoadGOp::ILGop_8Uto32 => Some(Op::Iop_8Uto32),
+        LoadGOp::ILGop_8Sto32 => Some(Op::Iop_8Sto32),
+        _ => None,
+    }
+}
+
+// Calls a helper, at an address `Interpreter::run`'s caller vouches for. All of VEX's helpers
+// take and return integers.
+fn call(callee: Callee, args: &[u64]) -> Result<u64, InterpError> {
+    macro_rules! call {
+        ($($arg:ident)*) => {
+            unsafe {
+                let addr = callee.addr();
+                mem::transmute::<*const c_void, extern "C" fn($(call!(@u64 $arg)),*) -> u64>(
+                    addr,
+                )($($arg),*)
+            }
+        };
+        (@u64 $arg:ident) => {
+            u64
+        };
+    }
+    Ok(match *args {
+        [] => call!(),
+        [a] => call!(a),
+        [a, b] => call!(a b),
+        [a, b, c] => call!(a b c),
+        [a, b, c, d] => call!(a b c d),
+        [a, b, c, d, e] => call!(a b c d e),
+        [a, b, c, d, e, f] => call!(a b c d e f),
+        _ => return Err(InterpError::UnsupportedHelper(callee.name().to_owned())),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{amd64, Arch, TranslateArgs, VexEndness};
+
+    fn get(state: &mut amd64::State, offset: Int) -> u64 {
+        let offset = offset as usize;
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&state.bytes()[offset..offset + 8]);
+        u64::from_ne_bytes(bytes)
+    }
+
+    fn put(state: &mut amd64::State, offset: Int, value: u64) {
+        let offset = offset as usize;
+        state.bytes()[offset..offset + 8].copy_from_slice(&value.to_ne_bytes());
+    }
+
+    #[test]
+    fn run() {
+        let irsb = crate::IRSB! {
+            t0:I64   t1:I64   t2:I64   t3:I1
+
+            ------ IMark(0x1000, 4, 0) ------
+            t0 = GET:I64(72)
+            t1 = LDle:I64(t0)
+            t2 = Add64(t1,0x1:I64)
+            STle(t0) = t2
+            PUT(16) = t2
+            t3 = CmpLT64U(t2,0x10:I64)
+            if (t3) { PUT(184) = 0x2000:I64; exit-Boring }
+            PUT(184) = 0x1004:I64; exit-Boring
+        };
+        let memory = FlatMemory {
+            base: 0x100,
+            bytes: 5u64.to_le_bytes().to_vec(),
+        };
+        let mut interp = Interpreter::new(amd64::State::default(), memory);
+        put(&mut interp.state, amd64::offset::RDI, 0x100);
+
+        let jump = unsafe { interp.run(&irsb) }.unwrap();
+        assert_eq!(
+            jump,
+            Jump {
+                next: 0x2000,
+                kind: JumpKind::Ijk_Boring
+            }
+        );
+        assert_eq!(get(&mut interp.state, amd64::offset::RIP), 0x2000);
+        assert_eq!(get(&mut interp.state, amd64::offset::RAX), 6);
+        assert_eq!(interp.memory.bytes, 6u64.to_le_bytes());
+
+        interp.memory.bytes = 0x20u64.to_le_bytes().to_vec();
+        assert_eq!(unsafe { interp.run(&irsb) }.unwrap().next, 0x1004);
+        assert_eq!(get(&mut interp.state, amd64::offset::RIP), 0x1004);
+
+        put(&mut interp.state, amd64::offset::RDI, 0x200);
+        assert_eq!(
+            unsafe { interp.run(&irsb) },
+            Err(InterpError::MemoryFault {
+                addr: 0x200,
+                size: 8
+            })
+        );
+    }
+
+    #[test]
+    fn lifted() {
+        //   0x1000: lea rax, [rdi+rsi]
+        //   0x1004: cmp rax, 0x10
+        //   0x1008: jb 0x101a
+        let code = [0x48, 0x8d, 0x04, 0x37, 0x48, 0x83, 0xf8, 0x10, 0x72, 0x10];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let irsb = vta.front_end(code.as_ptr(), 0x1000).unwrap();
+        let mut interp = Interpreter::new(amd64::State::default(), FlatMemory::default());
+
+        for &(rdi, rsi, next) in &[(3, 4, 0x101a), (30, 4, 0x100a)] {
+            put(&mut interp.state, amd64::offset::RDI, rdi);
+            put(&mut interp.state, amd64::offset::RSI, rsi);
+            assert_eq!(unsafe { interp.run(&irsb) }.unwrap().next, next);
+            assert_eq!(get(&mut interp.state, amd64::offset::RAX), rdi + rsi);
+            assert_eq!(get(&mut interp.state, amd64::offset::RIP), next);
+        }
+    }
+}
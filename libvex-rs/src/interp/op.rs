@@ -0,0 +1,476 @@
+//! The semantics of ops, which are told apart by their names (as in [OpInfo]'s category
+//! predicates), following VEX's naming conventions.
+
+use vex_sys::IRRoundingMode;
+
+use super::{InterpError, Value};
+use crate::ir::{Op, OpInfo, Type};
+
+// The width in bits of integer and vector types.
+fn width(ty: Type) -> u32 {
+    match ty {
+        Type::Ity_I1 => 1,
+        Type::Ity_I8 => 8,
+        Type::Ity_I16 => 16,
+        Type::Ity_I32 => 32,
+        Type::Ity_I64 => 64,
+        Type::Ity_I128 | Type::Ity_V128 => 128,
+        Type::Ity_V256 => 256,
+        _ => 0,
+    }
+}
+
+fn mask(width: u32) -> u128 {
+    if width >= 128 {
+        !0
+    } else {
+        (1 << width) - 1
+    }
+}
+
+// Sign extends the low `width` bits of `bits`.
+fn sext(bits: u128, width: u32) -> i128 {
+    let shift = 128 - width;
+    ((bits << shift) as i128) >> shift
+}
+
+// All ones if `cond` holds, as vector comparisons produce.
+fn all(cond: bool, width: u32) -> u128 {
+    if cond {
+        mask(width)
+    } else {
+        0
+    }
+}
+
+fn float(value: Value) -> f64 {
+    match value {
+        Value::F32(val) => val as f64,
+        Value::F64(val) => val,
+        value => f64::from_bits(value.bits() as u64),
+    }
+}
+
+/// Apply `op` to `args`, which have the types its signature requires.
+pub(super) fn apply(op: Op, args: &[Value]) -> Result<Value, InterpError> {
+    let (ret, arg_tys) = op.signature();
+    let name = op.name();
+    let unsupported = InterpError::UnsupportedOp(op);
+
+    let bits = if name.starts_with("Reinterp") {
+        args[0].bits()
+    } else if let Some(lanes) = Lanes::parse(name) {
+        lanes.apply(args).ok_or(unsupported)?
+    } else if op.is_float() {
+        return scalar_float(op, name, ret, args).ok_or(unsupported);
+    } else if let Some(rest) = name.strip_prefix("DivMod") {
+        // The remainder goes in the high half and the quotient in the low half.
+        let (wide, narrow) = (width(arg_tys[0]), width(arg_tys[1]));
+        let (a, b) = (args[0].bits(), args[1].bits());
+        if b == 0 {
+            return Err(InterpError::DivideByZero);
+        }
+        let (quot, rem) = if rest.starts_with('S') {
+            let (a, b) = (sext(a, wide), sext(b, narrow));
+            (a.wrapping_div(b) as u128, a.wrapping_rem(b) as u128)
+        } else {
+            (a / b, a % b)
+        };
+        (rem & mask(narrow)) << narrow | quot & mask(narrow)
+    } else if ret == Type::Ity_V256 || arg_tys.contains(&Type::Ity_V256) {
+        return v256(name, args).ok_or(unsupported);
+    } else if let Some((from, _)) = name.split_once("to") {
+        let a = args[0].bits();
+        if from.ends_with("HL") {
+            a << width(arg_tys[1]) | args[1].bits()
+        } else if from.ends_with("HI") {
+            a >> width(ret)
+        } else if from.ends_with('S') {
+            sext(a, width(arg_tys[0])) as u128
+        } else {
+            // Zero extension, or truncation.
+            a
+        }
+    } else {
+        integer(name, arg_tys, args)?.ok_or(unsupported)?
+    };
+    Value::from_bits(ret, bits).ok_or(InterpError::UnsupportedType(ret))
+}
+
+// Integer and bitwise ops, such as `Add64`, `CmpLT32S` or `AndV128`.
+fn integer(name: &str, arg_tys: &[Type], args: &[Value]) -> Result<Option<u128>, InterpError> {
+    // Comparisons and `Max`/`Min` spell their signedness after the width, e.g. `CmpLT64S`.
+    let signed = name.ends_with('S');
+    let family = name.trim_end_matches(['S', 'U']);
+    let family = family.trim_end_matches(|c: char| c.is_ascii_digit());
+    let family = family.strip_suffix('V').unwrap_or(family);
+    let w = width(arg_tys[0]);
+    let a = args[0].bits();
+    let b = args.get(1).map_or(0, Value::bits);
+    Ok(Some(match family {
+        "Add" => a.wrapping_add(b),
+        "Sub" => a.wrapping_sub(b),
+        "Mul" => a.wrapping_mul(b),
+        "Or" => a | b,
+        "And" => a & b,
+        "Xor" => a ^ b,
+        "Not" => !a,
+        "Shl" if b < w as u128 => a << b,
+        "Shr" if b < w as u128 => a >> b,
+        "Shl" | "Shr" => 0,
+        "Sar" => (sext(a, w) >> b.min(w as u128 - 1)) as u128,
+        "CmpEQ" | "CasCmpEQ" => (a == b) as u128,
+        "CmpNE" | "CasCmpNE" | "ExpCmpNE" => (a != b) as u128,
+        "CmpLT" if signed => (sext(a, w) < sext(b, w)) as u128,
+        "CmpLT" => (a < b) as u128,
+        "CmpLE" if signed => (sext(a, w) <= sext(b, w)) as u128,
+        "CmpLE" => (a <= b) as u128,
+        "CmpNEZ" => (a != 0) as u128,
+        "CmpwNEZ" => all(a != 0, w),
+        "Left" => a | a.wrapping_neg(),
+        "Clz" => (a.leading_zeros() - (128 - w)) as u128,
+        "Ctz" => a.trailing_zeros().min(w) as u128,
+        "Max" if signed => sext(a, w).max(sext(b, w)) as u128,
+        "Max" => a.max(b),
+        "Min" if signed => sext(a, w).min(sext(b, w)) as u128,
+        "Min" => a.min(b),
+        // The full product, twice as wide as the arguments.
+        "MullS" => sext(a, w).wrapping_mul(sext(b, w)) as u128,
+        "MullU" => a.wrapping_mul(b),
+        "DivS" | "DivU" if b == 0 => return Err(InterpError::DivideByZero),
+        "DivS" => sext(a, w).wrapping_div(sext(b, w)) as u128,
+        "DivU" => a / b,
+        _ => return Ok(None),
+    }))
+}
+
+// Ops on 256 bit vectors, which don't fit in `Value::bits`.
+fn v256(name: &str, args: &[Value]) -> Option<Value> {
+    let halves = |value: Value| match value {
+        Value::V256(halves) => halves,
+        value => [value.bits(), 0],
+    };
+    let [a_lo, a_hi] = halves(args[0]);
+    let [b_lo, b_hi] = args.get(1).copied().map_or([0, 0], halves);
+    Some(match name {
+        "V128HLtoV256" => Value::V256([b_lo, a_lo]),
+        "V256toV128_0" => Value::V128(a_lo),
+        "V256toV128_1" => Value::V128(a_hi),
+        "AndV256" => Value::V256([a_lo & b_lo, a_hi & b_hi]),
+        "OrV256" => Value::V256([a_lo | b_lo, a_hi | b_hi]),
+        "XorV256" => Value::V256([a_lo ^ b_lo, a_hi ^ b_hi]),
+        "NotV256" => Value::V256([!a_lo, !a_hi]),
+        _ => return None,
+    })
+}
+
+// Rounds `x` to an integer with a VEX rounding mode.
+fn round(x: f64, rm: u128) -> f64 {
+    match rm {
+        rm if rm == IRRoundingMode::Irrm_NegINF as u128 => x.floor(),
+        rm if rm == IRRoundingMode::Irrm_PosINF as u128 => x.ceil(),
+        rm if rm == IRRoundingMode::Irrm_ZERO as u128 => x.trunc(),
+        // To nearest, with ties to even.
+        _ if (x - x.trunc()).abs() == 0.5 => 2.0 * (x / 2.0).round(),
+        _ => x.round(),
+    }
+}
+
+// Scalar floating point ops, such as `AddF64`, `CmpF32` or `F64toI32S`.
+fn scalar_float(op: Op, name: &str, ret: Type, args: &[Value]) -> Option<Value> {
+    let (rm, args) = if op.takes_rounding_mode() {
+        (args[0].bits(), &args[1..])
+    } else {
+        (IRRoundingMode::Irrm_NEAREST as u128, args)
+    };
+    let to_float = |x: f64| match ret {
+        Type::Ity_F32 => Some(Value::F32(x as f32)),
+        Type::Ity_F64 => Some(Value::F64(x)),
+        _ => None,
+    };
+
+    if let Some((from, _)) = name
+        .strip_prefix('I')
+        .and_then(|name| name.split_once("to"))
+    {
+        // From an integer, e.g. `I64StoF64`.
+        let bits = args[0].bits();
+        let signed = from.ends_with('S');
+        let w = width(args[0].ty());
+        return match ret {
+            Type::Ity_F32 if signed => Some(Value::F32(sext(bits, w) as f32)),
+            Type::Ity_F32 => Some(Value::F32(bits as f32)),
+            _ if signed => to_float(sext(bits, w) as f64),
+            _ => to_float(bits as f64),
+        };
+    }
+    let x = float(args[0]);
+    if name.contains("toI") && !matches!(ret, Type::Ity_F32 | Type::Ity_F64) {
+        // To an integer, e.g. `F64toI32S`. Results that don't fit are the smallest signed
+        // integer, as on x86.
+        let w = width(ret);
+        let x = round(x, rm);
+        let limit = 2f64.powi(w as i32 - 1);
+        let bits = if name.ends_with('S') {
+            if x.is_nan() || x < -limit || x >= limit {
+                1 << (w - 1)
+            } else {
+                x as i128 as u128
+            }
+        } else if x.is_nan() || x < 0.0 || x >= 2.0 * limit {
+            1 << (w - 1)
+        } else {
+            x as u128
+        };
+        return Value::from_bits(ret, bits);
+    }
+    if name.starts_with('F') && name.contains("toF") {
+        // Between precisions, e.g. `F32toF64`.
+        return to_float(x);
+    }
+
+    let y = args.get(1).copied().map_or(0.0, float);
+    // Arithmetic in double precision rounds to the same single precision results.
+    let result = match &name[..name.len() - "F64".len()] {
+        "Add" => x + y,
+        "Sub" => x - y,
+        "Mul" => x * y,
+        "Div" => x / y,
+        "Neg" => -x,
+        "Abs" => x.abs(),
+        "Sqrt" => x.sqrt(),
+        "Max" => {
+            if x > y {
+                x
+            } else {
+                y
+            }
+        }
+        "Min" => {
+            if x < y {
+                x
+            } else {
+                y
+            }
+        }
+        "Cmp" => {
+            let flags = if x.is_nan() || y.is_nan() {
+                0x45
+            } else if x < y {
+                0x01
+            } else if x > y {
+                0x00
+            } else {
+                0x40
+            };
+            return Some(Value::I32(flags));
+        }
+        _ => return None,
+    };
+    to_float(result)
+}
+
+// A lane-wise op on a vector, such as `Add32x4`, `CmpGT8Sx16` or `Mul64Fx2`.
+struct Lanes<'n> {
+    family: &'n str,
+    bits: u32,
+    // `F` for floats, `S` or `U` for signed or unsigned integers, or nothing.
+    kind: Option<char>,
+    count: u32,
+}
+
+impl<'n> Lanes<'n> {
+    fn parse(name: &'n str) -> Option<Self> {
+        let (shape, count) = name.rsplit_once('x')?;
+        let count = count.parse().ok()?;
+        let kind = shape.chars().last().filter(|c| "FSU".contains(*c));
+        let shape = &shape[..shape.len() - kind.map_or(0, char::len_utf8)];
+        let family = shape.trim_end_matches(|c: char| c.is_ascii_digit());
+        let bits = shape[family.len()..].parse().ok()?;
+        if !matches!(bits, 8 | 16 | 32 | 64) || bits * count > 128 {
+            return None;
+        }
+        Some(Self {
+            family,
+            bits,
+            kind,
+            count,
+        })
+    }
+
+    fn apply(&self, args: &[Value]) -> Option<u128> {
+        let bits = self.bits;
+        // Float lanes may be preceded by a rounding mode, which is ignored.
+        let args = match (self.kind, args) {
+            (Some('F'), [_, a, b]) => [*a, *b],
+            (_, [a, b]) => [*a, *b],
+            (_, [a]) => [*a, Value::I8(0)],
+            _ => return None,
+        };
+        let (x, y) = (args[0].bits(), args[1].bits());
+        let lane = |v: u128, i: u32| (v >> (i * bits)) & mask(bits);
+        let float = |lane: u128| match bits {
+            32 => f32::from_bits(lane as u32) as f64,
+            _ => f64::from_bits(lane as u64),
+        };
+        let unfloat = |x: f64| match bits {
+            32 => (x as f32).to_bits() as u128,
+            _ => x.to_bits() as u128,
+        };
+        let signed = self.kind == Some('S');
+
+        let mut result = 0;
+        for i in 0..self.count {
+            let (a, b) = (lane(x, i), lane(y, i));
+            let value = match (self.family, self.kind) {
+                ("Add", Some('F')) => unfloat(float(a) + float(b)),
+                ("Sub", Some('F')) => unfloat(float(a) - float(b)),
+                ("Mul", Some('F')) => unfloat(float(a) * float(b)),
+                ("Div", Some('F')) => unfloat(float(a) / float(b)),
+                ("Max", Some('F')) if float(a) > float(b) => a,
+                ("Min", Some('F')) if float(a) < float(b) => a,
+                ("Max", Some('F')) | ("Min", Some('F')) => b,
+                (_, Some('F')) => return None,
+                ("Add", _) => a.wrapping_add(b),
+                ("Sub", _) => a.wrapping_sub(b),
+                ("Mul", _) => a.wrapping_mul(b),
+                ("CmpEQ", _) => all(a == b, bits),
+                ("CmpGT", _) if signed => all(sext(a, bits) > sext(b, bits), bits),
+                ("CmpGT", _) => all(a > b, bits),
+                ("Max", _) if signed => sext(a, bits).max(sext(b, bits)) as u128,
+                ("Max", _) => a.max(b),
+                ("Min", _) if signed => sext(a, bits).min(sext(b, bits)) as u128,
+                ("Min", _) => a.min(b),
+                // The shift amount is a single byte, not a vector.
+                ("ShlN", _) if y < bits as u128 => a << y,
+                ("ShrN", _) if y < bits as u128 => a >> y,
+                ("ShlN", _) | ("ShrN", _) => 0,
+                ("SarN", _) => (sext(a, bits) >> y.min(bits as u128 - 1)) as u128,
+                // Lanes alternate between the arguments, starting with the second.
+                ("InterleaveLO", _) | ("InterleaveHI", _) => {
+                    let half = if self.family == "InterleaveHI" {
+                        self.count / 2
+                    } else {
+                        0
+                    };
+                    lane(if i % 2 == 1 { x } else { y }, half + i / 2)
+                }
+                _ => return None,
+            };
+            result |= (value & mask(bits)) << (i * bits);
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn apply(name: &str, args: &[Value]) -> Value {
+        super::apply(Op::from_name(name).unwrap(), args).unwrap()
+    }
+
+    #[test]
+    fn integers() {
+        use Value::*;
+
+        assert_eq!(apply("Add8", &[I8(0xff), I8(2)]), I8(1));
+        assert_eq!(apply("Sub64", &[I64(0), I64(1)]), I64(!0));
+        assert_eq!(apply("Shl32", &[I32(1), I8(31)]), I32(0x8000_0000));
+        assert_eq!(apply("Shr32", &[I32(0x8000_0000), I8(31)]), I32(1));
+        assert_eq!(apply("Sar32", &[I32(0x8000_0000), I8(31)]), I32(!0));
+        assert_eq!(apply("CmpLT64S", &[I64(!0), I64(0)]), I1(true));
+        assert_eq!(apply("CmpLT64U", &[I64(!0), I64(0)]), I1(false));
+        assert_eq!(apply("CmpwNEZ32", &[I32(3)]), I32(!0));
+        assert_eq!(apply("Clz64", &[I64(1)]), I64(63));
+        assert_eq!(apply("MullS32", &[I32(!0), I32(2)]), I64(-2i64 as u64));
+        assert_eq!(apply("DivModU64to32", &[I64(7), I32(2)]), I64(1 << 32 | 3));
+        assert_eq!(
+            apply("DivModS64to32", &[I64(-7i64 as u64), I32(2)]),
+            I64(((-1i32 as u32 as u64) << 32) | -3i32 as u32 as u64)
+        );
+        assert_eq!(
+            super::apply(Op::Iop_DivU32, &[I32(1), I32(0)]),
+            Err(InterpError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        use Value::*;
+
+        assert_eq!(apply("8Sto64", &[I8(0x80)]), I64(0xffff_ffff_ffff_ff80));
+        assert_eq!(apply("16Uto32", &[I16(0x8000)]), I32(0x8000));
+        assert_eq!(apply("64to8", &[I64(0x1234)]), I8(0x34));
+        assert_eq!(
+            apply("64HIto32", &[I64(0x1234_5678_0000_0000)]),
+            I32(0x1234_5678)
+        );
+        assert_eq!(apply("32HLto64", &[I32(1), I32(2)]), I64(1 << 32 | 2));
+        assert_eq!(apply("1Sto32", &[I1(true)]), I32(!0));
+        assert_eq!(apply("64HLtoV128", &[I64(1), I64(2)]), V128(1 << 64 | 2));
+        assert_eq!(apply("V128HIto64", &[V128(1 << 64 | 2)]), I64(1));
+        assert_eq!(apply("V128HLtoV256", &[V128(1), V128(2)]), V256([2, 1]));
+        assert_eq!(apply("ReinterpF64asI64", &[F64(1.0)]), I64(0x3ff0 << 48));
+    }
+
+    #[test]
+    fn floats() {
+        use Value::*;
+
+        let nearest = I32(IRRoundingMode::Irrm_NEAREST as u32);
+        let zero = I32(IRRoundingMode::Irrm_ZERO as u32);
+        assert_eq!(apply("AddF64", &[nearest, F64(1.5), F64(2.0)]), F64(3.5));
+        assert_eq!(apply("MulF32", &[nearest, F32(1.5), F32(2.0)]), F32(3.0));
+        assert_eq!(apply("NegF64", &[F64(1.0)]), F64(-1.0));
+        assert_eq!(apply("CmpF64", &[F64(1.0), F64(2.0)]), I32(0x01));
+        assert_eq!(apply("CmpF64", &[F64(f64::NAN), F64(2.0)]), I32(0x45));
+        assert_eq!(apply("F64toI32S", &[nearest, F64(2.5)]), I32(2));
+        assert_eq!(apply("F64toI32S", &[zero, F64(-2.7)]), I32(-2i32 as u32));
+        assert_eq!(apply("F64toI64S", &[zero, F64(1e300)]), I64(1 << 63));
+        assert_eq!(apply("I32StoF64", &[I32(-1i32 as u32)]), F64(-1.0));
+        assert_eq!(apply("F32toF64", &[F32(0.5)]), F64(0.5));
+    }
+
+    #[test]
+    fn vectors() {
+        use Value::*;
+
+        let v = |lanes: [u32; 4]| {
+            V128(
+                lanes
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, &lane| acc << 32 | lane as u128),
+            )
+        };
+        assert_eq!(
+            apply("Add32x4", &[v([1, 2, 3, !0]), v([1, 1, 1, 1])]),
+            v([2, 3, 4, 0])
+        );
+        assert_eq!(
+            apply("CmpGT32Sx4", &[v([1, !0, 3, 0]), v([0, 0, 3, 0])]),
+            v([!0, 0, 0, 0])
+        );
+        assert_eq!(
+            apply("ShlN32x4", &[v([1, 2, 3, 4]), I8(1)]),
+            v([2, 4, 6, 8])
+        );
+        assert_eq!(
+            apply("InterleaveLO32x4", &[v([1, 2, 3, 4]), v([5, 6, 7, 8])]),
+            v([5, 1, 6, 2])
+        );
+        assert_eq!(
+            apply("InterleaveHI32x4", &[v([1, 2, 3, 4]), v([5, 6, 7, 8])]),
+            v([7, 3, 8, 4])
+        );
+        let f = |x: f32| x.to_bits();
+        assert_eq!(
+            apply("Sub32Fx4", &[I32(0), v([f(2.0); 4]), v([f(0.5); 4])]),
+            v([f(1.5); 4])
+        );
+        assert_eq!(apply("XorV128", &[V128(3), V128(1)]), V128(2));
+        assert_eq!(apply("NotV256", &[V256([0, !0])]), V256([!0, 0]));
+    }
+}
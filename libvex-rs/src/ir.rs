@@ -23,6 +23,21 @@ use vex_sys::{
 
 use super::logger;
 
+mod analysis;
+mod op;
+mod opt;
+pub mod owned;
+mod parse;
+mod validate;
+
+pub use analysis::{DefUse, GuestRange, GuestStateEffects, Site};
+pub use op::OpInfo;
+pub use parse::ParseError;
+pub use validate::{IrError, IrErrorKind};
+
+#[cfg(test)]
+pub(crate) use parse::test::ALL_KINDS;
+
 // Re-exports: we (usually) want to remove the IR prefix, since we're in the `ir` module.
 // Note: we don't want to export any struct that we also assume lives in VEX's heap, such
 //       as IRConst. Otherwise, a user could easily cause UB by running code such as:
@@ -32,14 +47,19 @@ use super::logger;
 //                              // constructed safely.
 // ```
 pub use vex_sys::{
+    IREffect as Effect,
     IREndness, // don't remove the IR prefix, to differentiate from VexEndness.
     IRJumpKind as JumpKind,
     IRLoadGOp as LoadGOp,
+    IRMBusEvent as MBusEvent,
     IROp as Op,
     IRTemp as Temp,
     IRType as Type,
 };
 
+/// VEX's `IRTemp_INVALID`, which bindgen can't import since it is defined with a cast.
+pub const TEMP_INVALID: Temp = 0xFFFF_FFFF;
+
 macro_rules! wrapper {
     ($wrapper_name:ident, $vex_name:ty) => {
         #[derive(Copy, Clone)]
@@ -174,6 +194,10 @@ impl Callee<'_> {
     pub fn mcx_mask(&self) -> u32 {
         unsafe { *self.0 }.mcx_mask
     }
+
+    pub fn set_mcx_mask(&mut self, mcx_mask: u32) {
+        unsafe { (*self.0).mcx_mask = mcx_mask }
+    }
 }
 
 impl Display for Callee<'_> {
@@ -185,6 +209,24 @@ impl Display for Callee<'_> {
 
 wrapper!(RegArray, IRRegArray);
 
+impl RegArray<'_> {
+    pub unsafe fn new(base: Int, elem_ty: Type, n_elems: Int) -> Self {
+        mkIRRegArray(base, elem_ty, n_elems).into()
+    }
+
+    pub fn base(&self) -> Int {
+        unsafe { (*self.0).base }
+    }
+
+    pub fn elem_ty(&self) -> Type {
+        unsafe { (*self.0).elemTy }
+    }
+
+    pub fn n_elems(&self) -> Int {
+        unsafe { (*self.0).nElems }
+    }
+}
+
 pub enum ExprEnum<'a> {
     Binder(Binder<'a>),
     Get(Get<'a>),
@@ -244,79 +286,79 @@ impl RdTmp<'_> {
 
 wrapper!(Qop, IRQop);
 
-impl Qop<'_> {
+impl<'a> Qop<'a> {
     pub fn op(&self) -> IROp {
         unsafe { (*self.0).op }
     }
 
-    pub fn arg1(&self) -> Expr {
+    pub fn arg1(&self) -> Expr<'a> {
         unsafe { (*self.0).arg1 }.into()
     }
 
-    pub fn arg2(&self) -> Expr {
+    pub fn arg2(&self) -> Expr<'a> {
         unsafe { (*self.0).arg2 }.into()
     }
 
-    pub fn arg3(&self) -> Expr {
+    pub fn arg3(&self) -> Expr<'a> {
         unsafe { (*self.0).arg3 }.into()
     }
 
-    pub fn arg4(&self) -> Expr {
+    pub fn arg4(&self) -> Expr<'a> {
         unsafe { (*self.0).arg4 }.into()
     }
 }
 
 wrapper!(Triop, IRTriop);
 
-impl Triop<'_> {
+impl<'a> Triop<'a> {
     pub fn op(&self) -> IROp {
         unsafe { (*self.0).op }
     }
 
-    pub fn arg1(&self) -> Expr {
+    pub fn arg1(&self) -> Expr<'a> {
         unsafe { (*self.0).arg1 }.into()
     }
 
-    pub fn arg2(&self) -> Expr {
+    pub fn arg2(&self) -> Expr<'a> {
         unsafe { (*self.0).arg2 }.into()
     }
 
-    pub fn arg3(&self) -> Expr {
+    pub fn arg3(&self) -> Expr<'a> {
         unsafe { (*self.0).arg3 }.into()
     }
 }
 
 wrapper!(Binop, IRBinop);
 
-impl Binop<'_> {
+impl<'a> Binop<'a> {
     pub fn op(&self) -> IROp {
         unsafe { (*self.0).op }
     }
 
-    pub fn arg1(&self) -> Expr {
+    pub fn arg1(&self) -> Expr<'a> {
         unsafe { (*self.0).arg1 }.into()
     }
 
-    pub fn arg2(&self) -> Expr {
+    pub fn arg2(&self) -> Expr<'a> {
         unsafe { (*self.0).arg2 }.into()
     }
 }
 
 wrapper!(Unop, IRUnop);
 
-impl Unop<'_> {
+impl<'a> Unop<'a> {
     pub fn op(&self) -> IROp {
         unsafe { (*self.0).op }
     }
 
-    pub fn arg(&self) -> Expr {
+    pub fn arg(&self) -> Expr<'a> {
         unsafe { (*self.0).arg }.into()
     }
 }
 
 wrapper!(Load, IRLoad);
 
-impl Load<'_> {
+impl<'a> Load<'a> {
     pub fn end(&self) -> IREndness {
         unsafe { (*self.0).end }
     }
@@ -325,15 +367,15 @@ impl Load<'_> {
         unsafe { (*self.0).ty }
     }
 
-    pub fn addr(&self) -> Expr {
+    pub fn addr(&self) -> Expr<'a> {
         unsafe { (*self.0).addr }.into()
     }
 }
 
 wrapper!(CCall, IRCCall);
 
-impl CCall<'_> {
-    pub fn callee(&self) -> Callee {
+impl<'a> CCall<'a> {
+    pub fn callee(&self) -> Callee<'a> {
         unsafe { (*self.0).cee }.into()
     }
 
@@ -341,23 +383,23 @@ impl CCall<'_> {
         unsafe { (*self.0).retty }
     }
 
-    pub fn args(&self) -> ExprVec {
+    pub fn args(&self) -> ExprVec<'a> {
         unsafe { (*self.0).args }.into()
     }
 }
 
 wrapper!(ITE, IRITE);
 
-impl ITE<'_> {
-    pub fn cond(&self) -> Expr {
+impl<'a> ITE<'a> {
+    pub fn cond(&self) -> Expr<'a> {
         unsafe { (*self.0).cond }.into()
     }
 
-    pub fn if_true(&self) -> Expr {
+    pub fn if_true(&self) -> Expr<'a> {
         unsafe { (*self.0).iftrue }.into()
     }
 
-    pub fn if_false(&self) -> Expr {
+    pub fn if_false(&self) -> Expr<'a> {
         unsafe { (*self.0).iffalse }.into()
     }
 }
@@ -365,6 +407,38 @@ impl ITE<'_> {
 wrapper!(ExprVec, *mut IRExpr);
 
 impl ExprVec<'_> {
+    /// The number of expressions in this (NULL terminated) vector.
+    pub fn len(&self) -> usize {
+        (0..)
+            .take_while(|&i| !unsafe { *self.0.add(i) }.is_null())
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Expr> {
+        let vec = self.0;
+        (0..self.len()).map(move |i| unsafe { *vec.add(i) }.into())
+    }
+
+    /// Create a vector of any length up to 13, the longest VEX provides a constructor for.
+    pub fn new(args: &[Expr]) -> Self {
+        assert!(args.len() <= 13, "Too many arguments for an IRExpr vector");
+        let null = std::ptr::null_mut();
+        unsafe {
+            let vec = mkIRExprVec_13(
+                null, null, null, null, null, null, null, null, null, null, null, null, null,
+            );
+            for (idx, arg) in args.iter().enumerate() {
+                *vec.add(idx) = arg.0;
+            }
+            *vec.add(args.len()) = null;
+            vec.into()
+        }
+    }
+
     pub fn new0() -> Self {
         unsafe { mkIRExprVec_0() }.into()
     }
@@ -476,8 +550,8 @@ impl Display for Expr<'_> {
     }
 }
 
-impl Expr<'_> {
-    pub fn as_enum(&self) -> ExprEnum {
+impl<'a> Expr<'a> {
+    pub fn as_enum(&self) -> ExprEnum<'a> {
         let this = unsafe { &mut *self.0 };
         match this.tag {
             // Note: Binder expressions should never be returned by VEX, but we do allow
@@ -499,6 +573,14 @@ impl Expr<'_> {
         }
     }
 
+    /// The kind of this expression.
+    ///
+    /// Unlike [Expr::as_enum], this doesn't panic for `VECRET` and `GSPTR`, which may
+    /// appear as arguments of dirty calls.
+    pub fn kind(&self) -> IRExprTag {
+        unsafe { (*self.0).tag }
+    }
+
     // Reimplemented, since the original is a 'static inline' function, and isn't linked
     // into libvex.a
     pub fn is_atom(&self) -> bool {
@@ -639,9 +721,9 @@ impl Stmt<'_> {
         unsafe { IRStmt_Put(off, data.0) }.into()
     }
 
-    // pub fn put_i() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
+    pub fn put_i(descr: RegArray, ix: Expr, bias: Int, data: Expr) -> Self {
+        unsafe { IRStmt_PutI(mkIRPutI(descr.0, ix.0, bias, data.0)) }.into()
+    }
 
     pub fn wr_tmp(tmp: Temp, data: Expr) -> Self {
         unsafe { IRStmt_WrTmp(tmp, data.0) }.into()
@@ -651,24 +733,96 @@ impl Stmt<'_> {
         unsafe { IRStmt_Store(end, addr.0, data.0) }.into()
     }
 
-    // pub fn load_g() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
-    // pub fn store_g() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
-    // pub fn cas() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
-    // pub fn llsc() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
-    // pub fn dirty() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
-    // pub fn mbe() -> Self {
-    //     IRStmt_NoOp().into()
-    // }
+    pub fn load_g(
+        end: IREndness,
+        cvt: LoadGOp,
+        dst: Temp,
+        addr: Expr,
+        alt: Expr,
+        guard: Expr,
+    ) -> Self {
+        unsafe { IRStmt_LoadG(end, cvt, dst, addr.0, alt.0, guard.0) }.into()
+    }
+
+    pub fn store_g(end: IREndness, addr: Expr, data: Expr, guard: Expr) -> Self {
+        unsafe { IRStmt_StoreG(end, addr.0, data.0, guard.0) }.into()
+    }
+
+    /// A compare-and-swap. The `_hi` parts must either all be given (for a double CAS) or
+    /// all be `None`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn cas(
+        old_hi: Option<Temp>,
+        old_lo: Temp,
+        end: IREndness,
+        addr: Expr,
+        expd_hi: Option<Expr>,
+        expd_lo: Expr,
+        data_hi: Option<Expr>,
+        data_lo: Expr,
+    ) -> Self {
+        let null = std::ptr::null_mut();
+        unsafe {
+            IRStmt_CAS(mkIRCAS(
+                old_hi.unwrap_or(TEMP_INVALID),
+                old_lo,
+                end,
+                addr.0,
+                expd_hi.map_or(null, |expr| expr.0),
+                expd_lo.0,
+                data_hi.map_or(null, |expr| expr.0),
+                data_lo.0,
+            ))
+        }
+        .into()
+    }
+
+    /// A load-linked (if `store_data` is `None`) or a store-conditional.
+    pub fn llsc(end: IREndness, result: Temp, addr: Expr, store_data: Option<Expr>) -> Self {
+        let store_data = store_data.map_or(std::ptr::null_mut(), |expr| expr.0);
+        unsafe { IRStmt_LLSC(end, result, addr.0, store_data) }.into()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn dirty(
+        callee: Callee,
+        guard: Expr,
+        args: ExprVec,
+        tmp: Option<Temp>,
+        m_fx: Effect,
+        m_addr: Option<Expr>,
+        m_size: Int,
+        fx_state: &[FxState],
+    ) -> Self {
+        unsafe {
+            let details = &mut *emptyIRDirty();
+            assert!(
+                fx_state.len() <= details.fxState.len(),
+                "Too many guest state effects for a dirty call"
+            );
+            details.cee = callee.0;
+            details.guard = guard.0;
+            details.args = args.0;
+            details.tmp = tmp.unwrap_or(TEMP_INVALID);
+            details.mFx = m_fx;
+            details.mAddr = m_addr.map_or(std::ptr::null_mut(), |expr| expr.0);
+            details.mSize = m_size;
+            details.nFxState = fx_state.len() as Int;
+            for (vex_fx, fx) in details.fxState.iter_mut().zip(fx_state) {
+                vex_fx.set_fx(fx.fx);
+                vex_fx.offset = fx.offset;
+                vex_fx.size = fx.size;
+                vex_fx.nRepeats = fx.n_repeats;
+                vex_fx.repeatLen = fx.repeat_len;
+            }
+            IRStmt_Dirty(details)
+        }
+        .into()
+    }
+
+    pub unsafe fn mbe(event: MBusEvent) -> Self {
+        IRStmt_MBE(event).into()
+    }
 
     pub fn exit(guard: Expr, jk: JumpKind, dst: Const, offs_ip: Int) -> Self {
         unsafe { IRStmt_Exit(guard.0, jk, dst.0, offs_ip) }.into()
@@ -677,8 +831,36 @@ impl Stmt<'_> {
 
 wrapper!(IMark, IRIMark);
 
+impl IMark<'_> {
+    pub fn addr(&self) -> Addr {
+        unsafe { (*self.0).addr }
+    }
+
+    pub fn len(&self) -> u32 {
+        unsafe { (*self.0).len }
+    }
+
+    pub fn delta(&self) -> u8 {
+        unsafe { (*self.0).delta }
+    }
+}
+
 wrapper!(AbiHint, IRAbiHint);
 
+impl AbiHint<'_> {
+    pub fn base(&self) -> Expr {
+        unsafe { (*self.0).base }.into()
+    }
+
+    pub fn len(&self) -> i32 {
+        unsafe { (*self.0).len }
+    }
+
+    pub fn nia(&self) -> Expr {
+        unsafe { (*self.0).nia }.into()
+    }
+}
+
 wrapper!(Put, IRPut);
 
 impl Put<'_> {
@@ -693,6 +875,28 @@ impl Put<'_> {
 
 wrapper!(PutI, IRPutI);
 
+impl PutI<'_> {
+    fn details(&self) -> &vex_sys::IRPutI {
+        unsafe { &*(*self.0).details }
+    }
+
+    pub fn descr(&self) -> RegArray {
+        self.details().descr.into()
+    }
+
+    pub fn ix(&self) -> Expr {
+        self.details().ix.into()
+    }
+
+    pub fn bias(&self) -> i32 {
+        self.details().bias
+    }
+
+    pub fn data(&self) -> Expr {
+        self.details().data.into()
+    }
+}
+
 wrapper!(WrTmp, IRWrTmp);
 
 impl WrTmp<'_> {
@@ -708,6 +912,10 @@ impl WrTmp<'_> {
 wrapper!(Store, IRStore);
 
 impl Store<'_> {
+    pub fn end(&self) -> IREndness {
+        unsafe { (*self.0).end }
+    }
+
     pub fn addr(&self) -> Expr {
         unsafe { (*self.0).addr }.into()
     }
@@ -767,12 +975,146 @@ impl LoadG<'_> {
 
 wrapper!(CAS, IRCAS);
 
+impl CAS<'_> {
+    fn details(&self) -> &vex_sys::IRCAS {
+        unsafe { &*(*self.0).details }
+    }
+
+    /// The high half of the old value, for double CASs.
+    pub fn old_hi(&self) -> Option<Temp> {
+        Some(self.details().oldHi).filter(|&tmp| tmp != TEMP_INVALID)
+    }
+
+    pub fn old_lo(&self) -> Temp {
+        self.details().oldLo
+    }
+
+    pub fn end(&self) -> IREndness {
+        self.details().end
+    }
+
+    pub fn addr(&self) -> Expr {
+        self.details().addr.into()
+    }
+
+    pub fn expd_hi(&self) -> Option<Expr> {
+        Some(self.details().expdHi)
+            .filter(|expr| !expr.is_null())
+            .map(Expr::from)
+    }
+
+    pub fn expd_lo(&self) -> Expr {
+        self.details().expdLo.into()
+    }
+
+    pub fn data_hi(&self) -> Option<Expr> {
+        Some(self.details().dataHi)
+            .filter(|expr| !expr.is_null())
+            .map(Expr::from)
+    }
+
+    pub fn data_lo(&self) -> Expr {
+        self.details().dataLo.into()
+    }
+}
+
 wrapper!(LLSC, IRLLSC);
 
+impl LLSC<'_> {
+    pub fn end(&self) -> IREndness {
+        unsafe { (*self.0).end }
+    }
+
+    pub fn result(&self) -> Temp {
+        unsafe { (*self.0).result }
+    }
+
+    pub fn addr(&self) -> Expr {
+        unsafe { (*self.0).addr }.into()
+    }
+
+    /// The data to store, or `None` for a load-linked.
+    pub fn store_data(&self) -> Option<Expr> {
+        Some(unsafe { (*self.0).storedata })
+            .filter(|expr| !expr.is_null())
+            .map(Expr::from)
+    }
+}
+
 wrapper!(Dirty, IRDirty);
 
+/// A description of the guest state a dirty helper accesses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FxState {
+    #[cfg_attr(feature = "serde", serde(with = "owned::by_name"))]
+    pub fx: Effect,
+    pub offset: u16,
+    pub size: u16,
+    pub n_repeats: u8,
+    pub repeat_len: u8,
+}
+
+impl Dirty<'_> {
+    fn details(&self) -> &vex_sys::IRDirty {
+        unsafe { &*(*self.0).details }
+    }
+
+    pub fn callee(&self) -> Callee {
+        self.details().cee.into()
+    }
+
+    pub fn guard(&self) -> Expr {
+        self.details().guard.into()
+    }
+
+    pub fn args(&self) -> ExprVec {
+        self.details().args.into()
+    }
+
+    /// The temp receiving the helper's return value, if any.
+    pub fn tmp(&self) -> Option<Temp> {
+        Some(self.details().tmp).filter(|&tmp| tmp != TEMP_INVALID)
+    }
+
+    pub fn m_fx(&self) -> Effect {
+        self.details().mFx
+    }
+
+    /// The memory accessed by the helper, if `m_fx` is not `Ifx_None`.
+    pub fn m_addr(&self) -> Option<Expr> {
+        Some(self.details().mAddr)
+            .filter(|expr| !expr.is_null())
+            .map(Expr::from)
+    }
+
+    pub fn m_size(&self) -> i32 {
+        self.details().mSize
+    }
+
+    pub fn fx_state(&self) -> Vec<FxState> {
+        let details = self.details();
+        details.fxState[..details.nFxState as usize]
+            .iter()
+            .map(|fx| FxState {
+                fx: fx.fx(),
+                offset: fx.offset,
+                size: fx.size,
+                n_repeats: fx.nRepeats,
+                repeat_len: fx.repeatLen,
+            })
+            .collect()
+    }
+}
+
 wrapper!(MBE, IRMBE);
 
+impl MBE<'_> {
+    pub fn event(&self) -> MBusEvent {
+        unsafe { (*self.0).event }
+    }
+}
+
 wrapper!(Exit, IRExit);
 
 impl Exit<'_> {
@@ -817,6 +1159,15 @@ impl<'a> TypeEnv<'a> {
     pub fn type_of_expr(&self, expr: Expr) -> IRType {
         unsafe { typeOfIRExpr(self.0, expr.0) }
     }
+
+    /// The types of all temps, indexed by temp.
+    pub fn types(&self) -> &[Type] {
+        let env = unsafe { &*self.0 };
+        if env.types_used == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(env.types, env.types_used as usize) }
+    }
 }
 
 impl Display for TypeEnv<'_> {
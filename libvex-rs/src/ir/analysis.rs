@@ -0,0 +1,337 @@
+//! Def-use chains of a block's temps, and the guest state the block reads and writes.
+
+use std::collections::BTreeSet;
+
+use vex_sys::{sizeofIRType, IRExprTag, Int};
+
+use super::{Effect, Expr, ExprEnum, Stmt, StmtEnum, Temp, IRSB};
+
+/// Where a temp is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Site {
+    /// The statement at this index.
+    Stmt(usize),
+    /// The block's `next`.
+    Next,
+}
+
+/// The statement defining each temp of a block, and the sites using it, as computed by
+/// [IRSB::def_use].
+///
+/// Blocks are in SSA form, so every temp has at most one definition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefUse {
+    defs: Vec<Option<usize>>,
+    uses: Vec<Vec<Site>>,
+}
+
+impl DefUse {
+    /// The index of the statement assigning `tmp`, or `None` if nothing does.
+    pub fn def(&self, tmp: Temp) -> Option<usize> {
+        self.defs.get(tmp as usize).copied().flatten()
+    }
+
+    /// The sites reading `tmp`, in order. A statement reading it more than once appears
+    /// once.
+    pub fn uses(&self, tmp: Temp) -> &[Site] {
+        self.uses.get(tmp as usize).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether `tmp` is assigned, but never read.
+    pub fn is_dead(&self, tmp: Temp) -> bool {
+        self.def(tmp).is_some() && self.uses(tmp).is_empty()
+    }
+
+    /// The temps live after the statement at `idx`, i.e. those assigned by it or before it
+    /// and read after it.
+    pub fn live_after(&self, idx: usize) -> Vec<Temp> {
+        (0..self.defs.len() as Temp)
+            .filter(|&tmp| matches!(self.def(tmp), Some(def) if def <= idx))
+            .filter(|&tmp| {
+                self.uses(tmp).iter().any(|site| match *site {
+                    Site::Stmt(use_) => use_ > idx,
+                    Site::Next => true,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A range of bytes of the guest state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GuestRange {
+    pub offset: Int,
+    pub size: Int,
+}
+
+/// The guest state a block accesses, as computed by [IRSB::guest_state_effects].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GuestStateEffects {
+    /// Everything read by `GET`, `GETI` and dirty helpers. A `GETI` reads the whole array,
+    /// as its index is only known at runtime.
+    pub reads: BTreeSet<GuestRange>,
+    /// Everything written by `PUT`, `PUTI` and dirty helpers, all of which is live out of
+    /// the block. The writes of the instruction pointer made by exits (at `offs_ip`) are
+    /// not included, but lifted blocks also write it with a `PUT`.
+    pub writes: BTreeSet<GuestRange>,
+}
+
+impl IRSB<'_> {
+    /// Find where every temp is defined and used.
+    pub fn def_use(&self) -> DefUse {
+        let len = self.type_env().types().len();
+        let mut def_use = DefUse {
+            defs: vec![None; len],
+            uses: vec![Vec::new(); len],
+        };
+        let mut use_ = |tmp: Temp, site| {
+            let uses = &mut def_use.uses[tmp as usize];
+            if uses.last() != Some(&site) {
+                uses.push(site);
+            }
+        };
+        for (idx, stmt) in self.iter_stmts().enumerate() {
+            stmt_exprs(stmt, &mut |expr| {
+                temps(expr, &mut |tmp| use_(tmp, Site::Stmt(idx)))
+            });
+        }
+        if !unsafe { (*self.inner).next }.is_null() {
+            temps(self.next(), &mut |tmp| use_(tmp, Site::Next));
+        }
+        for (idx, stmt) in self.iter_stmts().enumerate() {
+            for tmp in stmt_defs(stmt) {
+                def_use.defs[tmp as usize] = Some(idx);
+            }
+        }
+        def_use
+    }
+
+    /// Find the guest state the block reads and writes.
+    pub fn guest_state_effects(&self) -> GuestStateEffects {
+        let mut effects = GuestStateEffects::default();
+        for mut stmt in self.iter_stmts() {
+            stmt_exprs(stmt, &mut |expr| reads(expr, &mut effects.reads));
+            match stmt.as_enum() {
+                StmtEnum::Put(put) => {
+                    let ty = self.type_env().type_of_expr(put.data());
+                    effects.writes.insert(GuestRange::new(put.offset(), ty));
+                }
+                StmtEnum::PutI(put_i) => {
+                    effects.writes.insert(GuestRange::array(put_i.descr()));
+                }
+                StmtEnum::Dirty(dirty) => {
+                    for fx in dirty.fx_state() {
+                        // Each repeat is `repeat_len` bytes after the previous one.
+                        for i in 0..=fx.n_repeats as Int {
+                            let range = GuestRange {
+                                offset: fx.offset as Int + i * fx.repeat_len as Int,
+                                size: fx.size as Int,
+                            };
+                            if matches!(fx.fx, Effect::Ifx_Read | Effect::Ifx_Modify) {
+                                effects.reads.insert(range);
+                            }
+                            if matches!(fx.fx, Effect::Ifx_Write | Effect::Ifx_Modify) {
+                                effects.writes.insert(range);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if !unsafe { (*self.inner).next }.is_null() {
+            reads(self.next(), &mut effects.reads);
+        }
+        effects
+    }
+}
+
+impl GuestRange {
+    fn new(offset: Int, ty: super::Type) -> Self {
+        Self {
+            offset,
+            size: unsafe { sizeofIRType(ty) },
+        }
+    }
+
+    fn array(descr: super::RegArray) -> Self {
+        Self {
+            offset: descr.base(),
+            size: descr.n_elems() * unsafe { sizeofIRType(descr.elem_ty()) },
+        }
+    }
+}
+
+// Calls `f` on every expression in `expr`, outermost first.
+fn visit(expr: Expr, f: &mut impl FnMut(Expr)) {
+    // These only appear as arguments of dirty calls, and contain nothing.
+    if matches!(expr.kind(), IRExprTag::Iex_VECRET | IRExprTag::Iex_GSPTR) {
+        return;
+    }
+    f(expr);
+    match expr.as_enum() {
+        ExprEnum::Binder(_) | ExprEnum::Get(_) | ExprEnum::RdTmp(_) | ExprEnum::Const(_) => {}
+        ExprEnum::GetI(mut get_i) => visit(get_i.ix(), f),
+        ExprEnum::Qop(qop) => {
+            for arg in [qop.arg1(), qop.arg2(), qop.arg3(), qop.arg4()] {
+                visit(arg, f);
+            }
+        }
+        ExprEnum::Triop(triop) => {
+            for arg in [triop.arg1(), triop.arg2(), triop.arg3()] {
+                visit(arg, f);
+            }
+        }
+        ExprEnum::Binop(binop) => {
+            visit(binop.arg1(), f);
+            visit(binop.arg2(), f);
+        }
+        ExprEnum::Unop(unop) => visit(unop.arg(), f),
+        ExprEnum::Load(load) => visit(load.addr(), f),
+        ExprEnum::CCall(ccall) => ccall.args().iter().for_each(|arg| visit(arg, f)),
+        ExprEnum::ITE(ite) => {
+            visit(ite.cond(), f);
+            visit(ite.if_true(), f);
+            visit(ite.if_false(), f);
+        }
+    }
+}
+
+// Calls `f` on every temp read by `expr`.
+fn temps(expr: Expr, f: &mut impl FnMut(Temp)) {
+    visit(expr, &mut |expr| {
+        if let ExprEnum::RdTmp(rd_tmp) = expr.as_enum() {
+            f(rd_tmp.tmp());
+        }
+    });
+}
+
+// Adds the guest state `expr` reads to `reads`.
+fn reads(expr: Expr, reads: &mut BTreeSet<GuestRange>) {
+    visit(expr, &mut |expr| match expr.as_enum() {
+        ExprEnum::Get(get) => {
+            reads.insert(GuestRange::new(get.offset(), get.ty()));
+        }
+        ExprEnum::GetI(get_i) => {
+            reads.insert(GuestRange::array(get_i.descr()));
+        }
+        _ => {}
+    });
+}
+
+// Calls `f` on every (top level) expression of `stmt`.
+fn stmt_exprs(mut stmt: Stmt, f: &mut impl FnMut(Expr)) {
+    match stmt.as_enum() {
+        StmtEnum::NoOp | StmtEnum::IMark(_) | StmtEnum::MBE(_) => {}
+        StmtEnum::AbiHint(hint) => {
+            f(hint.base());
+            f(hint.nia());
+        }
+        StmtEnum::Put(put) => f(put.data()),
+        StmtEnum::PutI(put_i) => {
+            f(put_i.ix());
+            f(put_i.data());
+        }
+        StmtEnum::WrTmp(wr_tmp) => f(wr_tmp.data()),
+        StmtEnum::Store(store) => {
+            f(store.addr());
+            f(store.data());
+        }
+        StmtEnum::StoreG(store_g) => {
+            f(store_g.addr());
+            f(store_g.data());
+            f(store_g.guard());
+        }
+        StmtEnum::LoadG(load_g) => {
+            f(load_g.addr());
+            f(load_g.alt());
+            f(load_g.guard());
+        }
+        StmtEnum::CAS(cas) => {
+            f(cas.addr());
+            cas.expd_hi().into_iter().for_each(&mut *f);
+            f(cas.expd_lo());
+            cas.data_hi().into_iter().for_each(&mut *f);
+            f(cas.data_lo());
+        }
+        StmtEnum::LLSC(llsc) => {
+            f(llsc.addr());
+            llsc.store_data().into_iter().for_each(f);
+        }
+        StmtEnum::Dirty(dirty) => {
+            f(dirty.guard());
+            dirty.args().iter().for_each(&mut *f);
+            dirty.m_addr().into_iter().for_each(f);
+        }
+        StmtEnum::Exit(exit) => f(exit.guard()),
+    }
+}
+
+// The temps `stmt` assigns.
+fn stmt_defs(mut stmt: Stmt) -> Vec<Temp> {
+    match stmt.as_enum() {
+        StmtEnum::WrTmp(wr_tmp) => vec![wr_tmp.tmp()],
+        StmtEnum::LoadG(load_g) => vec![load_g.dst()],
+        StmtEnum::CAS(cas) => cas.old_hi().into_iter().chain(Some(cas.old_lo())).collect(),
+        StmtEnum::LLSC(llsc) => vec![llsc.result()],
+        StmtEnum::Dirty(dirty) => dirty.tmp().into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn def_use() {
+        let irsb = crate::IRSB! {
+            t0:I64   t1:I64   t2:I1   t3:I64   t4:I64
+
+            ------ IMark(0x1000, 4, 0) ------
+            t0 = GET:I64(48)
+            t1 = Add64(t0,t0)
+            t2 = CmpEQ64(t1,0x0:I64)
+            t3 = LDle:I64(t0)
+            if (t2) { PUT(184) = 0x2000:I64; exit-Boring }
+            PUT(16) = t1
+            PUT(184) = t1; exit-Boring
+        };
+        let def_use = irsb.def_use();
+
+        assert_eq!(def_use.def(0), Some(1));
+        assert_eq!(def_use.uses(0), [Site::Stmt(2), Site::Stmt(4)]);
+        assert_eq!(def_use.uses(1), [Site::Stmt(3), Site::Stmt(6), Site::Next]);
+        assert!(def_use.is_dead(3));
+        assert!(!def_use.is_dead(4));
+        assert_eq!(def_use.def(4), None);
+        assert_eq!(def_use.live_after(2), [0, 1]);
+        assert_eq!(def_use.live_after(4), [1, 2]);
+        assert_eq!(def_use.live_after(6), [1]);
+    }
+
+    #[test]
+    fn effects() {
+        let irsb = crate::IRSB! {
+            t0:I64   t1:I32   t2:I64
+
+            t0 = GET:I64(48)
+            t1 = GET:I32(16)
+            t2 = GETI(136:8xI64)[t1,-1]
+            PUT(48) = t2
+            PUTI(200:4xI32)[t1,0] = t1
+            PUT(184) = GET:I64(56); exit-Boring
+        };
+        let effects = irsb.guest_state_effects();
+
+        let range = |offset, size| GuestRange { offset, size };
+        assert_eq!(
+            effects.reads.into_iter().collect::<Vec<_>>(),
+            [range(16, 4), range(48, 8), range(56, 8), range(136, 64)]
+        );
+        assert_eq!(
+            effects.writes.into_iter().collect::<Vec<_>>(),
+            [range(48, 8), range(200, 16)]
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+use std::mem;
+
+use lazy_static::lazy_static;
+
+use vex_sys::{ppIROp, typeOfPrimop};
+
+use super::{Op, Type};
+use crate::logger;
+
+// `IROp` is a contiguous `repr(u32)` enum, delimited by `Iop_INVALID` and `Iop_LAST`.
+fn all_ops() -> impl Iterator<Item = Op> {
+    (Op::Iop_INVALID as u32 + 1..Op::Iop_LAST as u32)
+        .map(|op| unsafe { mem::transmute::<u32, Op>(op) })
+}
+
+fn index(op: Op) -> usize {
+    assert!(
+        op != Op::Iop_INVALID && op != Op::Iop_LAST,
+        "{:?} is not a real operation",
+        op
+    );
+    (op as u32 - Op::Iop_INVALID as u32 - 1) as usize
+}
+
+lazy_static! {
+    // Indexed by `index(op)`.
+    static ref NAMES: Vec<String> = {
+        crate::init();
+        all_ops()
+            .map(|op| {
+                let ((), pp) = logger::with(|| unsafe { ppIROp(op) });
+                pp.unwrap()
+            })
+            .collect()
+    };
+    static ref BY_NAME: HashMap<&'static str, Op> = all_ops()
+        .map(|op| (NAMES[index(op)].as_str(), op))
+        .collect();
+    // Indexed by `index(op)`.
+    static ref SIGNATURES: Vec<(Type, Vec<Type>)> = {
+        crate::init();
+        all_ops().map(type_of_primop).collect()
+    };
+}
+
+fn type_of_primop(op: Op) -> (Type, Vec<Type>) {
+    let mut ret = Type::Ity_INVALID;
+    let mut args = [Type::Ity_INVALID; 4];
+    unsafe {
+        let [arg1, arg2, arg3, arg4] = &mut args;
+        typeOfPrimop(op, &mut ret, arg1, arg2, arg3, arg4);
+    }
+    let args = args
+        .iter()
+        .copied()
+        .take_while(|&ty| ty != Type::Ity_INVALID)
+        .collect();
+    (ret, args)
+}
+
+fn is_int(ty: Type) -> bool {
+    matches!(
+        ty,
+        Type::Ity_I1
+            | Type::Ity_I8
+            | Type::Ity_I16
+            | Type::Ity_I32
+            | Type::Ity_I64
+            | Type::Ity_I128
+    )
+}
+
+fn is_float(ty: Type) -> bool {
+    matches!(
+        ty,
+        Type::Ity_F16
+            | Type::Ity_F32
+            | Type::Ity_F64
+            | Type::Ity_F128
+            | Type::Ity_D32
+            | Type::Ity_D64
+            | Type::Ity_D128
+    )
+}
+
+fn is_vector(ty: Type) -> bool {
+    matches!(ty, Type::Ity_V128 | Type::Ity_V256)
+}
+
+// Lane shapes are spelled like `8x16`, `32Fx4`, `16Sx8` or `8Ux8`.
+fn has_lanes(name: &str) -> bool {
+    name.as_bytes().windows(3).any(|w| {
+        (w[0].is_ascii_digit() || matches!(w[0], b'F' | b'S' | b'U'))
+            && w[1] == b'x'
+            && w[2].is_ascii_digit()
+    })
+}
+
+/// Metadata about an [Op], as known to VEX.
+///
+/// `Op` is VEX's `IROp` enum, so these are provided through a trait rather than as
+/// inherent methods. Import it to use them, e.g. `Op::from_name("Add64")`.
+///
+/// The category predicates follow VEX's naming conventions (see `libvex_ir.h`) together
+/// with the op's signature, so they are only as precise as those conventions are.
+pub trait OpInfo: Sized {
+    /// The result type and the argument types of this op, as given by `typeOfPrimop`.
+    ///
+    /// # Panics
+    /// For `Iop_INVALID` and `Iop_LAST`.
+    fn signature(&self) -> (Type, &'static [Type]);
+
+    /// The number of arguments this op takes.
+    fn arity(&self) -> usize {
+        self.signature().1.len()
+    }
+
+    /// The name of this op, as printed by VEX (e.g. `Add64` for `Iop_Add64`).
+    ///
+    /// # Panics
+    /// For `Iop_INVALID` and `Iop_LAST`, which delimit the enum but aren't operations.
+    fn name(&self) -> &'static str;
+
+    /// The inverse of [OpInfo::name].
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Whether this op only operates on integers, either scalar or packed into vectors.
+    fn is_integer(&self) -> bool;
+
+    /// Whether this op operates on binary or decimal floating point values.
+    fn is_float(&self) -> bool;
+
+    /// Whether this op operates on multiple lanes at once.
+    fn is_simd(&self) -> bool;
+
+    /// Whether this op compares its arguments.
+    fn is_comparison(&self) -> bool;
+
+    /// Whether this op converts, widens, narrows or reinterprets its argument, i.e. it
+    /// takes one value (and maybe a rounding mode) and returns a value of another type.
+    fn is_conversion(&self) -> bool;
+
+    /// Whether the first argument of this op is an `IRRoundingMode`.
+    fn takes_rounding_mode(&self) -> bool;
+}
+
+impl OpInfo for Op {
+    fn signature(&self) -> (Type, &'static [Type]) {
+        let (ret, args) = &SIGNATURES[index(*self)];
+        (*ret, args)
+    }
+
+    fn name(&self) -> &'static str {
+        NAMES[index(*self)].as_str()
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        BY_NAME.get(name).copied()
+    }
+
+    fn is_integer(&self) -> bool {
+        let (ret, args) = self.signature();
+        let is_int_or_vector = |ty| is_int(ty) || is_vector(ty);
+        !self.is_float() && is_int_or_vector(ret) && args.iter().copied().all(is_int_or_vector)
+    }
+
+    fn is_float(&self) -> bool {
+        let (ret, args) = self.signature();
+        let name = self.name();
+        is_float(ret)
+            || args.iter().copied().any(is_float)
+            || name.contains("Fx")
+            || name.contains("F0x")
+            || (name.starts_with('F') && has_lanes(name))
+    }
+
+    fn is_simd(&self) -> bool {
+        let (ret, args) = self.signature();
+        is_vector(ret) || args.iter().copied().any(is_vector) || has_lanes(self.name())
+    }
+
+    fn is_comparison(&self) -> bool {
+        self.name().contains("Cmp")
+    }
+
+    fn is_conversion(&self) -> bool {
+        let (ret, args) = self.signature();
+        let arg = match (self.takes_rounding_mode(), args) {
+            (false, &[arg]) | (true, &[_, arg]) => arg,
+            _ => return false,
+        };
+        // Comparisons and lane-wise ops (e.g. `CmpNEZ64` or `GetMSBs8x8`) change the
+        // type without converting a value.
+        let name = self.name();
+        if self.is_comparison() || has_lanes(name) {
+            return false;
+        }
+        ret != arg || name.starts_with("Reinterp")
+    }
+
+    fn takes_rounding_mode(&self) -> bool {
+        let (_, args) = self.signature();
+        args.len() >= 2 && args[0] == Type::Ity_I32 && self.is_float()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(Op::Iop_Add64.name(), "Add64");
+        assert_eq!(Op::Iop_32Uto64.name(), "32Uto64");
+        assert_eq!(Op::from_name("Sub32"), Some(Op::Iop_Sub32));
+        assert_eq!(Op::from_name("Sub33"), None);
+        for op in all_ops() {
+            assert_eq!(Op::from_name(op.name()), Some(op));
+        }
+    }
+
+    #[test]
+    fn signatures() {
+        use Type::*;
+
+        assert_eq!(
+            Op::Iop_Add64.signature(),
+            (Ity_I64, &[Ity_I64, Ity_I64][..])
+        );
+        assert_eq!(Op::Iop_64to32.signature(), (Ity_I32, &[Ity_I64][..]));
+        assert_eq!(Op::Iop_CmpEQ8.signature(), (Ity_I1, &[Ity_I8, Ity_I8][..]));
+        for op in all_ops() {
+            let (ret, args) = type_of_primop(op);
+            assert_eq!(op.signature(), (ret, args.as_slice()));
+        }
+        assert_eq!(Op::Iop_Shl32.arity(), 2);
+        assert_eq!(Op::Iop_AddF64.arity(), 3);
+    }
+
+    #[test]
+    fn categories() {
+        assert!(Op::Iop_Add64.is_integer());
+        assert!(!Op::Iop_Add64.is_float());
+        assert!(!Op::Iop_Add64.is_simd());
+
+        assert!(Op::Iop_AddF64.is_float());
+        assert!(Op::Iop_AddF64.takes_rounding_mode());
+        assert!(!Op::Iop_NegF64.takes_rounding_mode());
+
+        assert!(Op::Iop_Add32x4.is_simd());
+        assert!(Op::Iop_Add32x4.is_integer());
+        assert!(Op::Iop_Add32Fx4.is_simd());
+        assert!(Op::Iop_Add32Fx4.is_float());
+
+        assert!(Op::Iop_CmpLT64U.is_comparison());
+        assert!(Op::Iop_CmpF64.is_comparison());
+        assert!(!Op::Iop_Sub8.is_comparison());
+
+        assert!(Op::Iop_8Uto64.is_conversion());
+        assert!(Op::Iop_ReinterpF64asI64.is_conversion());
+        assert!(Op::Iop_F64toI32S.is_conversion());
+        assert!(Op::Iop_I32StoF64.is_conversion());
+        assert!(!Op::Iop_RoundF64toInt.is_conversion());
+        assert!(!Op::Iop_MullS32.is_conversion());
+        assert!(!Op::Iop_Xor32.is_conversion());
+        assert!(!Op::Iop_CmpNEZ64.is_conversion());
+        assert!(!Op::Iop_GetMSBs8x8.is_conversion());
+    }
+
+    // libvex-macros checks IR against a copy of VEX's types and ops, which is checked in
+    // so that the macros don't need VEX. This generates it.
+    fn macro_tables() -> Result<String, std::fmt::Error> {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// @generated by libvex's `ir::op::test::macro_tables` test from VEX's"
+        )?;
+        writeln!(out, "// `ppIROp` and `typeOfPrimop`.")?;
+
+        // `IRType` is a contiguous `repr(u32)` enum too.
+        let types: Vec<Type> = (Type::Ity_INVALID as u32..=Type::Ity_V256 as u32)
+            .map(|ty| unsafe { mem::transmute::<u32, Type>(ty) })
+            .collect();
+        writeln!(out, "\n/// VEX's `IRType`.")?;
+        writeln!(out, "#[allow(dead_code, non_camel_case_types)]")?;
+        writeln!(out, "#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]")?;
+        writeln!(out, "pub enum Type {{")?;
+        for ty in &types {
+            writeln!(out, "    {:?},", ty)?;
+        }
+        writeln!(out, "}}")?;
+
+        writeln!(out, "\n/// Every type but `Ity_INVALID`.")?;
+        writeln!(out, "pub const ALL_TYPES: &[Type] = &[")?;
+        for ty in &types[1..] {
+            writeln!(out, "    Type::{:?},", ty)?;
+        }
+        writeln!(out, "];")?;
+
+        writeln!(
+            out,
+            "\n/// Every op, as `(name as printed by VEX, C name, result type, argument types)`."
+        )?;
+        writeln!(
+            out,
+            "pub const ALL_OPS: &[(&str, &str, Type, &[Type])] = &["
+        )?;
+        for op in all_ops() {
+            let (ret, args) = op.signature();
+            let args: Vec<_> = args.iter().map(|ty| format!("Type::{:?}", ty)).collect();
+            writeln!(
+                out,
+                "    ({:?}, \"{:?}\", Type::{:?}, &[{}]),",
+                op.name(),
+                op,
+                ret,
+                args.join(", ")
+            )?;
+        }
+        writeln!(out, "];")?;
+        Ok(out)
+    }
+
+    // Run with `LIBVEX_BLESS=1` to regenerate libvex-macros' tables after updating VEX.
+    #[test]
+    fn macro_tables_up_to_date() {
+        use std::{env, fs};
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../libvex-macros/src/ir/vex.rs"
+        );
+        let tables = macro_tables().unwrap();
+        if env::var_os("LIBVEX_BLESS").is_some() {
+            fs::write(path, &tables).unwrap();
+        }
+        assert!(
+            fs::read_to_string(path).unwrap() == tables,
+            "{} is out of date, rerun this test with LIBVEX_BLESS=1",
+            path
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use vex_sys::{Addr, Bool, HChar, IRExpr, IRStmt, Int, VexArch, VexControl, VexRegisterUpdates};
+
+use super::{StmtEnum, IRSB};
+use crate::Arch;
+
+type SpecHelper =
+    unsafe extern "C" fn(*const HChar, *mut *mut IRExpr, *mut *mut IRStmt, Int) -> *mut IRExpr;
+type PreciseMemExns = unsafe extern "C" fn(Int, Int, VexRegisterUpdates) -> Bool;
+
+// These live in VEX's private headers (ir_opt.h, main_globals.h and guest_*_defs.h), so
+// bindgen doesn't know about them.
+extern "C" {
+    static mut vex_control: VexControl;
+
+    fn do_iropt_BB(
+        bb: *mut vex_sys::IRSB,
+        specialise_fn: SpecHelper,
+        precise_mem_exns_fn: PreciseMemExns,
+        px_control: VexRegisterUpdates,
+        guest_addr: Addr,
+        guest_arch: VexArch,
+    ) -> *mut vex_sys::IRSB;
+    fn do_minimal_initial_iropt_BB(bb0: *mut vex_sys::IRSB) -> *mut vex_sys::IRSB;
+    fn do_deadcode_BB(bb: *mut vex_sys::IRSB);
+
+    fn guest_x86_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_amd64_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_arm_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_arm64_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_ppc32_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_ppc64_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_s390x_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_mips32_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+    fn guest_mips64_spechelper(
+        name: *const HChar,
+        args: *mut *mut IRExpr,
+        preceding: *mut *mut IRStmt,
+        n_preceding: Int,
+    ) -> *mut IRExpr;
+
+    fn guest_x86_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_amd64_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_arm_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_arm64_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_ppc32_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_ppc64_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_s390x_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_mips32_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+    fn guest_mips64_state_requires_precise_mem_exns(
+        minoff: Int,
+        maxoff: Int,
+        px_control: VexRegisterUpdates,
+    ) -> Bool;
+}
+
+// The same choice LibVEX_FrontEnd makes for each guest.
+fn guest_helpers(arch: Arch) -> (SpecHelper, PreciseMemExns) {
+    match arch {
+        Arch::VexArchX86 => (
+            guest_x86_spechelper,
+            guest_x86_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchAMD64 => (
+            guest_amd64_spechelper,
+            guest_amd64_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchARM => (
+            guest_arm_spechelper,
+            guest_arm_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchARM64 => (
+            guest_arm64_spechelper,
+            guest_arm64_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchPPC32 => (
+            guest_ppc32_spechelper,
+            guest_ppc32_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchPPC64 => (
+            guest_ppc64_spechelper,
+            guest_ppc64_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchS390X => (
+            guest_s390x_spechelper,
+            guest_s390x_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchMIPS32 | Arch::VexArchNANOMIPS => (
+            guest_mips32_spechelper,
+            guest_mips32_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArchMIPS64 => (
+            guest_mips64_spechelper,
+            guest_mips64_state_requires_precise_mem_exns,
+        ),
+        Arch::VexArch_INVALID => panic!("Invalid guest architecture"),
+    }
+}
+
+// Sets VEX's optimisation level, and restores the old one when dropped.
+struct IroptLevel(Int);
+
+impl IroptLevel {
+    unsafe fn set(level: Int) -> Self {
+        let old = vex_control.iropt_level;
+        vex_control.iropt_level = level;
+        Self(old)
+    }
+}
+
+impl Drop for IroptLevel {
+    fn drop(&mut self) {
+        unsafe { vex_control.iropt_level = self.0 }
+    }
+}
+
+impl IRSB<'_> {
+    /// Run VEX's IR optimiser (`do_iropt_BB`) on this block, as `LibVEX_Translate` does
+    /// between the front-end and instrumentation.
+    ///
+    /// `do_iropt_BB` requires a flat block, so the block is first flattened, as by
+    /// `flatten`. `level` is VEX's `iropt_level`: 0 does nothing more, 1 adds constant
+    /// folding, redundant GET/PUT elimination and dead code removal, and 2 also runs the
+    /// more expensive transformations (CSE, loop unrolling, ...). Guest specific helper
+    /// calls are specialised using `guest_arch`'s helpers.
+    ///
+    /// The result is flat.
+    ///
+    /// # Panics
+    /// If `level` is above 2, or `guest_arch` is `VexArch_INVALID`.
+    pub fn optimise(&mut self, level: u8, guest_arch: Arch) {
+        assert!(level <= 2, "VEX only has optimisation levels 0-2");
+        self.flatten();
+        let (spec_helper, precise_mem_exns) = guest_helpers(guest_arch);
+        // Unrolling wants the guest address of the block.
+        let guest_addr = self
+            .iter_stmts()
+            .find_map(|mut stmt| match stmt.as_enum() {
+                StmtEnum::IMark(imark) => Some(imark.addr()),
+                _ => None,
+            })
+            .unwrap_or(0);
+        unsafe {
+            let _level = IroptLevel::set(level as Int);
+            self.inner = do_iropt_BB(
+                self.inner,
+                spec_helper,
+                precise_mem_exns,
+                vex_control.iropt_register_updates_default,
+                guest_addr,
+                guest_arch,
+            );
+        }
+    }
+
+    /// Flatten this block, so that all operands are atoms.
+    ///
+    /// VEX's `flatten_BB` isn't exported, so this runs `do_minimal_initial_iropt_BB`, as
+    /// the front-end does. It also removes redundant GETs and propagates copies and
+    /// constants, without folding them.
+    pub fn flatten(&mut self) {
+        crate::init();
+        self.inner = unsafe { do_minimal_initial_iropt_BB(self.inner) };
+    }
+
+    /// Remove statements whose results are never used (`do_deadcode_BB`).
+    ///
+    /// The block must be flat.
+    pub fn remove_dead_code(&mut self) {
+        assert!(self.is_flat(), "Dead code removal requires a flat IRSB");
+        unsafe { do_deadcode_BB(self.inner) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ir::{Const, Expr, JumpKind, Op, Stmt, Type, IRSB};
+    use crate::{Arch, TranslateArgs, VexEndness};
+
+    #[test]
+    fn flatten() {
+        let mut irsb = IRSB::new();
+        let t0 = irsb.type_env().new_tmp(Type::Ity_I64);
+        unsafe {
+            irsb.add_stmt(Stmt::wr_tmp(
+                t0,
+                Expr::binop(
+                    Op::Iop_Add64,
+                    Expr::get(16, Type::Ity_I64),
+                    Expr::const_(Const::u64(1)),
+                ),
+            ));
+            irsb.set_next(Expr::rd_tmp(t0));
+        }
+        irsb.set_jump_kind(JumpKind::Ijk_Boring);
+        irsb.set_offs_ip(184);
+        assert!(!irsb.is_flat());
+
+        irsb.flatten();
+        assert!(irsb.is_flat());
+        assert_eq!(irsb.iter_stmts().count(), 2);
+    }
+
+    #[test]
+    fn optimise() {
+        // mov rax, 1; add rax, 2; ret
+        let code = [
+            0x48, 0xc7, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x48, 0x83, 0xc0, 0x02, 0xc3,
+        ];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let mut irsb = vta.front_end(code.as_ptr(), code.as_ptr() as _).unwrap();
+        let before = irsb.iter_stmts().count();
+
+        irsb.optimise(2, Arch::VexArchAMD64);
+        irsb.remove_dead_code();
+
+        assert!(irsb.is_flat());
+        assert!(irsb.iter_stmts().count() < before);
+        // rax = 1 + 2 is folded
+        assert!(irsb.to_string().contains("PUT(16) = 0x3:I64"));
+    }
+}
@@ -0,0 +1,884 @@
+//! An owned copy of the IR, which doesn't live in VEX's heap.
+//!
+//! Unlike the wrappers in [crate::ir], these types can outlive a translation, be
+//! compared, cloned, modified freely and (with the `serde` feature) serialised. Convert
+//! an [IRSB] with [Block::from], and back with [Block::to_irsb].
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use vex_sys::{Addr, IRExprTag};
+
+use super::{
+    ConstEnum, Effect, ExprEnum, FxState, IREndness, JumpKind, LoadGOp, MBusEvent, Op, StmtEnum,
+    Temp, Type, IRSB,
+};
+
+/// The version of the serialised form of [Block]. Bumped whenever it changes
+/// incompatibly.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A constant. Floats are kept as their bits, so that every value (NaNs included) compares
+/// equal to itself and survives serialisation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Const {
+    U1(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    /// The bits of an `f32`.
+    F32(u32),
+    F32i(u32),
+    /// The bits of an `f64`.
+    F64(u64),
+    F64i(u64),
+    /// One bit per byte of the vector.
+    V128(u16),
+    /// One bit per byte of the vector.
+    V256(u32),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegArray {
+    pub base: i32,
+    #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+    pub elem_ty: Type,
+    pub n_elems: i32,
+}
+
+/// A helper function called by a [Expr::CCall] or a [Stmt::Dirty].
+///
+/// `addr` is only meaningful in the process that lifted the block; VEX only cares about it
+/// when generating host code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Callee {
+    pub regparms: i32,
+    pub name: String,
+    pub addr: u64,
+    pub mcx_mask: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Expr {
+    Binder(i32),
+    Get {
+        offset: i32,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        ty: Type,
+    },
+    GetI {
+        descr: RegArray,
+        ix: Box<Expr>,
+        bias: i32,
+    },
+    RdTmp(Temp),
+    Qop {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        op: Op,
+        args: Box<[Expr; 4]>,
+    },
+    Triop {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        op: Op,
+        args: Box<[Expr; 3]>,
+    },
+    Binop {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        op: Op,
+        args: Box<[Expr; 2]>,
+    },
+    Unop {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        op: Op,
+        arg: Box<Expr>,
+    },
+    Load {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        ty: Type,
+        addr: Box<Expr>,
+    },
+    Const(Const),
+    ITE {
+        cond: Box<Expr>,
+        if_true: Box<Expr>,
+        if_false: Box<Expr>,
+    },
+    CCall {
+        callee: Callee,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        ret_ty: Type,
+        args: Vec<Expr>,
+    },
+    /// Only valid as an argument of a dirty call.
+    VecRet,
+    /// Only valid as an argument of a dirty call.
+    GsPtr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Stmt {
+    NoOp,
+    IMark {
+        addr: Addr,
+        len: u32,
+        delta: u8,
+    },
+    AbiHint {
+        base: Expr,
+        len: i32,
+        nia: Expr,
+    },
+    Put {
+        offset: i32,
+        data: Expr,
+    },
+    PutI {
+        descr: RegArray,
+        ix: Expr,
+        bias: i32,
+        data: Expr,
+    },
+    WrTmp {
+        tmp: Temp,
+        data: Expr,
+    },
+    Store {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        addr: Expr,
+        data: Expr,
+    },
+    LoadG {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        cvt: LoadGOp,
+        dst: Temp,
+        addr: Expr,
+        alt: Expr,
+        guard: Expr,
+    },
+    StoreG {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        addr: Expr,
+        data: Expr,
+        guard: Expr,
+    },
+    CAS {
+        old_hi: Option<Temp>,
+        old_lo: Temp,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        addr: Expr,
+        expd_hi: Option<Expr>,
+        expd_lo: Expr,
+        data_hi: Option<Expr>,
+        data_lo: Expr,
+    },
+    LLSC {
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        end: IREndness,
+        result: Temp,
+        addr: Expr,
+        store_data: Option<Expr>,
+    },
+    Dirty {
+        callee: Callee,
+        guard: Expr,
+        args: Vec<Expr>,
+        tmp: Option<Temp>,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        m_fx: Effect,
+        m_addr: Option<Expr>,
+        m_size: i32,
+        fx_state: Vec<FxState>,
+    },
+    MBE(#[cfg_attr(feature = "serde", serde(with = "by_name"))] MBusEvent),
+    Exit {
+        guard: Expr,
+        dst: Const,
+        #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+        jump_kind: JumpKind,
+        offs_ip: i32,
+    },
+}
+
+/// An owned [IRSB].
+///
+/// When serialised, a block is wrapped together with [SCHEMA_VERSION], and deserialising
+/// a block with a different version fails.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(remote = "Self")
+)]
+pub struct Block {
+    /// The types of all temps, indexed by temp.
+    #[cfg_attr(feature = "serde", serde(with = "by_name::vec"))]
+    pub types: Vec<Type>,
+    pub stmts: Vec<Stmt>,
+    pub next: Expr,
+    #[cfg_attr(feature = "serde", serde(with = "by_name"))]
+    pub jump_kind: JumpKind,
+    pub offs_ip: i32,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Block {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Versioned<'a> {
+            version: u32,
+            #[serde(with = "Block")]
+            block: &'a Block,
+        }
+
+        Versioned {
+            version: SCHEMA_VERSION,
+            block: self,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Versioned {
+            version: u32,
+            #[serde(with = "Block")]
+            block: Block,
+        }
+
+        let versioned = Versioned::deserialize(deserializer)?;
+        if versioned.version != SCHEMA_VERSION {
+            return Err(serde::de::Error::custom(format_args!(
+                "unsupported IR schema version {} (expected {})",
+                versioned.version, SCHEMA_VERSION
+            )));
+        }
+        Ok(versioned.block)
+    }
+}
+
+impl From<super::Const<'_>> for Const {
+    fn from(co: super::Const) -> Self {
+        match co.as_enum() {
+            ConstEnum::U1(val) => Const::U1(val),
+            ConstEnum::U8(val) => Const::U8(val),
+            ConstEnum::U16(val) => Const::U16(val),
+            ConstEnum::U32(val) => Const::U32(val),
+            ConstEnum::U64(val) => Const::U64(val),
+            ConstEnum::F32(val) => Const::F32(val.to_bits()),
+            ConstEnum::F32i(val) => Const::F32i(val),
+            ConstEnum::F64(val) => Const::F64(val.to_bits()),
+            ConstEnum::F64i(val) => Const::F64i(val),
+            ConstEnum::V128(vec) => Const::V128(unsafe { vec.w16[0] }),
+            ConstEnum::V256(vec) => Const::V256(unsafe { vec.w32[0] }),
+        }
+    }
+}
+
+impl From<super::RegArray<'_>> for RegArray {
+    fn from(descr: super::RegArray) -> Self {
+        RegArray {
+            base: descr.base(),
+            elem_ty: descr.elem_ty(),
+            n_elems: descr.n_elems(),
+        }
+    }
+}
+
+impl From<super::Callee<'_>> for Callee {
+    fn from(callee: super::Callee) -> Self {
+        Callee {
+            regparms: callee.regparms(),
+            name: callee.name().to_owned(),
+            addr: callee.addr() as u64,
+            mcx_mask: callee.mcx_mask(),
+        }
+    }
+}
+
+impl From<super::Expr<'_>> for Expr {
+    fn from(expr: super::Expr) -> Self {
+        let boxed = |expr: super::Expr| Box::new(Expr::from(expr));
+        match expr.kind() {
+            IRExprTag::Iex_VECRET => return Expr::VecRet,
+            IRExprTag::Iex_GSPTR => return Expr::GsPtr,
+            _ => {}
+        }
+        match expr.as_enum() {
+            ExprEnum::Binder(binder) => Expr::Binder(unsafe { (*binder.0).binder }),
+            ExprEnum::Get(get) => Expr::Get {
+                offset: get.offset(),
+                ty: get.ty(),
+            },
+            ExprEnum::GetI(mut get_i) => Expr::GetI {
+                descr: get_i.descr().into(),
+                ix: boxed(get_i.ix()),
+                bias: get_i.bias(),
+            },
+            ExprEnum::RdTmp(rd_tmp) => Expr::RdTmp(rd_tmp.tmp()),
+            ExprEnum::Qop(qop) => Expr::Qop {
+                op: qop.op(),
+                args: Box::new([
+                    qop.arg1().into(),
+                    qop.arg2().into(),
+                    qop.arg3().into(),
+                    qop.arg4().into(),
+                ]),
+            },
+            ExprEnum::Triop(triop) => Expr::Triop {
+                op: triop.op(),
+                args: Box::new([
+                    triop.arg1().into(),
+                    triop.arg2().into(),
+                    triop.arg3().into(),
+                ]),
+            },
+            ExprEnum::Binop(binop) => Expr::Binop {
+                op: binop.op(),
+                args: Box::new([binop.arg1().into(), binop.arg2().into()]),
+            },
+            ExprEnum::Unop(unop) => Expr::Unop {
+                op: unop.op(),
+                arg: boxed(unop.arg()),
+            },
+            ExprEnum::Load(load) => Expr::Load {
+                end: load.end(),
+                ty: load.ty(),
+                addr: boxed(load.addr()),
+            },
+            ExprEnum::Const(co) => Expr::Const(co.into()),
+            ExprEnum::ITE(ite) => Expr::ITE {
+                cond: boxed(ite.cond()),
+                if_true: boxed(ite.if_true()),
+                if_false: boxed(ite.if_false()),
+            },
+            ExprEnum::CCall(ccall) => Expr::CCall {
+                callee: ccall.callee().into(),
+                ret_ty: ccall.ret_ty(),
+                args: ccall.args().iter().map(Expr::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<super::Stmt<'_>> for Stmt {
+    fn from(mut stmt: super::Stmt) -> Self {
+        match stmt.as_enum() {
+            StmtEnum::NoOp => Stmt::NoOp,
+            StmtEnum::IMark(imark) => Stmt::IMark {
+                addr: imark.addr(),
+                len: imark.len(),
+                delta: imark.delta(),
+            },
+            StmtEnum::AbiHint(hint) => Stmt::AbiHint {
+                base: hint.base().into(),
+                len: hint.len(),
+                nia: hint.nia().into(),
+            },
+            StmtEnum::Put(put) => Stmt::Put {
+                offset: put.offset(),
+                data: put.data().into(),
+            },
+            StmtEnum::PutI(put_i) => Stmt::PutI {
+                descr: put_i.descr().into(),
+                ix: put_i.ix().into(),
+                bias: put_i.bias(),
+                data: put_i.data().into(),
+            },
+            StmtEnum::WrTmp(wr_tmp) => Stmt::WrTmp {
+                tmp: wr_tmp.tmp(),
+                data: wr_tmp.data().into(),
+            },
+            StmtEnum::Store(store) => Stmt::Store {
+                end: store.end(),
+                addr: store.addr().into(),
+                data: store.data().into(),
+            },
+            StmtEnum::LoadG(load_g) => Stmt::LoadG {
+                end: load_g.end(),
+                cvt: load_g.cvt(),
+                dst: load_g.dst(),
+                addr: load_g.addr().into(),
+                alt: load_g.alt().into(),
+                guard: load_g.guard().into(),
+            },
+            StmtEnum::StoreG(store_g) => Stmt::StoreG {
+                end: store_g.end(),
+                addr: store_g.addr().into(),
+                data: store_g.data().into(),
+                guard: store_g.guard().into(),
+            },
+            StmtEnum::CAS(cas) => Stmt::CAS {
+                old_hi: cas.old_hi(),
+                old_lo: cas.old_lo(),
+                end: cas.end(),
+                addr: cas.addr().into(),
+                expd_hi: cas.expd_hi().map(Expr::from),
+                expd_lo: cas.expd_lo().into(),
+                data_hi: cas.data_hi().map(Expr::from),
+                data_lo: cas.data_lo().into(),
+            },
+            StmtEnum::LLSC(llsc) => Stmt::LLSC {
+                end: llsc.end(),
+                result: llsc.result(),
+                addr: llsc.addr().into(),
+                store_data: llsc.store_data().map(Expr::from),
+            },
+            StmtEnum::Dirty(dirty) => Stmt::Dirty {
+                callee: dirty.callee().into(),
+                guard: dirty.guard().into(),
+                args: dirty.args().iter().map(Expr::from).collect(),
+                tmp: dirty.tmp(),
+                m_fx: dirty.m_fx(),
+                m_addr: dirty.m_addr().map(Expr::from),
+                m_size: dirty.m_size(),
+                fx_state: dirty.fx_state(),
+            },
+            StmtEnum::MBE(mbe) => Stmt::MBE(mbe.event()),
+            StmtEnum::Exit(exit) => Stmt::Exit {
+                guard: exit.guard().into(),
+                dst: exit.dst().into(),
+                jump_kind: exit.jump_kind(),
+                offs_ip: exit.offs_ip(),
+            },
+        }
+    }
+}
+
+impl From<&IRSB<'_>> for Block {
+    fn from(irsb: &IRSB) -> Self {
+        Block {
+            types: irsb.type_env().types().to_vec(),
+            stmts: irsb.iter_stmts().map(Stmt::from).collect(),
+            next: irsb.next().into(),
+            jump_kind: irsb.jump_kind(),
+            offs_ip: unsafe { (*irsb.inner).offsIP },
+        }
+    }
+}
+
+// The conversions below allocate in VEX's heap, and are only called while an IRSB (and
+// therefore the lift lock) is held.
+
+impl Const {
+    unsafe fn to_vex<'a>(self) -> super::Const<'a> {
+        use super::Const as C;
+        match self {
+            Const::U1(val) => C::u1(val),
+            Const::U8(val) => C::u8(val),
+            Const::U16(val) => C::u16(val),
+            Const::U32(val) => C::u32(val),
+            Const::U64(val) => C::u64(val),
+            Const::F32(bits) => C::f32(f32::from_bits(bits)),
+            Const::F32i(val) => C::f32i(val),
+            Const::F64(bits) => C::f64(f64::from_bits(bits)),
+            Const::F64i(val) => C::f64i(val),
+            Const::V128(val) => C::v128(val),
+            Const::V256(val) => C::v256(val),
+        }
+    }
+}
+
+impl RegArray {
+    unsafe fn to_vex<'a>(self) -> super::RegArray<'a> {
+        super::RegArray::new(self.base, self.elem_ty, self.n_elems)
+    }
+}
+
+impl Callee {
+    unsafe fn to_vex<'a>(&self) -> super::Callee<'a> {
+        let mut callee = super::Callee::new(self.regparms, &self.name, self.addr as *mut _);
+        callee.set_mcx_mask(self.mcx_mask);
+        callee
+    }
+}
+
+unsafe fn exprs_to_vex<'a>(exprs: &[Expr]) -> super::ExprVec<'a> {
+    let exprs: Vec<_> = exprs.iter().map(|expr| expr.to_vex()).collect();
+    super::ExprVec::new(&exprs)
+}
+
+impl Expr {
+    unsafe fn to_vex<'a>(&self) -> super::Expr<'a> {
+        use super::Expr as E;
+        match self {
+            Expr::Binder(binder) => E::binder(*binder),
+            Expr::Get { offset, ty } => E::get(*offset, *ty),
+            Expr::GetI { descr, ix, bias } => E::get_i(descr.to_vex(), ix.to_vex(), *bias),
+            Expr::RdTmp(tmp) => E::rd_tmp(*tmp),
+            Expr::Qop { op, args } => {
+                let [arg1, arg2, arg3, arg4] = &**args;
+                E::qop(
+                    *op,
+                    arg1.to_vex(),
+                    arg2.to_vex(),
+                    arg3.to_vex(),
+                    arg4.to_vex(),
+                )
+            }
+            Expr::Triop { op, args } => {
+                let [arg1, arg2, arg3] = &**args;
+                E::triop(*op, arg1.to_vex(), arg2.to_vex(), arg3.to_vex())
+            }
+            Expr::Binop { op, args } => {
+                let [arg1, arg2] = &**args;
+                E::binop(*op, arg1.to_vex(), arg2.to_vex())
+            }
+            Expr::Unop { op, arg } => E::unop(*op, arg.to_vex()),
+            Expr::Load { end, ty, addr } => E::load(*end, *ty, addr.to_vex()),
+            Expr::Const(co) => E::const_(co.to_vex()),
+            Expr::ITE {
+                cond,
+                if_true,
+                if_false,
+            } => E::ite(cond.to_vex(), if_true.to_vex(), if_false.to_vex()),
+            Expr::CCall {
+                callee,
+                ret_ty,
+                args,
+            } => E::ccall(callee.to_vex(), *ret_ty, exprs_to_vex(args)),
+            Expr::VecRet => E::vecret(),
+            Expr::GsPtr => E::gsptr(),
+        }
+    }
+}
+
+impl Stmt {
+    unsafe fn to_vex<'a>(&self) -> super::Stmt<'a> {
+        use super::Stmt as S;
+        match self {
+            Stmt::NoOp => S::no_op(),
+            Stmt::IMark { addr, len, delta } => S::imark(*addr, *len, *delta),
+            Stmt::AbiHint { base, len, nia } => {
+                S::abi_hint(base.to_vex(), *len as u32, nia.to_vex())
+            }
+            Stmt::Put { offset, data } => S::put(*offset, data.to_vex()),
+            Stmt::PutI {
+                descr,
+                ix,
+                bias,
+                data,
+            } => S::put_i(descr.to_vex(), ix.to_vex(), *bias, data.to_vex()),
+            Stmt::WrTmp { tmp, data } => S::wr_tmp(*tmp, data.to_vex()),
+            Stmt::Store { end, addr, data } => S::store(*end, addr.to_vex(), data.to_vex()),
+            Stmt::LoadG {
+                end,
+                cvt,
+                dst,
+                addr,
+                alt,
+                guard,
+            } => S::load_g(
+                *end,
+                *cvt,
+                *dst,
+                addr.to_vex(),
+                alt.to_vex(),
+                guard.to_vex(),
+            ),
+            Stmt::StoreG {
+                end,
+                addr,
+                data,
+                guard,
+            } => S::store_g(*end, addr.to_vex(), data.to_vex(), guard.to_vex()),
+            Stmt::CAS {
+                old_hi,
+                old_lo,
+                end,
+                addr,
+                expd_hi,
+                expd_lo,
+                data_hi,
+                data_lo,
+            } => S::cas(
+                *old_hi,
+                *old_lo,
+                *end,
+                addr.to_vex(),
+                expd_hi.as_ref().map(|expr| expr.to_vex()),
+                expd_lo.to_vex(),
+                data_hi.as_ref().map(|expr| expr.to_vex()),
+                data_lo.to_vex(),
+            ),
+            Stmt::LLSC {
+                end,
+                result,
+                addr,
+                store_data,
+            } => S::llsc(
+                *end,
+                *result,
+                addr.to_vex(),
+                store_data.as_ref().map(|expr| expr.to_vex()),
+            ),
+            Stmt::Dirty {
+                callee,
+                guard,
+                args,
+                tmp,
+                m_fx,
+                m_addr,
+                m_size,
+                fx_state,
+            } => S::dirty(
+                callee.to_vex(),
+                guard.to_vex(),
+                exprs_to_vex(args),
+                *tmp,
+                *m_fx,
+                m_addr.as_ref().map(|expr| expr.to_vex()),
+                *m_size,
+                fx_state,
+            ),
+            Stmt::MBE(event) => S::mbe(*event),
+            Stmt::Exit {
+                guard,
+                dst,
+                jump_kind,
+                offs_ip,
+            } => S::exit(guard.to_vex(), *jump_kind, dst.to_vex(), *offs_ip),
+        }
+    }
+}
+
+impl Block {
+    /// Build an [IRSB] in VEX's heap from this block.
+    pub fn to_irsb<'a>(&self) -> IRSB<'a> {
+        let mut irsb = IRSB::new();
+        let env = irsb.type_env();
+        for &ty in &self.types {
+            env.new_tmp(ty);
+        }
+        unsafe {
+            for stmt in &self.stmts {
+                irsb.add_stmt(stmt.to_vex());
+            }
+            irsb.set_next(self.next.to_vex());
+        }
+        irsb.set_jump_kind(self.jump_kind);
+        irsb.set_offs_ip(self.offs_ip);
+        irsb
+    }
+}
+
+/// (De)serialisation of VEX's enums by their C names, e.g. `Ity_I64` or `Iop_Add64`, so
+/// that the serialised form doesn't depend on their numeric values.
+#[cfg(feature = "serde")]
+pub(super) mod by_name {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::mem;
+
+    use lazy_static::lazy_static;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::super::{Effect, IREndness, JumpKind, LoadGOp, MBusEvent, Op, Type};
+
+    pub trait Named: Copy + Debug + Sized {
+        fn from_name(name: &str) -> Option<Self>;
+    }
+
+    // VEX's enums are contiguous `repr(u32)` enums, so all of their names can be found by
+    // walking from the first variant to the last.
+    macro_rules! named {
+        ($ty:ident, $first:ident ..= $last:ident) => {
+            impl Named for $ty {
+                fn from_name(name: &str) -> Option<Self> {
+                    lazy_static! {
+                        static ref BY_NAME: HashMap<String, $ty> = ($ty::$first as u32
+                            ..=$ty::$last as u32)
+                            .map(|val| unsafe { mem::transmute::<u32, $ty>(val) })
+                            .map(|val| (format!("{:?}", val), val))
+                            .collect();
+                    }
+                    BY_NAME.get(name).copied()
+                }
+            }
+        };
+    }
+
+    named!(Type, Ity_INVALID..=Ity_V256);
+    named!(IREndness, Iend_LE..=Iend_BE);
+    named!(JumpKind, Ijk_INVALID..=Ijk_Sys_sysenter);
+    named!(LoadGOp, ILGop_INVALID..=ILGop_8Sto32);
+    named!(Effect, Ifx_None..=Ifx_Modify);
+    named!(MBusEvent, Imbe_Fence..=Imbe_CancelReservation);
+    // Not `OpInfo::from_name`, as VEX prints some ops differently from their C names.
+    named!(Op, Iop_INVALID..=Iop_LAST);
+
+    pub fn serialize<T: Named, S: Serializer>(val: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", val))
+    }
+
+    pub fn deserialize<'de, T: Named, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        T::from_name(&name)
+            .ok_or_else(|| D::Error::custom(format_args!("unknown VEX name {}", name)))
+    }
+
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<T: Named, S: Serializer>(
+            vals: &[T],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(vals.iter().map(|val| format!("{:?}", val)))
+        }
+
+        pub fn deserialize<'de, T: Named, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<T>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .into_iter()
+                .map(|name| {
+                    T::from_name(&name)
+                        .ok_or_else(|| D::Error::custom(format_args!("unknown VEX name {}", name)))
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Arch, TranslateArgs, VexEndness};
+
+    // mov rax, [rdi]; lock cmpxchg [rsi], rdx; add rax, 1; jz +0; ret
+    const AMD64: &[u8] = &[
+        0x48, 0x8b, 0x07, 0xf0, 0x48, 0x0f, 0xb1, 0x16, 0x48, 0x83, 0xc0, 0x01, 0x74, 0x00, 0xc3,
+    ];
+
+    // Blocks of every guest arch, lifted for an AMD64 host.
+    #[cfg(feature = "serde")]
+    const GUESTS: &[(Arch, VexEndness, &[u8])] = &[
+        (Arch::VexArchAMD64, VexEndness::VexEndnessLE, AMD64),
+        // ldr x2, [x0]; add x0, x0, x1; cbz x2, +8; ret
+        (
+            Arch::VexArchARM64,
+            VexEndness::VexEndnessLE,
+            &[
+                0x02, 0x00, 0x40, 0xf9, 0x00, 0x00, 0x01, 0x8b, 0x42, 0x00, 0x00, 0xb4, 0xc0, 0x03,
+                0x5f, 0xd6,
+            ],
+        ),
+        // ld r5, 0(r3); add r3, r3, r4; blr
+        (
+            Arch::VexArchPPC64,
+            VexEndness::VexEndnessBE,
+            &[
+                0xe8, 0xa3, 0x00, 0x00, 0x7c, 0x63, 0x22, 0x14, 0x4e, 0x80, 0x00, 0x20,
+            ],
+        ),
+        // lg %r4, 0(%r2); agr %r2, %r3; br %r14
+        (
+            Arch::VexArchS390X,
+            VexEndness::VexEndnessBE,
+            &[
+                0xe3, 0x40, 0x20, 0x00, 0x00, 0x04, 0xb9, 0x08, 0x00, 0x23, 0x07, 0xfe,
+            ],
+        ),
+    ];
+
+    #[cfg(feature = "serde")]
+    fn lift(arch: Arch, endness: VexEndness, code: &[u8]) -> Block {
+        let mut vta =
+            TranslateArgs::new_cross(arch, endness, Arch::VexArchAMD64, VexEndness::VexEndnessLE);
+        let irsb = vta.front_end(code.as_ptr(), 0x1000).unwrap();
+        Block::from(&irsb)
+    }
+
+    #[test]
+    fn to_irsb() {
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let irsb = vta.front_end(AMD64.as_ptr(), 0x1000).unwrap();
+        let block = Block::from(&irsb);
+        assert!(block
+            .stmts
+            .iter()
+            .any(|stmt| matches!(stmt, Stmt::CAS { .. })));
+
+        let copy = block.to_irsb();
+        assert_eq!(copy, irsb);
+        assert_eq!(Block::from(&copy), block);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        // Without libvexmultiarch, only the host's arch can be lifted.
+        let guests = GUESTS
+            .iter()
+            .filter(|&&(arch, ..)| vex_sys::MULTIARCH || arch == Arch::VexArchAMD64);
+        for &(arch, endness, code) in guests {
+            let block = lift(arch, endness, code);
+
+            let json = serde_json::to_string(&block).unwrap();
+            assert!(json.contains(r#""version":1"#));
+            assert!(json.contains("Iop_Add64"), "{:?}: {}", arch, json);
+            assert_eq!(serde_json::from_str::<Block>(&json).unwrap(), block);
+
+            let bin = bincode::serialize(&block).unwrap();
+            assert_eq!(bincode::deserialize::<Block>(&bin).unwrap(), block);
+
+            let old = json.replace(r#""version":1"#, r#""version":0"#);
+            assert!(serde_json::from_str::<Block>(&old).is_err());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn op_names() {
+        // Serialised by its C name, whatever VEX prints it as.
+        let op = Op::Iop_PwBitMtxXpose64x2;
+        let json = serde_json::to_string(&Expr::Unop {
+            op,
+            arg: Box::new(Expr::RdTmp(0)),
+        })
+        .unwrap();
+        assert!(json.contains("Iop_PwBitMtxXpose64x2"));
+        match serde_json::from_str(&json).unwrap() {
+            Expr::Unop { op: parsed, .. } => assert_eq!(parsed, op),
+            expr => panic!("expected a Unop, got {:?}", expr),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn floats() {
+        for co in [
+            Const::F32(f32::NAN.to_bits()),
+            Const::F32(f32::NEG_INFINITY.to_bits()),
+            Const::F64(f64::NAN.to_bits()),
+            Const::F64(f64::INFINITY.to_bits()),
+            Const::F64(1.5f64.to_bits()),
+        ] {
+            let json = serde_json::to_string(&co).unwrap();
+            assert_eq!(serde_json::from_str::<Const>(&json).unwrap(), co);
+        }
+    }
+}
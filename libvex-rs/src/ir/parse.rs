@@ -0,0 +1,885 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+
+use vex_sys::{ppIRJumpKind, ppIRType};
+
+use super::owned::{Block, Callee, Const, Expr, RegArray, Stmt};
+use super::{Effect, FxState, IREndness, JumpKind, LoadGOp, MBusEvent, Op, OpInfo, Type, IRSB};
+use crate::logger;
+
+/// An error in the textual IR passed to [IRSB::parse] or [Block::parse].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+lazy_static! {
+    // Names as printed by ppIRType and ppIRJumpKind (e.g. `I64` and `Return`).
+    static ref TYPES: HashMap<String, Type> = {
+        crate::init();
+        (Type::Ity_I1 as u32..=Type::Ity_V256 as u32)
+            .map(|ty| unsafe { mem::transmute::<u32, Type>(ty) })
+            .map(|ty| (logger::with(|| unsafe { ppIRType(ty) }).1.unwrap(), ty))
+            .collect()
+    };
+    static ref JUMP_KINDS: HashMap<String, JumpKind> = {
+        crate::init();
+        (JumpKind::Ijk_Boring as u32..=JumpKind::Ijk_Sys_sysenter as u32)
+            .map(|jk| unsafe { mem::transmute::<u32, JumpKind>(jk) })
+            .map(|jk| (logger::with(|| unsafe { ppIRJumpKind(jk) }).1.unwrap(), jk))
+            .collect()
+    };
+}
+
+fn is_tmp(word: &str) -> bool {
+    word.len() > 1 && word.starts_with('t') && word[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+enum Item {
+    Stmt(Box<Stmt>),
+    End {
+        next: Expr,
+        jump_kind: JumpKind,
+        offs_ip: i32,
+    },
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn error<T>(&self, message: impl Into<String>) -> Result<T> {
+        let before = &self.src[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Err(ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        })
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_ws(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self, lit: &str) -> bool {
+        self.skip_ws();
+        self.rest().starts_with(lit)
+    }
+
+    fn eat(&mut self, lit: &str) -> bool {
+        let found = self.peek(lit);
+        if found {
+            self.pos += lit.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, lit: &str) -> Result<()> {
+        if self.eat(lit) {
+            Ok(())
+        } else {
+            self.error(format!("expected `{}`", lit))
+        }
+    }
+
+    fn peek_word(&mut self) -> &'s str {
+        self.skip_ws();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        &rest[..len]
+    }
+
+    fn word(&mut self) -> Result<&'s str> {
+        let word = self.peek_word();
+        if word.is_empty() {
+            return self.error("expected a name or a number");
+        }
+        self.pos += word.len();
+        Ok(word)
+    }
+
+    fn number<T: FromStr>(&mut self, word: &str) -> Result<T> {
+        match word.parse() {
+            Ok(num) => Ok(num),
+            Err(_) => self.error(format!("invalid number `{}`", word)),
+        }
+    }
+
+    fn int<T: FromStr>(&mut self) -> Result<T> {
+        let neg = self.eat("-");
+        let word = self.word()?;
+        if neg {
+            self.number(&format!("-{}", word))
+        } else {
+            self.number(word)
+        }
+    }
+
+    fn hex_word(&mut self, word: &str) -> Result<u64> {
+        match word
+            .strip_prefix("0x")
+            .map(|hex| u64::from_str_radix(hex, 16))
+        {
+            Some(Ok(num)) => Ok(num),
+            _ => self.error(format!("invalid hex number `{}`", word)),
+        }
+    }
+
+    fn hex<T: TryFrom<u64>>(&mut self) -> Result<T> {
+        let word = self.word()?;
+        let num = self.hex_word(word)?;
+        match T::try_from(num) {
+            Ok(num) => Ok(num),
+            Err(_) => self.error(format!("`{}` is out of range", word)),
+        }
+    }
+
+    fn tmp(&mut self) -> Result<u32> {
+        let word = self.word()?;
+        if !is_tmp(word) {
+            return self.error(format!("expected a temp, found `{}`", word));
+        }
+        self.number(&word[1..])
+    }
+
+    fn ty(&mut self) -> Result<Type> {
+        let word = self.word()?;
+        match TYPES.get(word) {
+            Some(&ty) => Ok(ty),
+            None => self.error(format!("unknown type `{}`", word)),
+        }
+    }
+
+    fn jump_kind(&mut self) -> Result<JumpKind> {
+        let word = self.word()?;
+        match JUMP_KINDS.get(word) {
+            Some(&jk) => Ok(jk),
+            None => self.error(format!("unknown jump kind `{}`", word)),
+        }
+    }
+
+    fn endness(&mut self, word: &str, prefix: &str) -> Result<IREndness> {
+        match word.strip_prefix(prefix) {
+            Some("le") => Ok(IREndness::Iend_LE),
+            Some("be") => Ok(IREndness::Iend_BE),
+            _ => self.error(format!("expected `{}le` or `{}be`", prefix, prefix)),
+        }
+    }
+
+    fn effect(&mut self) -> Result<Effect> {
+        Ok(match self.word()? {
+            "noFX" => Effect::Ifx_None,
+            "RdFX" => Effect::Ifx_Read,
+            "WrFX" => Effect::Ifx_Write,
+            "MoFX" => Effect::Ifx_Modify,
+            word => return self.error(format!("unknown effect `{}`", word)),
+        })
+    }
+
+    fn load_g_op(&mut self) -> Result<LoadGOp> {
+        Ok(match self.word()? {
+            "IdentV128" => LoadGOp::ILGop_IdentV128,
+            "Ident64" => LoadGOp::ILGop_Ident64,
+            "Ident32" => LoadGOp::ILGop_Ident32,
+            "16Uto32" => LoadGOp::ILGop_16Uto32,
+            "16Sto32" => LoadGOp::ILGop_16Sto32,
+            "8Uto32" => LoadGOp::ILGop_8Uto32,
+            "8Sto32" => LoadGOp::ILGop_8Sto32,
+            word => return self.error(format!("unknown guarded load conversion `{}`", word)),
+        })
+    }
+
+    // `(base:NxTy)`
+    fn reg_array(&mut self) -> Result<RegArray> {
+        self.expect("(")?;
+        let base = self.int()?;
+        self.expect(":")?;
+        let word = self.word()?;
+        let (n_elems, elem_ty) = match word.find('x') {
+            Some(idx) => (&word[..idx], &word[idx + 1..]),
+            None => return self.error("expected `<elements>x<type>`"),
+        };
+        let n_elems = self.number(n_elems)?;
+        let elem_ty = match TYPES.get(elem_ty) {
+            Some(&ty) => ty,
+            None => return self.error(format!("unknown type `{}`", elem_ty)),
+        };
+        self.expect(")")?;
+        Ok(RegArray {
+            base,
+            elem_ty,
+            n_elems,
+        })
+    }
+
+    // `name[rp=N][mcx=0xM]{0xaddr}`, after the name.
+    fn callee(&mut self, name: &str) -> Result<Callee> {
+        let regparms = if self.eat("[rp=") {
+            let regparms = self.int()?;
+            self.expect("]")?;
+            regparms
+        } else {
+            0
+        };
+        let mcx_mask = if self.eat("[mcx=") {
+            let mcx_mask = self.hex()?;
+            self.expect("]")?;
+            mcx_mask
+        } else {
+            0
+        };
+        self.expect("{")?;
+        let addr = self.hex()?;
+        self.expect("}")?;
+        Ok(Callee {
+            regparms,
+            name: name.to_owned(),
+            addr,
+            mcx_mask,
+        })
+    }
+
+    fn args(&mut self) -> Result<Vec<Expr>> {
+        self.expect("(")?;
+        let mut args = Vec::new();
+        if self.eat(")") {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.expr()?);
+            if self.eat(")") {
+                return Ok(args);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    // The value of a constant, after its leading word (e.g. `0x10` in `0x10:I64`).
+    fn constant(&mut self, word: &str) -> Result<Const> {
+        if self.eat("{") {
+            let bits = self.hex::<u64>()?;
+            self.expect("}")?;
+            let (u32_bits, u16_bits) = (bits as u32, bits as u16);
+            if (word == "V128" && bits != u16_bits as u64)
+                || (matches!(word, "F32" | "F32i" | "V256") && bits != u32_bits as u64)
+            {
+                return self.error(format!("`{:#x}` is out of range for {}", bits, word));
+            }
+            return Ok(match word {
+                "F32" => Const::F32(u32_bits),
+                "F32i" => Const::F32i(u32_bits),
+                "F64" => Const::F64(bits),
+                "F64i" => Const::F64i(bits),
+                "V128" => Const::V128(u16_bits),
+                "V256" => Const::V256(u32_bits),
+                _ => unreachable!(),
+            });
+        }
+
+        let val = if word.starts_with("0x") {
+            self.hex_word(word)?
+        } else {
+            self.number(word)?
+        };
+        self.expect(":")?;
+        let ty = self.ty()?;
+        let out_of_range =
+            |this: &Self| this.error(format!("`{}` is out of range for {:?}", word, ty));
+        Ok(match ty {
+            Type::Ity_I1 if val <= 1 => Const::U1(val == 1),
+            Type::Ity_I8 if val <= u8::MAX as u64 => Const::U8(val as u8),
+            Type::Ity_I16 if val <= u16::MAX as u64 => Const::U16(val as u16),
+            Type::Ity_I32 if val <= u32::MAX as u64 => Const::U32(val as u32),
+            Type::Ity_I64 => Const::U64(val),
+            Type::Ity_I1 | Type::Ity_I8 | Type::Ity_I16 | Type::Ity_I32 => {
+                return out_of_range(self)
+            }
+            _ => return self.error(format!("invalid constant type {:?}", ty)),
+        })
+    }
+
+    fn boxed(&mut self) -> Result<Box<Expr>> {
+        self.expr().map(Box::new)
+    }
+
+    fn expr(&mut self) -> Result<Expr> {
+        self.skip_ws();
+        let start = self.pos;
+        let word = self.word()?;
+        Ok(match word {
+            "GET" => {
+                self.expect(":")?;
+                let ty = self.ty()?;
+                self.expect("(")?;
+                let offset = self.int()?;
+                self.expect(")")?;
+                Expr::Get { offset, ty }
+            }
+            "GETI" => {
+                let descr = self.reg_array()?;
+                self.expect("[")?;
+                let ix = self.boxed()?;
+                self.expect(",")?;
+                let bias = self.int()?;
+                self.expect("]")?;
+                Expr::GetI { descr, ix, bias }
+            }
+            "LDle" | "LDbe" => {
+                let end = self.endness(word, "LD")?;
+                self.expect(":")?;
+                let ty = self.ty()?;
+                self.expect("(")?;
+                let addr = self.boxed()?;
+                self.expect(")")?;
+                Expr::Load { end, ty, addr }
+            }
+            "ITE" => {
+                self.expect("(")?;
+                let cond = self.boxed()?;
+                self.expect(",")?;
+                let if_true = self.boxed()?;
+                self.expect(",")?;
+                let if_false = self.boxed()?;
+                self.expect(")")?;
+                Expr::ITE {
+                    cond,
+                    if_true,
+                    if_false,
+                }
+            }
+            "BIND" => {
+                self.expect("-")?;
+                Expr::Binder(self.int()?)
+            }
+            "VECRET" => Expr::VecRet,
+            "GSPTR" => Expr::GsPtr,
+            "F32" | "F32i" | "F64" | "F64i" | "V128" | "V256" if self.peek("{") => {
+                Expr::Const(self.constant(word)?)
+            }
+            _ if is_tmp(word) && !self.peek("(") => Expr::RdTmp(self.number(&word[1..])?),
+            _ if word.starts_with(|c: char| c.is_ascii_digit()) && self.peek(":") => {
+                Expr::Const(self.constant(word)?)
+            }
+            _ if self.peek("(") => {
+                let op = match Op::from_name(word) {
+                    Some(op) => op,
+                    None => {
+                        self.pos = start;
+                        return self.error(format!("unknown op `{}`", word));
+                    }
+                };
+                let mut args = self.args()?.into_iter();
+                match (
+                    args.next(),
+                    args.next(),
+                    args.next(),
+                    args.next(),
+                    args.next(),
+                ) {
+                    (Some(arg), None, None, None, None) => Expr::Unop {
+                        op,
+                        arg: Box::new(arg),
+                    },
+                    (Some(arg1), Some(arg2), None, None, None) => Expr::Binop {
+                        op,
+                        args: Box::new([arg1, arg2]),
+                    },
+                    (Some(arg1), Some(arg2), Some(arg3), None, None) => Expr::Triop {
+                        op,
+                        args: Box::new([arg1, arg2, arg3]),
+                    },
+                    (Some(arg1), Some(arg2), Some(arg3), Some(arg4), None) => Expr::Qop {
+                        op,
+                        args: Box::new([arg1, arg2, arg3, arg4]),
+                    },
+                    _ => return self.error(format!("{} must take 1 to 4 arguments", word)),
+                }
+            }
+            _ => {
+                let callee = self.callee(word)?;
+                let args = self.args()?;
+                self.expect(":")?;
+                let ret_ty = self.ty()?;
+                Expr::CCall {
+                    callee,
+                    ret_ty,
+                    args,
+                }
+            }
+        })
+    }
+
+    // `t = DIRTY guard RdFX-mem(addr,size) MoFX-gst(off,size[,repsN,stepM]) ::: callee(args)`,
+    // after `DIRTY`.
+    fn dirty(&mut self, tmp: Option<u32>) -> Result<Stmt> {
+        let guard = self.expr()?;
+        let mut m_fx = Effect::Ifx_None;
+        let mut m_addr = None;
+        let mut m_size = 0;
+        let mut fx_state = Vec::new();
+        while !self.peek(":::") {
+            let fx = self.effect()?;
+            if self.eat("-mem(") {
+                m_fx = fx;
+                m_addr = Some(self.expr()?);
+                self.expect(",")?;
+                m_size = self.int()?;
+            } else {
+                self.expect("-gst(")?;
+                let offset = self.int()?;
+                self.expect(",")?;
+                let size = self.int()?;
+                let (mut n_repeats, mut repeat_len) = (0, 0);
+                if self.eat(",reps") {
+                    n_repeats = self.int()?;
+                    self.expect(",step")?;
+                    repeat_len = self.int()?;
+                }
+                fx_state.push(FxState {
+                    fx,
+                    offset,
+                    size,
+                    n_repeats,
+                    repeat_len,
+                });
+            }
+            self.expect(")")?;
+        }
+        self.expect(":::")?;
+        let name = self.word()?;
+        let callee = self.callee(name)?;
+        let args = self.args()?;
+        Ok(Stmt::Dirty {
+            callee,
+            guard,
+            args,
+            tmp,
+            m_fx,
+            m_addr,
+            m_size,
+            fx_state,
+        })
+    }
+
+    // `oldHi,oldLo = CASle(addr::expdHi,expdLo->dataHi,dataLo)`, after the `=`.
+    fn cas(&mut self, old_hi: Option<u32>, old_lo: u32) -> Result<Stmt> {
+        let word = self.word()?;
+        let end = self.endness(word, "CAS")?;
+        self.expect("(")?;
+        let addr = self.expr()?;
+        self.expect("::")?;
+        let mut expd_lo = self.expr()?;
+        let expd_hi = if self.eat(",") {
+            Some(mem::replace(&mut expd_lo, self.expr()?))
+        } else {
+            None
+        };
+        self.expect("->")?;
+        let mut data_lo = self.expr()?;
+        let data_hi = if self.eat(",") {
+            Some(mem::replace(&mut data_lo, self.expr()?))
+        } else {
+            None
+        };
+        self.expect(")")?;
+        Ok(Stmt::CAS {
+            old_hi,
+            old_lo,
+            end,
+            addr,
+            expd_hi,
+            expd_lo,
+            data_hi,
+            data_lo,
+        })
+    }
+
+    // Everything that starts with `t = `.
+    fn assignment(&mut self, tmp: u32) -> Result<Stmt> {
+        if self.eat(",") {
+            let old_lo = self.tmp()?;
+            self.expect("=")?;
+            return self.cas(Some(tmp), old_lo);
+        }
+        self.expect("=")?;
+
+        if self.eat("if-strict") {
+            self.expect("(")?;
+            let guard = self.expr()?;
+            self.expect(")")?;
+            let cvt = self.load_g_op()?;
+            self.expect("(")?;
+            let word = self.word()?;
+            let end = self.endness(word, "LD")?;
+            self.expect("(")?;
+            let addr = self.expr()?;
+            self.expect(")")?;
+            self.expect(")")?;
+            self.expect("else")?;
+            let alt = self.expr()?;
+            return Ok(Stmt::LoadG {
+                end,
+                cvt,
+                dst: tmp,
+                addr,
+                alt,
+                guard,
+            });
+        }
+        if self.peek("CASle(") || self.peek("CASbe(") {
+            return self.cas(None, tmp);
+        }
+        if self.peek("LDle-Linked(") || self.peek("LDbe-Linked(") {
+            let word = self.word()?;
+            let end = self.endness(word, "LD")?;
+            self.expect("-Linked(")?;
+            let addr = self.expr()?;
+            self.expect(")")?;
+            return Ok(Stmt::LLSC {
+                end,
+                result: tmp,
+                addr,
+                store_data: None,
+            });
+        }
+        if self.eat("(") {
+            let word = self.word()?;
+            let end = self.endness(word, "ST")?;
+            self.expect("-Cond(")?;
+            let addr = self.expr()?;
+            self.expect(")")?;
+            self.expect("=")?;
+            let data = self.expr()?;
+            self.expect(")")?;
+            return Ok(Stmt::LLSC {
+                end,
+                result: tmp,
+                addr,
+                store_data: Some(data),
+            });
+        }
+        if self.peek_word() == "DIRTY" {
+            self.word()?;
+            return self.dirty(Some(tmp));
+        }
+        Ok(Stmt::WrTmp {
+            tmp,
+            data: self.expr()?,
+        })
+    }
+
+    fn stmt(&mut self) -> Result<Item> {
+        if self.eat("------") {
+            self.expect("IMark(")?;
+            let addr = self.hex()?;
+            self.expect(",")?;
+            let len = self.int()?;
+            self.expect(",")?;
+            let delta = self.int()?;
+            self.expect(")")?;
+            self.expect("------")?;
+            return Ok(Item::Stmt(Box::new(Stmt::IMark { addr, len, delta })));
+        }
+        if self.eat("======") {
+            self.expect("AbiHint(")?;
+            let base = self.expr()?;
+            self.expect(",")?;
+            let len = self.int()?;
+            self.expect(",")?;
+            let nia = self.expr()?;
+            self.expect(")")?;
+            self.expect("======")?;
+            return Ok(Item::Stmt(Box::new(Stmt::AbiHint { base, len, nia })));
+        }
+
+        let word = self.word()?;
+        let stmt = match word {
+            "IR" => {
+                self.expect("-")?;
+                match self.word()? {
+                    "NoOp" => Stmt::NoOp,
+                    "Fence" => Stmt::MBE(MBusEvent::Imbe_Fence),
+                    "CancelReservation" => Stmt::MBE(MBusEvent::Imbe_CancelReservation),
+                    word => return self.error(format!("unknown statement `IR-{}`", word)),
+                }
+            }
+            "PUT" => {
+                self.expect("(")?;
+                let offset = self.int()?;
+                self.expect(")")?;
+                self.expect("=")?;
+                let data = self.expr()?;
+                if self.eat(";") {
+                    self.expect("exit-")?;
+                    return Ok(Item::End {
+                        next: data,
+                        jump_kind: self.jump_kind()?,
+                        offs_ip: offset,
+                    });
+                }
+                Stmt::Put { offset, data }
+            }
+            "PUTI" => {
+                let descr = self.reg_array()?;
+                self.expect("[")?;
+                let ix = self.expr()?;
+                self.expect(",")?;
+                let bias = self.int()?;
+                self.expect("]")?;
+                self.expect("=")?;
+                let data = self.expr()?;
+                Stmt::PutI {
+                    descr,
+                    ix,
+                    bias,
+                    data,
+                }
+            }
+            "STle" | "STbe" => {
+                let end = self.endness(word, "ST")?;
+                self.expect("(")?;
+                let addr = self.expr()?;
+                self.expect(")")?;
+                self.expect("=")?;
+                let data = self.expr()?;
+                Stmt::Store { end, addr, data }
+            }
+            "if" => {
+                self.expect("(")?;
+                let guard = self.expr()?;
+                self.expect(")")?;
+                self.expect("{")?;
+                let stmt = if self.eat("PUT") {
+                    self.expect("(")?;
+                    let offs_ip = self.int()?;
+                    self.expect(")")?;
+                    self.expect("=")?;
+                    let word = self.word()?;
+                    let dst = self.constant(word)?;
+                    self.expect(";")?;
+                    self.expect("exit-")?;
+                    let jump_kind = self.jump_kind()?;
+                    Stmt::Exit {
+                        guard,
+                        dst,
+                        jump_kind,
+                        offs_ip,
+                    }
+                } else {
+                    let word = self.word()?;
+                    let end = self.endness(word, "ST")?;
+                    self.expect("(")?;
+                    let addr = self.expr()?;
+                    self.expect(")")?;
+                    self.expect("=")?;
+                    let data = self.expr()?;
+                    Stmt::StoreG {
+                        end,
+                        addr,
+                        data,
+                        guard,
+                    }
+                };
+                self.expect("}")?;
+                stmt
+            }
+            "DIRTY" => self.dirty(None)?,
+            _ if is_tmp(word) => {
+                let tmp = self.number(&word[1..])?;
+                self.assignment(tmp)?
+            }
+            _ => return self.error(format!("unknown statement `{}`", word)),
+        };
+        Ok(Item::Stmt(Box::new(stmt)))
+    }
+
+    fn block(&mut self) -> Result<Block> {
+        let wrapped = self.eat("IRSB");
+        if wrapped {
+            self.expect("{")?;
+        }
+
+        let mut types = Vec::new();
+        loop {
+            let start = self.pos;
+            let word = self.peek_word();
+            if !is_tmp(word) {
+                break;
+            }
+            self.pos += word.len();
+            if !self.rest().starts_with(':') {
+                self.pos = start;
+                break;
+            }
+            self.pos += 1;
+            if self.number::<usize>(&word[1..])? != types.len() {
+                self.pos = start;
+                return self.error(format!("expected t{} to be declared next", types.len()));
+            }
+            types.push(self.ty()?);
+        }
+
+        let mut stmts = Vec::new();
+        let (next, jump_kind, offs_ip) = loop {
+            match self.stmt()? {
+                Item::Stmt(stmt) => stmts.push(*stmt),
+                Item::End {
+                    next,
+                    jump_kind,
+                    offs_ip,
+                } => break (next, jump_kind, offs_ip),
+            }
+        };
+
+        if wrapped {
+            self.expect("}")?;
+        }
+        self.skip_ws();
+        if !self.rest().is_empty() {
+            return self.error("expected the end of the block");
+        }
+
+        Ok(Block {
+            types,
+            stmts,
+            next,
+            jump_kind,
+            offs_ip,
+        })
+    }
+}
+
+impl Block {
+    /// Parse a block in the format printed by `ppIRSB` (and `Display for IRSB`).
+    ///
+    /// The surrounding `IRSB { ... }` is optional, and whitespace is insignificant.
+    pub fn parse(src: &str) -> Result<Self> {
+        Parser { src, pos: 0 }.block()
+    }
+}
+
+impl FromStr for Block {
+    type Err = ParseError;
+
+    fn from_str(src: &str) -> Result<Self> {
+        Self::parse(src)
+    }
+}
+
+impl IRSB<'_> {
+    /// Parse a block in the format printed by `ppIRSB` (and `Display for IRSB`).
+    ///
+    /// See [Block::parse].
+    pub fn parse(src: &str) -> Result<Self> {
+        Ok(Block::parse(src)?.to_irsb())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::*;
+    use crate::{Arch, TranslateArgs, VexEndness};
+
+    #[test]
+    fn lifted() {
+        // mov rax, [rdi]; lock cmpxchg [rsi], rdx; cmovz rax, rcx; add rax, 1; jz +0; ret
+        let code = [
+            0x48, 0x8b, 0x07, 0xf0, 0x48, 0x0f, 0xb1, 0x16, 0x48, 0x0f, 0x44, 0xc1, 0x48, 0x83,
+            0xc0, 0x01, 0x74, 0x00, 0xc3,
+        ];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let irsb = vta.front_end(code.as_ptr(), 0x1000).unwrap();
+
+        assert_eq!(IRSB::parse(&irsb.to_string()).unwrap(), irsb);
+    }
+
+    // Every kind of statement and expression, as printed by VEX.
+    pub(crate) const ALL_KINDS: &str = "IRSB {
+        t0:I64   t1:I64   t2:I1   t3:I32   t4:I32   t5:I64   t6:I64   t7:I1
+        t8:F64   t9:V128
+
+        IR-NoOp
+        ------ IMark(0x1000, 4, 0) ------
+        ====== AbiHint(0x20:I64, 128, t0) ======
+        t0 = GET:I64(48)
+        t1 = GETI(136:8xI64)[t3,-1]
+        PUTI(136:8xI64)[t3,1] = t1
+        t2 = CmpEQ64(t0,t1)
+        t5 = ITE(t2,LDle:I64(t0),0x0:I64)
+        t6 = amd64g_calculate_rflags_c[mcx=0x9]{0x4000}(0x8:I64,t0,t1,0x0:I64):I64
+        t8 = AddF64(0x0:I32,F64{0x3ff0000000000000},GET:F64(200))
+        t9 = 64HLtoV128(t0,t0)
+        PUT(216) = V128{0xff00}
+        t4 = if-strict (t2) 16Uto32(LDle(t0)) else 0x0:I32
+        if (t2) { STbe(t0) = t3 }
+        t3 = CASle(t0::t3->t4)
+        t5,t6 = CASle(t0::t5,t6->t1,t0)
+        t0 = LDle-Linked(t1)
+        t7 = ( STle-Cond(t1) = t0 )
+        IR-Fence
+        t1 = DIRTY t2 RdFX-mem(t0,16) RdFX-gst(16,8) MoFX-gst(200,8,reps4,step16) ::: amd64g_dirtyhelper_CPUID_baseline[rp=1]{0x5000}(GSPTR,t0)
+        DIRTY 1:I1 ::: amd64g_dirtyhelper_RDTSC{0x6000}()
+        STle(t0) = F32{0x3f800000}
+        if (t2) { PUT(184) = 0x1004:I64; exit-SigSEGV }
+        PUT(184) = t5; exit-Return
+    }";
+
+    #[test]
+    fn all_kinds() {
+        let block = Block::parse(ALL_KINDS).unwrap();
+        assert_eq!(block.types.len(), 10);
+        assert_eq!(block.stmts.len(), 23);
+        assert_eq!(block.jump_kind, JumpKind::Ijk_Ret);
+
+        let irsb = block.to_irsb();
+        let printed = irsb.to_string();
+        assert_eq!(Block::parse(&printed).unwrap(), block);
+        assert_eq!(IRSB::parse(&printed).unwrap(), irsb);
+    }
+
+    #[test]
+    fn errors() {
+        let err =
+            Block::parse("t0:I64\n   t0 = Foo64(t0)\n   PUT(184) = t0; exit-Boring").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(err.message, "unknown op `Foo64`");
+
+        assert!(Block::parse("t1:I64 PUT(184) = 0x0:I64; exit-Boring").is_err());
+        assert!(Block::parse("PUT(184) = 0x100:I8; exit-Boring").is_err());
+        assert!(Block::parse("PUT(184) = 0x0:I64; exit-Boring }").is_err());
+        assert!(Block::parse("PUT(16) = 0x0:I64").is_err());
+    }
+}
@@ -0,0 +1,538 @@
+use std::fmt::{self, Display, Formatter};
+
+use vex_sys::{typeOfIRConst, typeOfIRLoadGOp, IRExprTag, Int};
+
+use super::{ConstEnum, Effect, Expr, ExprEnum, Op, OpInfo, Stmt, StmtEnum, Temp, Type, IRSB};
+
+/// A problem found by [IRSB::validate].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IrError {
+    /// The index of the offending statement, or `None` if the problem is in the block's
+    /// `next`, `jump_kind` or `offs_ip`.
+    pub stmt: Option<usize>,
+    pub kind: IrErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IrErrorKind {
+    /// An expression doesn't have the type required by its context.
+    IllTyped {
+        context: &'static str,
+        expected: Type,
+        found: Type,
+    },
+    /// An expression has a type which is never allowed in its context (e.g. `GET:I1`).
+    BadType { context: &'static str, found: Type },
+    /// An op was given the wrong number of arguments.
+    WrongArity {
+        op: Op,
+        expected: usize,
+        found: usize,
+    },
+    /// A temp that isn't in the block's type environment.
+    UndefinedTemp(Temp),
+    /// A temp that is read before it is assigned.
+    UseBeforeDef(Temp),
+    /// A temp that is assigned more than once.
+    MultiplyAssigned(Temp),
+    /// An expression kind which isn't allowed in its context (e.g. `GSPTR` outside of a
+    /// dirty call).
+    UnexpectedExpr(IRExprTag),
+    /// A statement field with a value VEX considers implausible.
+    Implausible(&'static str),
+    /// An `offs_ip` which can't point at a guest register.
+    BadOffsIp(Int),
+    /// The block has no `next` expression.
+    MissingNext,
+    /// A statement (or `next`) that isn't flat.
+    NotFlat,
+}
+
+impl Display for IrError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.stmt {
+            Some(idx) => write!(f, "statement {}: ", idx)?,
+            None => write!(f, "block exit: ")?,
+        }
+        match &self.kind {
+            IrErrorKind::IllTyped {
+                context,
+                expected,
+                found,
+            } => write!(f, "{}: expected {:?}, found {:?}", context, expected, found),
+            IrErrorKind::BadType { context, found } => {
+                write!(f, "{}: type {:?} is not allowed", context, found)
+            }
+            IrErrorKind::WrongArity {
+                op,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} takes {} arguments, but was given {}",
+                op.name(),
+                expected,
+                found
+            ),
+            IrErrorKind::UndefinedTemp(tmp) => write!(f, "t{} is not in the type environment", tmp),
+            IrErrorKind::UseBeforeDef(tmp) => write!(f, "t{} is used before it is assigned", tmp),
+            IrErrorKind::MultiplyAssigned(tmp) => write!(f, "t{} is assigned more than once", tmp),
+            IrErrorKind::UnexpectedExpr(tag) => write!(f, "unexpected {:?}", tag),
+            IrErrorKind::Implausible(what) => write!(f, "implausible {}", what),
+            IrErrorKind::BadOffsIp(offs_ip) => write!(f, "bad offs_ip {}", offs_ip),
+            IrErrorKind::MissingNext => write!(f, "next is not set"),
+            IrErrorKind::NotFlat => write!(f, "not flat"),
+        }
+    }
+}
+
+impl std::error::Error for IrError {}
+
+// The first 16 bytes of every guest state hold the event check counter and fail address.
+const MIN_OFFS_IP: Int = 16;
+
+struct Checker<'t> {
+    types: &'t [Type],
+    defined: Vec<bool>,
+    word: Option<Type>,
+    stmt: Option<usize>,
+    errors: Vec<IrError>,
+}
+
+impl Checker<'_> {
+    fn error(&mut self, kind: IrErrorKind) {
+        self.errors.push(IrError {
+            stmt: self.stmt,
+            kind,
+        });
+    }
+
+    fn expect(&mut self, context: &'static str, expected: Type, found: Option<Type>) {
+        match found {
+            Some(found) if found != expected => self.error(IrErrorKind::IllTyped {
+                context,
+                expected,
+                found,
+            }),
+            _ => {}
+        }
+    }
+
+    fn expect_word(&mut self, context: &'static str, found: Option<Type>) {
+        if let Some(word) = self.word {
+            self.expect(context, word, found);
+        }
+    }
+
+    fn forbid_i1(&mut self, context: &'static str, found: Option<Type>) {
+        if found == Some(Type::Ity_I1) {
+            self.error(IrErrorKind::BadType {
+                context,
+                found: Type::Ity_I1,
+            });
+        }
+    }
+
+    fn tmp_type(&mut self, tmp: Temp) -> Option<Type> {
+        let ty = self.types.get(tmp as usize).copied();
+        if ty.is_none() {
+            self.error(IrErrorKind::UndefinedTemp(tmp));
+        }
+        ty
+    }
+
+    fn use_tmp(&mut self, tmp: Temp) -> Option<Type> {
+        let ty = self.tmp_type(tmp)?;
+        if !self.defined[tmp as usize] {
+            self.error(IrErrorKind::UseBeforeDef(tmp));
+        }
+        Some(ty)
+    }
+
+    fn def_tmp(&mut self, tmp: Temp) -> Option<Type> {
+        let ty = self.tmp_type(tmp)?;
+        if self.defined[tmp as usize] {
+            self.error(IrErrorKind::MultiplyAssigned(tmp));
+        }
+        self.defined[tmp as usize] = true;
+        Some(ty)
+    }
+
+    fn op(&mut self, op: Op, args: &[Expr]) -> Option<Type> {
+        let arg_tys: Vec<_> = args.iter().map(|arg| self.expr(*arg)).collect();
+        let (ret, expected) = op.signature();
+        if expected.len() != args.len() {
+            self.error(IrErrorKind::WrongArity {
+                op,
+                expected: expected.len(),
+                found: args.len(),
+            });
+            return Some(ret);
+        }
+        for (&expected, found) in expected.iter().zip(arg_tys) {
+            self.expect("op argument", expected, found);
+        }
+        Some(ret)
+    }
+
+    /// Type check an expression, returning its type (if it could be determined).
+    fn expr(&mut self, expr: Expr) -> Option<Type> {
+        if let tag @ (IRExprTag::Iex_Binder | IRExprTag::Iex_VECRET | IRExprTag::Iex_GSPTR) =
+            expr.kind()
+        {
+            self.error(IrErrorKind::UnexpectedExpr(tag));
+            return None;
+        }
+        match expr.as_enum() {
+            ExprEnum::Binder(_) => unreachable!(),
+            ExprEnum::Get(get) => {
+                self.forbid_i1("GET", Some(get.ty()));
+                Some(get.ty())
+            }
+            ExprEnum::GetI(mut get_i) => {
+                let ix = self.expr(get_i.ix());
+                self.expect("GETI index", Type::Ity_I32, ix);
+                let descr = get_i.descr();
+                self.forbid_i1("GETI", Some(descr.elem_ty()));
+                if descr.n_elems() <= 0 {
+                    self.error(IrErrorKind::Implausible("GETI array size"));
+                }
+                Some(descr.elem_ty())
+            }
+            ExprEnum::RdTmp(rd_tmp) => self.use_tmp(rd_tmp.tmp()),
+            ExprEnum::Qop(qop) => {
+                self.op(qop.op(), &[qop.arg1(), qop.arg2(), qop.arg3(), qop.arg4()])
+            }
+            ExprEnum::Triop(triop) => {
+                self.op(triop.op(), &[triop.arg1(), triop.arg2(), triop.arg3()])
+            }
+            ExprEnum::Binop(binop) => self.op(binop.op(), &[binop.arg1(), binop.arg2()]),
+            ExprEnum::Unop(unop) => self.op(unop.op(), &[unop.arg()]),
+            ExprEnum::Load(load) => {
+                let addr = self.expr(load.addr());
+                self.expect_word("load address", addr);
+                self.forbid_i1("load", Some(load.ty()));
+                Some(load.ty())
+            }
+            ExprEnum::Const(co) => Some(match co.as_enum() {
+                ConstEnum::U1(_) => Type::Ity_I1,
+                ConstEnum::U8(_) => Type::Ity_I8,
+                ConstEnum::U16(_) => Type::Ity_I16,
+                ConstEnum::U32(_) => Type::Ity_I32,
+                ConstEnum::U64(_) => Type::Ity_I64,
+                ConstEnum::F32(_) | ConstEnum::F32i(_) => Type::Ity_F32,
+                ConstEnum::F64(_) | ConstEnum::F64i(_) => Type::Ity_F64,
+                ConstEnum::V128(_) => Type::Ity_V128,
+                ConstEnum::V256(_) => Type::Ity_V256,
+            }),
+            ExprEnum::CCall(ccall) => {
+                for arg in ccall.args().iter() {
+                    let ty = self.expr(arg);
+                    self.forbid_i1("CCall argument", ty);
+                }
+                self.forbid_i1("CCall return", Some(ccall.ret_ty()));
+                Some(ccall.ret_ty())
+            }
+            ExprEnum::ITE(ite) => {
+                let cond = self.expr(ite.cond());
+                self.expect("ITE condition", Type::Ity_I1, cond);
+                let if_true = self.expr(ite.if_true());
+                let if_false = self.expr(ite.if_false());
+                if let (Some(if_true), Some(_)) = (if_true, if_false) {
+                    self.expect("ITE false branch", if_true, if_false);
+                }
+                if_true.or(if_false)
+            }
+        }
+    }
+
+    fn stmt(&mut self, mut stmt: Stmt) {
+        if !stmt.is_flat() {
+            self.error(IrErrorKind::NotFlat);
+        }
+        match stmt.as_enum() {
+            StmtEnum::NoOp | StmtEnum::MBE(_) => {}
+            StmtEnum::IMark(imark) => {
+                if imark.len() > 20 {
+                    self.error(IrErrorKind::Implausible("IMark length"));
+                }
+                if imark.delta() > 1 {
+                    self.error(IrErrorKind::Implausible("IMark delta"));
+                }
+            }
+            StmtEnum::AbiHint(hint) => {
+                let base = self.expr(hint.base());
+                self.expect_word("AbiHint base", base);
+                let nia = self.expr(hint.nia());
+                self.expect_word("AbiHint nia", nia);
+                if hint.len() <= 0 {
+                    self.error(IrErrorKind::Implausible("AbiHint length"));
+                }
+            }
+            StmtEnum::Put(put) => {
+                let data = self.expr(put.data());
+                self.forbid_i1("PUT", data);
+            }
+            StmtEnum::PutI(put_i) => {
+                let ix = self.expr(put_i.ix());
+                self.expect("PUTI index", Type::Ity_I32, ix);
+                let data = self.expr(put_i.data());
+                let descr = put_i.descr();
+                self.forbid_i1("PUTI", Some(descr.elem_ty()));
+                self.expect("PUTI data", descr.elem_ty(), data);
+                if descr.n_elems() <= 0 {
+                    self.error(IrErrorKind::Implausible("PUTI array size"));
+                }
+            }
+            StmtEnum::WrTmp(wr_tmp) => {
+                let data = self.expr(wr_tmp.data());
+                if let Some(ty) = self.def_tmp(wr_tmp.tmp()) {
+                    self.expect("WrTmp data", ty, data);
+                }
+            }
+            StmtEnum::Store(store) => {
+                let addr = self.expr(store.addr());
+                self.expect_word("store address", addr);
+                let data = self.expr(store.data());
+                self.forbid_i1("store", data);
+            }
+            StmtEnum::StoreG(store_g) => {
+                let addr = self.expr(store_g.addr());
+                self.expect_word("guarded store address", addr);
+                let data = self.expr(store_g.data());
+                self.forbid_i1("guarded store", data);
+                let guard = self.expr(store_g.guard());
+                self.expect("guarded store guard", Type::Ity_I1, guard);
+            }
+            StmtEnum::LoadG(load_g) => {
+                let addr = self.expr(load_g.addr());
+                self.expect_word("guarded load address", addr);
+                let alt = self.expr(load_g.alt());
+                let guard = self.expr(load_g.guard());
+                self.expect("guarded load guard", Type::Ity_I1, guard);
+                let mut res = Type::Ity_INVALID;
+                let mut arg = Type::Ity_INVALID;
+                unsafe { typeOfIRLoadGOp(load_g.cvt(), &mut res, &mut arg) };
+                self.expect("guarded load alternative", res, alt);
+                if let Some(dst) = self.def_tmp(load_g.dst()) {
+                    self.expect("guarded load destination", dst, Some(res));
+                }
+            }
+            StmtEnum::CAS(cas) => {
+                let addr = self.expr(cas.addr());
+                self.expect_word("CAS address", addr);
+                let expd_lo = self.expr(cas.expd_lo());
+                let data_lo = self.expr(cas.data_lo());
+                let old_lo = self.def_tmp(cas.old_lo());
+                self.forbid_i1("CAS", old_lo);
+                if let Some(old_lo) = old_lo {
+                    self.expect("CAS expected value", old_lo, expd_lo);
+                    self.expect("CAS new value", old_lo, data_lo);
+                }
+                match (cas.old_hi(), cas.expd_hi(), cas.data_hi()) {
+                    (None, None, None) => {}
+                    (Some(old_hi), Some(expd_hi), Some(data_hi)) => {
+                        let expd_hi = self.expr(expd_hi);
+                        let data_hi = self.expr(data_hi);
+                        if let Some(old_hi) = self.def_tmp(old_hi) {
+                            self.expect("CAS expected value", old_hi, expd_hi);
+                            self.expect("CAS new value", old_hi, data_hi);
+                            if let Some(old_lo) = old_lo {
+                                self.expect("CAS high half", old_lo, Some(old_hi));
+                            }
+                        }
+                    }
+                    _ => self.error(IrErrorKind::Implausible("double CAS")),
+                }
+            }
+            StmtEnum::LLSC(llsc) => {
+                let addr = self.expr(llsc.addr());
+                self.expect_word("LLSC address", addr);
+                let store_data = llsc.store_data().map(|data| self.expr(data));
+                let result = self.def_tmp(llsc.result());
+                match store_data {
+                    None => self.forbid_i1("load-linked", result),
+                    Some(data) => {
+                        self.forbid_i1("store-conditional", data);
+                        self.expect("store-conditional result", Type::Ity_I1, result);
+                    }
+                }
+            }
+            StmtEnum::Dirty(dirty) => {
+                let guard = self.expr(dirty.guard());
+                self.expect("dirty guard", Type::Ity_I1, guard);
+                for arg in dirty.args().iter() {
+                    if !matches!(arg.kind(), IRExprTag::Iex_VECRET | IRExprTag::Iex_GSPTR) {
+                        let ty = self.expr(arg);
+                        self.forbid_i1("dirty argument", ty);
+                    }
+                }
+                if dirty.m_fx() != Effect::Ifx_None {
+                    match dirty.m_addr() {
+                        Some(m_addr) => {
+                            let m_addr = self.expr(m_addr);
+                            self.expect_word("dirty memory address", m_addr);
+                        }
+                        None => self.error(IrErrorKind::Implausible("dirty memory address")),
+                    }
+                    if dirty.m_size() <= 0 {
+                        self.error(IrErrorKind::Implausible("dirty memory size"));
+                    }
+                }
+                if let Some(tmp) = dirty.tmp() {
+                    let ty = self.def_tmp(tmp);
+                    self.forbid_i1("dirty result", ty);
+                }
+            }
+            StmtEnum::Exit(exit) => {
+                let guard = self.expr(exit.guard());
+                self.expect("exit guard", Type::Ity_I1, guard);
+                // Typed directly, as wrapping it in an expression would allocate.
+                let dst = unsafe { typeOfIRConst(exit.dst().0) };
+                self.expect_word("exit destination", Some(dst));
+                if exit.offs_ip() < MIN_OFFS_IP {
+                    self.error(IrErrorKind::BadOffsIp(exit.offs_ip()));
+                }
+            }
+        }
+    }
+}
+
+impl IRSB<'_> {
+    /// Check that this block is well formed, without aborting on failure as
+    /// [IRSB::sanity_check] does.
+    ///
+    /// This checks (roughly) what VEX's `sanityCheckIRSB` checks, with flatness
+    /// required. Callers validating non-flat blocks (e.g. ones built by [crate::IRSB!])
+    /// can ignore errors of kind [IrErrorKind::NotFlat].
+    ///
+    /// The guest word type is taken to be the type of `next`.
+    pub fn validate(&self) -> Result<(), Vec<IrError>> {
+        let env = self.type_env();
+        let mut checker = Checker {
+            types: env.types(),
+            defined: vec![false; env.types().len()],
+            word: None,
+            stmt: None,
+            errors: Vec::new(),
+        };
+
+        let next = unsafe { (*self.inner).next };
+        if next.is_null() {
+            checker.error(IrErrorKind::MissingNext);
+        } else {
+            // Check `next` in the context of the whole block, but report its errors first.
+            let mut defined = vec![true; env.types().len()];
+            std::mem::swap(&mut checker.defined, &mut defined);
+            let next = self.next();
+            checker.word = checker.expr(next);
+            if !next.is_atom() {
+                checker.error(IrErrorKind::NotFlat);
+            }
+            if !matches!(checker.word, None | Some(Type::Ity_I32 | Type::Ity_I64)) {
+                checker.error(IrErrorKind::BadType {
+                    context: "next",
+                    found: checker.word.unwrap(),
+                });
+                checker.word = None;
+            }
+            std::mem::swap(&mut checker.defined, &mut defined);
+        }
+        let offs_ip = unsafe { (*self.inner).offsIP };
+        if offs_ip < MIN_OFFS_IP {
+            checker.error(IrErrorKind::BadOffsIp(offs_ip));
+        }
+
+        for (idx, stmt) in self.iter_stmts().enumerate() {
+            checker.stmt = Some(idx);
+            checker.stmt(stmt);
+        }
+
+        if checker.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(checker.errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ir::{Const, IREndness, Stmt, TypeEnv};
+
+    fn errors(irsb: &IRSB) -> Vec<IrErrorKind> {
+        irsb.validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|err| err.kind)
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        let mut irsb = IRSB::new();
+        let env: TypeEnv = irsb.type_env();
+        let t0 = env.new_tmp(Type::Ity_I64);
+        let t1 = env.new_tmp(Type::Ity_I64);
+        unsafe {
+            irsb.add_stmt(Stmt::imark(0x1000, 4, 0));
+            irsb.add_stmt(Stmt::wr_tmp(t0, Expr::get(16, Type::Ity_I64)));
+            irsb.add_stmt(Stmt::wr_tmp(
+                t1,
+                Expr::binop(Op::Iop_Add64, Expr::rd_tmp(t0), Expr::const_(Const::u64(8))),
+            ));
+            irsb.add_stmt(Stmt::store(
+                IREndness::Iend_LE,
+                Expr::rd_tmp(t0),
+                Expr::rd_tmp(t1),
+            ));
+            irsb.set_next(Expr::rd_tmp(t1));
+        }
+        irsb.set_offs_ip(184);
+        irsb.set_jump_kind(crate::ir::JumpKind::Ijk_Boring);
+
+        assert_eq!(irsb.validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut irsb = IRSB::new();
+        let env: TypeEnv = irsb.type_env();
+        let t0 = env.new_tmp(Type::Ity_I32);
+        let t1 = env.new_tmp(Type::Ity_I64);
+        unsafe {
+            // ill-typed
+            irsb.add_stmt(Stmt::wr_tmp(
+                t0,
+                Expr::binop(
+                    Op::Iop_Add32,
+                    Expr::get(16, Type::Ity_I64),
+                    Expr::const_(Const::u32(1)),
+                ),
+            ));
+            // use before def
+            irsb.add_stmt(Stmt::put(24, Expr::rd_tmp(t1)));
+            // multiply assigned
+            irsb.add_stmt(Stmt::wr_tmp(t0, Expr::const_(Const::u32(0))));
+            irsb.set_next(Expr::const_(Const::u64(0x1000)));
+        }
+        irsb.set_offs_ip(8);
+
+        assert_eq!(
+            errors(&irsb),
+            vec![
+                IrErrorKind::BadOffsIp(8),
+                IrErrorKind::NotFlat,
+                IrErrorKind::IllTyped {
+                    context: "op argument",
+                    expected: Type::Ity_I32,
+                    found: Type::Ity_I64
+                },
+                IrErrorKind::UseBeforeDef(t1),
+                IrErrorKind::MultiplyAssigned(t0),
+            ]
+        );
+        assert_eq!(irsb.validate().unwrap_err()[2].stmt, Some(0));
+    }
+}
@@ -0,0 +1,335 @@
+//! Running translations natively, for amd64 guests on amd64 Linux hosts.
+//!
+//! Guest code and data live in the host's own address space: the guest's addresses are
+//! host addresses, and its instructions are read from there when translated. Blocks are
+//! translated into a `TranslationCache`, and entered through a small assembly stub that
+//! gives control back to the dispatcher loop in `Jit::run` whenever a block exits to
+//! somewhere it isn't chained to.
+
+use std::arch::global_asm;
+use std::ffi::c_void;
+use std::fmt::{self, Display};
+use std::io;
+
+use crate::{amd64, Addr, Arch, TranslateArgs, TranslateError, Translation, VexEndness};
+
+mod cache;
+
+pub use cache::{Stats, TranslationCache};
+
+// Translations expect the guest state in %rbp, and a 16 byte aligned stack. Every exit
+// stores the guest's next address first. Exits that need the dispatcher go to
+// `xassisted` with the reason (a `VEX_TRC_JMP_*` code) in %rbp. With chaining, direct
+// jumps call `chain_me_to_*EP` from the place to patch (a 10 byte `movabs` and a 3 byte
+// `call`), and indirect ones go to `xindir`. A failed event check goes to
+// `evcheck_fail`, leaving the guest at the start of the block.
+global_asm!(
+    ".globl libvex_jit_enter",
+    "libvex_jit_enter:",
+    "push rbp",
+    "push rbx",
+    "push r12",
+    "push r13",
+    "push r14",
+    "push r15",
+    "sub rsp, 8",
+    "mov rbp, rdi",
+    "jmp rsi",
+    ".globl libvex_jit_xassisted",
+    "libvex_jit_xassisted:",
+    "mov rax, rbp",
+    "jmp libvex_jit_leave",
+    ".globl libvex_jit_chain_me_to_slowEP",
+    "libvex_jit_chain_me_to_slowEP:",
+    "mov eax, 1",
+    "pop rdx",
+    "sub rdx, 13",
+    "jmp libvex_jit_leave",
+    ".globl libvex_jit_chain_me_to_fastEP",
+    "libvex_jit_chain_me_to_fastEP:",
+    "mov eax, 2",
+    "pop rdx",
+    "sub rdx, 13",
+    "jmp libvex_jit_leave",
+    ".globl libvex_jit_xindir",
+    "libvex_jit_xindir:",
+    "mov eax, 3",
+    "jmp libvex_jit_leave",
+    ".globl libvex_jit_evcheck_fail",
+    "libvex_jit_evcheck_fail:",
+    "xor eax, eax",
+    "libvex_jit_leave:",
+    "add rsp, 8",
+    "pop r15",
+    "pop r14",
+    "pop r13",
+    "pop r12",
+    "pop rbx",
+    "pop rbp",
+    "ret",
+);
+
+extern "C" {
+    fn libvex_jit_enter(state: *mut c_void, code: *const c_void) -> Leave;
+    fn libvex_jit_xassisted();
+    fn libvex_jit_chain_me_to_slowEP();
+    fn libvex_jit_chain_me_to_fastEP();
+    fn libvex_jit_xindir();
+    fn libvex_jit_evcheck_fail();
+}
+
+// What the stub returns for the exits that aren't `VEX_TRC_JMP_*` codes.
+const EVCHECK_FAIL: u32 = 0;
+const CHAIN_ME_TO_SLOW_EP: u32 = 1;
+const CHAIN_ME_TO_FAST_EP: u32 = 2;
+const XINDIR: u32 = 3;
+
+// How translated code gave control back: the exit's code, and for the `chain_me` exits
+// the place to patch.
+#[repr(C)]
+pub(crate) struct Leave {
+    pub trc: u64,
+    pub place: *mut c_void,
+}
+
+/// Run translated code from `entry` on `state`, until it gives control back.
+///
+/// # Safety
+/// The code must be of the state's guest, and everything it accesses must be mapped.
+pub(crate) unsafe fn enter(state: &mut amd64::State, entry: *const c_void) -> Leave {
+    state.0.host_EvC_FAILADDR = libvex_jit_evcheck_fail as *const c_void as Addr;
+    libvex_jit_enter(&mut state.0 as *mut _ as *mut c_void, entry)
+}
+
+// Translation arguments for amd64 on this host, with exits going to the stub.
+pub(crate) fn translate_args(chaining: bool) -> TranslateArgs {
+    let mut vta = TranslateArgs::new(
+        Arch::VexArchAMD64,
+        Arch::VexArchAMD64,
+        VexEndness::VexEndnessLE,
+    );
+    vta.0.disp_cp_xassisted = libvex_jit_xassisted as *const _;
+    if chaining {
+        vta.0.disp_cp_chain_me_to_slowEP = libvex_jit_chain_me_to_slowEP as *const _;
+        vta.0.disp_cp_chain_me_to_fastEP = libvex_jit_chain_me_to_fastEP as *const _;
+        vta.0.disp_cp_xindir = libvex_jit_xindir as *const _;
+    }
+    vta
+}
+
+/// Why `Jit::run` stopped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Exit {
+    /// A `syscall`. The guest's `rip` is the instruction after it.
+    Syscall,
+    /// VEX couldn't decode the instruction at the guest's `rip`.
+    NoDecode,
+    /// Any other exit the dispatcher doesn't handle itself (signals, client requests,
+    /// emulation failures...), by its `VEX_TRC_JMP_*` code.
+    Other(u32),
+}
+
+#[derive(Debug)]
+pub enum JitError {
+    Translate(TranslateError),
+    Map(io::Error),
+}
+
+impl Display for JitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Translate(err) => write!(f, "Translation failed: {:?}", err),
+            Self::Map(err) => write!(f, "Mapping translated code failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for JitError {}
+
+impl From<TranslateError> for JitError {
+    fn from(err: TranslateError) -> Self {
+        Self::Translate(err)
+    }
+}
+
+// A copy of translated code, mapped executable.
+pub(crate) struct Executable {
+    ptr: *mut c_void,
+    len: usize,
+}
+
+impl Executable {
+    fn new(code: &[u8]) -> io::Result<Self> {
+        unsafe {
+            let ptr = libc::mmap(
+                std::ptr::null_mut(),
+                code.len(),
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            let this = Self {
+                ptr,
+                len: code.len(),
+            };
+            std::ptr::copy_nonoverlapping(code.as_ptr(), ptr as *mut u8, code.len());
+            if libc::mprotect(ptr, code.len(), libc::PROT_READ | libc::PROT_EXEC) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(this)
+        }
+    }
+
+    // Translate the block at `bytes`, whose guest address is `addr`. If the arguments
+    // enable profiling, the translation counts its runs into `counter`.
+    pub(crate) fn translate(
+        vta: &mut TranslateArgs,
+        bytes: *const u8,
+        addr: Addr,
+        counter: *mut u64,
+    ) -> Result<(Self, Translation), JitError> {
+        // Enough for the largest blocks VEX makes.
+        let mut host = vec![0; 0x10000];
+        let translation = vta.translate_full(bytes, addr, &mut host)?;
+        if let Some(place) = translation.prof_inc {
+            // The counter's address is absolute, so it can be patched in before mapping.
+            unsafe { vta.patch_prof_inc(host[place..].as_mut_ptr(), counter) };
+        }
+        let code = Self::new(&host[..translation.size]).map_err(JitError::Map)?;
+        Ok((code, translation))
+    }
+
+    pub(crate) fn entry(&self) -> *const c_void {
+        self.ptr
+    }
+
+    fn contains(&self, host: *const c_void) -> bool {
+        (self.ptr as usize..self.ptr as usize + self.len).contains(&(host as usize))
+    }
+}
+
+impl Drop for Executable {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+/// A minimal dynamic binary translator, for amd64 guests running in this process.
+pub struct Jit {
+    /// The number of blocks to run between event checks failing, which hand control back
+    /// to the dispatcher. Must be at least 1.
+    pub quantum: u32,
+    pub cache: TranslationCache,
+}
+
+impl Default for Jit {
+    fn default() -> Self {
+        Self {
+            quantum: 1000,
+            cache: TranslationCache::default(),
+        }
+    }
+}
+
+impl Jit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run the guest from its `rip` until it makes a system call, or until it reaches
+    /// code that can't be run by the dispatcher alone.
+    ///
+    /// Direct jumps between blocks are chained as they are taken. When the guest
+    /// invalidates its instruction cache, the translations of that range are dropped.
+    ///
+    /// # Safety
+    /// The guest runs in this process, with full access to its memory.
+    pub unsafe fn run(&mut self, state: &mut amd64::State) -> Result<Exit, JitError> {
+        state.0.host_EvC_COUNTER = self.quantum;
+        loop {
+            let entry = self.cache.entry(state.0.guest_RIP)?;
+            let leave = enter(state, entry);
+            match leave.trc as u32 {
+                EVCHECK_FAIL => {
+                    self.cache.stats.evcheck_failures += 1;
+                    state.0.host_EvC_COUNTER = self.quantum;
+                }
+                CHAIN_ME_TO_SLOW_EP | CHAIN_ME_TO_FAST_EP => {
+                    let fast = leave.trc as u32 == CHAIN_ME_TO_FAST_EP;
+                    self.cache.chain(leave.place, fast, state.0.guest_RIP)?;
+                }
+                vex_sys::VEX_TRC_JMP_INVALICACHE => {
+                    self.cache
+                        .invalidate(state.0.guest_CMSTART, state.0.guest_CMLEN)?;
+                }
+                XINDIR
+                | vex_sys::VEX_TRC_JMP_BORING
+                | vex_sys::VEX_TRC_JMP_YIELD
+                | vex_sys::VEX_TRC_JMP_EMWARN
+                | vex_sys::VEX_TRC_JMP_FLUSHDCACHE => {}
+                vex_sys::VEX_TRC_JMP_SYS_SYSCALL => return Ok(Exit::Syscall),
+                vex_sys::VEX_TRC_JMP_NODECODE => return Ok(Exit::NoDecode),
+                trc => return Ok(Exit::Other(trc)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    //   xor eax, eax
+    // loop:
+    //   add rax, rdi
+    //   dec rdi
+    //   jnz loop
+    //   syscall
+    //   .byte 0x06
+    static CODE: [u8; 13] = [
+        0x31, 0xc0, 0x48, 0x01, 0xf8, 0x48, 0xff, 0xcf, 0x75, 0xf8, 0x0f, 0x05, 0x06,
+    ];
+
+    #[test]
+    fn run() {
+        let code = CODE.as_ptr() as Addr;
+        let mut state = amd64::State::default();
+        state.0.guest_RIP = code;
+        state.0.guest_RDI = 10;
+        let mut jit = Jit::new();
+        // Make the event checks fail while looping.
+        jit.quantum = 3;
+
+        assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::Syscall);
+        assert_eq!(state.0.guest_RAX, 55);
+        assert_eq!(state.0.guest_RDI, 0);
+        assert_eq!(state.0.guest_RIP, code + 12);
+        let stats = jit.cache.stats();
+        assert!(stats.chains > 0);
+        assert!(stats.evcheck_failures > 0);
+        assert!(jit.cache.profile().is_empty());
+
+        assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::NoDecode);
+        assert_eq!(state.0.guest_RIP, code + 12);
+    }
+
+    #[test]
+    fn profile() {
+        let code = CODE.as_ptr() as Addr;
+        let mut state = amd64::State::default();
+        state.0.guest_RIP = code;
+        state.0.guest_RDI = 10;
+        let mut jit = Jit::new();
+        jit.cache.enable_profiling();
+
+        assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::Syscall);
+        let profile: Vec<_> = jit.cache.profile().into_iter().collect();
+        assert_eq!(profile, [(code, 1), (code + 2, 9), (code + 10, 1)]);
+    }
+}
@@ -0,0 +1,359 @@
+//! A cache of translations keyed by guest address, with direct jumps chained between them.
+
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::*;
+use crate::ir::IRSB;
+use crate::SelfCheckPolicy;
+
+// The size of an unchained place: a 10 byte `movabs` of the `chain_me` stub's address to
+// %r11, and a 3 byte `call *%r11`.
+const PLACE_SIZE: usize = 13;
+
+/// Counts of what the cache did.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Lookups of blocks that were already translated.
+    pub hits: u64,
+    /// Lookups of blocks that had to be translated.
+    pub misses: u64,
+    /// Jumps that were patched to go straight to their target.
+    pub chains: u64,
+    /// Translations dropped by `invalidate`.
+    pub invalidations: u64,
+    /// Event checks that failed, handing control back to the dispatcher.
+    pub evcheck_failures: u64,
+}
+
+// A patched jump, from `place` in the block at `from` to one of the entry points of
+// another block.
+#[derive(Copy, Clone)]
+struct Link {
+    from: Addr,
+    place: *mut c_void,
+    fast: bool,
+}
+
+struct Block {
+    code: Executable,
+    // The guest code the block was translated from.
+    extents: Vec<(Addr, u64)>,
+    // The jumps chained to this block.
+    incoming: Vec<Link>,
+    // The blocks this block has jumps chained to.
+    outgoing: BTreeSet<Addr>,
+    // The number of times the block ran, if it was translated with profiling.
+    counter: Option<Box<Cell<u64>>>,
+}
+
+/// Translations of amd64 guest code, for running in this process.
+///
+/// Every translation starts with an event check, of `LibVEX_evCheckSzB` bytes, which
+/// counts down the guest's `host_EvC_COUNTER`. Its slow entry point is the event check,
+/// and its fast one is right after it. Backward jumps are chained to slow entry points,
+/// so that loops can still be interrupted.
+pub struct TranslationCache {
+    pub(super) stats: Stats,
+    vta: TranslateArgs,
+    blocks: BTreeMap<Addr, Block>,
+    // The blocks by the host address of their code.
+    hosts: BTreeMap<usize, Addr>,
+    evcheck_size: usize,
+}
+
+impl Default for TranslationCache {
+    fn default() -> Self {
+        crate::init();
+        Self {
+            stats: Stats::default(),
+            vta: translate_args(true),
+            blocks: BTreeMap::new(),
+            hosts: BTreeMap::new(),
+            evcheck_size: unsafe { vex_sys::LibVEX_evCheckSzB(Arch::VexArchAMD64) } as usize,
+        }
+    }
+}
+
+impl TranslationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// The number of cached translations.
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Which new translations check their code for modifications when they run. The
+    /// dispatcher invalidates the code of translations that find it modified.
+    pub fn set_self_check(&mut self, policy: SelfCheckPolicy) {
+        self.vta.set_self_check(policy);
+    }
+
+    /// Call `preamble` at the start of every new translation, as with
+    /// `TranslateArgs::set_preamble`.
+    pub fn set_preamble(&mut self, preamble: impl FnMut(Addr, &mut IRSB) -> bool + 'static) {
+        self.vta.set_preamble(preamble);
+    }
+
+    /// Count the runs of every new translation, for `profile`.
+    pub fn enable_profiling(&mut self) {
+        self.vta.enable_profiling();
+    }
+
+    pub fn disable_profiling(&mut self) {
+        self.vta.disable_profiling();
+    }
+
+    /// The number of times each cached translation made with profiling has run.
+    pub fn profile(&self) -> BTreeMap<Addr, u64> {
+        self.blocks
+            .iter()
+            .filter_map(|(&addr, block)| Some((addr, block.counter.as_ref()?.get())))
+            .collect()
+    }
+
+    /// Is there a translation of the block at `addr`.
+    pub fn contains(&self, addr: Addr) -> bool {
+        self.blocks.contains_key(&addr)
+    }
+
+    /// Drop the translations of any code in `start..start + len`, e.g. after the guest
+    /// modified it, and unchain the jumps to them.
+    pub fn invalidate(&mut self, start: Addr, len: u64) -> Result<(), JitError> {
+        let end = start.saturating_add(len);
+        let stale: BTreeSet<Addr> = self
+            .blocks
+            .iter()
+            .filter(|(_, block)| {
+                let mut extents = block.extents.iter();
+                extents.any(|&(base, len)| base < end && start < base + len)
+            })
+            .map(|(&addr, _)| addr)
+            .collect();
+
+        for addr in &stale {
+            let block = self.blocks.remove(addr).unwrap();
+            self.hosts.remove(&(block.code.entry() as usize));
+            // Stale blocks are unmapped as they are dropped, so only the others are
+            // patched.
+            for link in block
+                .incoming
+                .iter()
+                .filter(|link| !stale.contains(&link.from))
+            {
+                let (target, chain_me) = self.target(block.code.entry(), link.fast);
+                unsafe {
+                    patch(link.place, || {
+                        vex_sys::LibVEX_UnChain(
+                            Arch::VexArchAMD64,
+                            VexEndness::VexEndnessLE,
+                            link.place,
+                            target,
+                            chain_me,
+                        )
+                    })
+                }
+                .map_err(JitError::Map)?;
+            }
+            for to in &block.outgoing {
+                if let Some(to) = self.blocks.get_mut(to) {
+                    to.incoming.retain(|link| link.from != *addr);
+                }
+            }
+            self.stats.invalidations += 1;
+        }
+        Ok(())
+    }
+
+    // The slow entry point of the block at `addr`, which is translated if it isn't
+    // cached yet.
+    pub(super) fn entry(&mut self, addr: Addr) -> Result<*const c_void, JitError> {
+        if let Some(block) = self.blocks.get(&addr) {
+            self.stats.hits += 1;
+            return Ok(block.code.entry());
+        }
+        self.stats.misses += 1;
+        let counter = Box::new(Cell::new(0));
+        let (code, translation) =
+            Executable::translate(&mut self.vta, addr as *const u8, addr, counter.as_ptr())?;
+        let entry = code.entry();
+        self.hosts.insert(entry as usize, addr);
+        self.blocks.insert(
+            addr,
+            Block {
+                code,
+                extents: translation.extents,
+                incoming: Vec::new(),
+                outgoing: BTreeSet::new(),
+                counter: translation.prof_inc.map(|_| counter),
+            },
+        );
+        Ok(entry)
+    }
+
+    // Patch the `chain_me` call at `place` to jump straight to the block at `to`.
+    pub(super) fn chain(
+        &mut self,
+        place: *mut c_void,
+        fast: bool,
+        to: Addr,
+    ) -> Result<(), JitError> {
+        let from = match self.hosts.range(..=place as usize).next_back() {
+            Some((_, &from)) if self.blocks[&from].code.contains(place) => from,
+            // Not one of our translations, so it's left to exit again.
+            _ => return Ok(()),
+        };
+        let entry = self.entry(to)?;
+        let (target, chain_me) = self.target(entry, fast);
+        unsafe {
+            patch(place, || {
+                vex_sys::LibVEX_Chain(
+                    Arch::VexArchAMD64,
+                    VexEndness::VexEndnessLE,
+                    place,
+                    chain_me,
+                    target,
+                )
+            })
+        }
+        .map_err(JitError::Map)?;
+
+        let link = Link { from, place, fast };
+        self.blocks.get_mut(&to).unwrap().incoming.push(link);
+        self.blocks.get_mut(&from).unwrap().outgoing.insert(to);
+        self.stats.chains += 1;
+        Ok(())
+    }
+
+    // Where a jump to the block at `entry` goes when chained, and the `chain_me` stub it
+    // calls when it isn't.
+    fn target(&self, entry: *const c_void, fast: bool) -> (*const c_void, *const c_void) {
+        if fast {
+            let target = (entry as usize + self.evcheck_size) as *const c_void;
+            (target, self.vta.0.disp_cp_chain_me_to_fastEP)
+        } else {
+            (entry, self.vta.0.disp_cp_chain_me_to_slowEP)
+        }
+    }
+}
+
+// Make the place writable while `f` patches it.
+unsafe fn patch(place: *mut c_void, f: impl FnOnce() -> vex_sys::VexInvalRange) -> io::Result<()> {
+    let page = libc::sysconf(libc::_SC_PAGESIZE) as usize;
+    let start = place as usize / page * page;
+    let len = place as usize + PLACE_SIZE - start;
+    let protect = |prot| match libc::mprotect(start as *mut c_void, len, prot) {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    };
+    protect(libc::PROT_READ | libc::PROT_WRITE)?;
+    // amd64 keeps its instruction cache coherent, so the range needn't be flushed.
+    f();
+    protect(libc::PROT_READ | libc::PROT_EXEC)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chain() {
+        //   mov eax, 0x10
+        //   jmp 1f
+        // 1:
+        //   add eax, 1
+        //   syscall
+        let mut code = vec![
+            0xb8, 0x10, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x83, 0xc0, 0x01, 0x0f, 0x05,
+        ];
+        let addr = code.as_ptr() as Addr;
+        let mut state = amd64::State::default();
+        let mut jit = Jit::new();
+        let mut run = |jit: &mut Jit| {
+            state.0.guest_RIP = addr;
+            assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::Syscall);
+            state.0.guest_RAX
+        };
+
+        assert_eq!(run(&mut jit), 0x11);
+        assert_eq!(jit.cache.len(), 2);
+        assert!(jit.cache.contains(addr + 7));
+        let stats = jit.cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.chains), (1, 2, 1));
+
+        // Runs the chained, stale translation.
+        code[9] = 2;
+        assert_eq!(run(&mut jit), 0x11);
+        assert_eq!(jit.cache.stats().misses, 2);
+
+        jit.cache.invalidate(addr + 9, 1).unwrap();
+        assert_eq!(jit.cache.len(), 1);
+        assert!(!jit.cache.contains(addr + 7));
+        assert_eq!(run(&mut jit), 0x12);
+        let stats = jit.cache.stats();
+        assert_eq!((stats.misses, stats.chains, stats.invalidations), (3, 2, 1));
+    }
+
+    #[test]
+    fn self_check() {
+        //   mov eax, 0x10
+        //   syscall
+        let mut code = [0xb8, 0x10, 0x00, 0x00, 0x00, 0x0f, 0x05];
+        let addr = code.as_ptr() as Addr;
+        let mut state = amd64::State::default();
+        let mut jit = Jit::new();
+        jit.cache.set_self_check(SelfCheckPolicy::All);
+        let mut run = |jit: &mut Jit| {
+            state.0.guest_RIP = addr;
+            assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::Syscall);
+            state.0.guest_RAX
+        };
+
+        assert_eq!(run(&mut jit), 0x10);
+        // Only the guest reads the code, which the compiler can't see.
+        unsafe { std::ptr::write_volatile(&mut code[1], 0x20) };
+        assert_eq!(run(&mut jit), 0x20);
+        let stats = jit.cache.stats();
+        assert_eq!((stats.misses, stats.invalidations), (2, 1));
+    }
+
+    #[test]
+    fn redirect() {
+        use crate::ir::{Const, Expr, JumpKind};
+
+        //   mov eax, 1
+        //   syscall
+        //   mov eax, 2
+        //   syscall
+        let code = [
+            0xb8, 0x01, 0x00, 0x00, 0x00, 0x0f, 0x05, 0xb8, 0x02, 0x00, 0x00, 0x00, 0x0f, 0x05,
+        ];
+        let (hooked, hook) = (code.as_ptr() as Addr, code.as_ptr() as Addr + 7);
+        let mut state = amd64::State::default();
+        let mut jit = Jit::new();
+        jit.cache.set_preamble(move |addr, irsb| {
+            if addr != hooked {
+                return false;
+            }
+            irsb.set_next(unsafe { Expr::const_(Const::u64(hook)) });
+            irsb.set_jump_kind(JumpKind::Ijk_Boring);
+            irsb.set_offs_ip(amd64::offset::RIP);
+            true
+        });
+
+        state.0.guest_RIP = hooked;
+        assert_eq!(unsafe { jit.run(&mut state) }.unwrap(), Exit::Syscall);
+        assert_eq!(state.0.guest_RAX, 2);
+        assert_eq!(state.0.guest_RIP, hook + 7);
+    }
+}
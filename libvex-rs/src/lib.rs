@@ -1,15 +1,27 @@
 use std::cell::RefCell;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
 
 use lazy_static::lazy_static;
 use parking_lot::{ReentrantMutex, ReentrantMutexGuard};
 
 use vex_sys;
 
+// Lets `IRSB!`, which refers to `libvex::ir`, be used inside this crate.
+extern crate self as libvex;
+
 pub use vex_sys::{Addr, VexArch as Arch, VexEndness};
-pub use libvex_macros::IRSB;
+pub use libvex_macros::{ir_expr, ir_match, ir_stmts, IRSB};
 
+pub mod cfg;
+#[cfg(all(test, target_arch = "x86_64", target_os = "linux"))]
+mod difftest;
+pub mod dot;
+pub mod interp;
 pub mod ir;
-mod logger;
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+pub mod jit;
+pub mod logger;
 
 // arch specific data:
 pub mod amd64;
@@ -23,13 +35,14 @@ pub mod s390x;
 pub mod x86;
 
 unsafe extern "C" fn failure_exit() -> ! {
+    // Make sure the sink gets the end of VEX's message.
+    let _ = logger::flush();
     panic!("LibVEX encountered a critical error.")
 }
 
 unsafe extern "C" fn log_bytes(bytes: *const libc::c_char, nbytes: u64) {
     let bytes = std::slice::from_raw_parts(bytes as *const u8, nbytes as usize);
-    let log = logger::VEX_LOG.lock();
-    let _ = std::io::Write::write(&mut *log.borrow_mut(), bytes);
+    logger::print(bytes);
 }
 
 fn init() {
@@ -82,12 +95,105 @@ impl Default for VexControl {
 }
 
 use libc::c_void;
-unsafe extern "C" fn return_0(
-    _cb: *mut c_void,
+
+/// Which translations check, whenever they run, that the guest code they were made from
+/// hasn't changed since. A translation that finds its code modified exits with
+/// `Ijk_InvalICache`, with the guest's `CMSTART` and `CMLEN` set to the code's range.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelfCheckPolicy {
+    /// Never check.
+    #[default]
+    None,
+    /// Check code in the guest's stack, at `start..end`, where code is most often
+    /// written at runtime (e.g. by trampolines).
+    Stack { start: Addr, end: Addr },
+    /// Check all code.
+    All,
+}
+
+impl SelfCheckPolicy {
+    /// Should code at `base..base + len` be checked.
+    pub fn checks(&self, base: Addr, len: u64) -> bool {
+        match *self {
+            Self::None => false,
+            Self::Stack { start, end } => base < end && start < base.saturating_add(len),
+            Self::All => true,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// The stages of a translation whose output VEX prints (its `VEX_TRACE_*` flags,
+    /// from `main_globals.h`).
+    pub struct TraceFlags: u32 {
+        /// The guest instructions, and the IR they are lifted to.
+        const FRONTEND = 1 << 7;
+        /// The IR after the first optimisation pass.
+        const OPT1 = 1 << 6;
+        /// The IR after instrumentation.
+        const INSTRUMENT = 1 << 5;
+        /// The IR after the second optimisation pass.
+        const OPT2 = 1 << 4;
+        /// The IR after tree building.
+        const TREEBUILD = 1 << 3;
+        /// The host instructions selected, on virtual registers.
+        const VCODE = 1 << 2;
+        /// The host instructions after register allocation.
+        const RCODE = 1 << 1;
+        /// The assembled host code.
+        const ASSEMBLY = 1 << 0;
+    }
+}
+
+/// Builds the start of every block, from its guest address and its empty IRSB. Returns
+/// whether the block is complete, in which case no guest code is decoded into it.
+pub type Preamble = dyn FnMut(Addr, &mut ir::IRSB) -> bool;
+
+// The state of VEX's callbacks, which `callback_opaque` points to.
+#[derive(Default)]
+struct Callbacks {
+    self_check: SelfCheckPolicy,
+    preamble: Option<Box<Preamble>>,
+    // The address of the block being lifted, which VEX doesn't pass to the preamble.
+    addr: Addr,
+    // Whether the preamble panicked during the last translation.
+    preamble_panicked: bool,
+}
+
+unsafe extern "C" fn needs_self_check(
+    cb: *mut c_void,
     _px_control: *mut vex_sys::VexRegisterUpdates,
-    _vge: *const vex_sys::VexGuestExtents,
+    vge: *const vex_sys::VexGuestExtents,
 ) -> u32 {
-    0
+    let callbacks = &*(cb as *const Callbacks);
+    let vge = &*vge;
+    (0..vge.n_used as usize)
+        .filter(|&i| callbacks.self_check.checks(vge.base[i], vge.len[i] as u64))
+        .fold(0, |mask, i| mask | 1 << i)
+}
+
+unsafe extern "C" fn preamble_function(cb: *mut c_void, irsb: *mut vex_sys::IRSB) -> u8 {
+    let callbacks = &mut *(cb as *mut Callbacks);
+    let addr = callbacks.addr;
+    let mut irsb = ir::IRSB {
+        inner: irsb,
+        _lock: LIFT_LOCK.lock(),
+    };
+    let preamble = match &mut callbacks.preamble {
+        Some(preamble) => preamble,
+        None => return 0,
+    };
+    // Unwinding into VEX would be undefined behaviour, so the panic is reported once VEX
+    // returns. Meanwhile, the block is lifted without what the preamble added to it.
+    let stmts_used = (*irsb.inner).stmts_used;
+    match panic::catch_unwind(AssertUnwindSafe(|| preamble(addr, &mut irsb))) {
+        Ok(complete) => complete as u8,
+        Err(_) => {
+            (*irsb.inner).stmts_used = stmts_used;
+            callbacks.preamble_panicked = true;
+            0
+        }
+    }
 }
 
 unsafe extern "C" fn return_false(_cb: *mut c_void, _addr: u64) -> u8 {
@@ -103,6 +209,11 @@ pub enum TranslateError {
     AccessFail,
     OutputFull,
     LockError(LockError),
+    /// The preamble set by `TranslateArgs::set_preamble` panicked.
+    PreamblePanicked,
+    /// The guest and host arches differ, but VEX only has its own arch enabled (see
+    /// `vex_sys::MULTIARCH`).
+    ArchNotEnabled,
 }
 
 impl From<LockError> for TranslateError {
@@ -113,14 +224,68 @@ impl From<LockError> for TranslateError {
 
 pub type TranslateResult<T> = Result<T, TranslateError>;
 
-pub struct TranslateArgs(pub vex_sys::VexTranslateArgs);
+// Every stage of a trace starts with a line of its title, between runs of 24 dashes.
+const RULE: &str = "------------------------";
+
+/// A successful translation, made by `TranslateArgs::translate_full`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Translation {
+    /// The number of bytes of host code.
+    pub size: usize,
+    /// The ranges of guest code that were translated, as `(address, length)`.
+    pub extents: Vec<(Addr, u64)>,
+    /// The offset of the profile increment in the host code, when profiling is enabled.
+    pub prof_inc: Option<usize>,
+    /// What VEX printed for the stages in `TranslateArgs`'s trace flags. Anything it
+    /// printed before the first stage goes to the [logger] sink, as when not tracing.
+    pub trace: String,
+}
+
+impl Translation {
+    /// The trace, split into the stages VEX printed, as `(title, text)`.
+    pub fn trace_stages(&self) -> Vec<(&str, &str)> {
+        let mut stages = Vec::new();
+        let mut rest = self.trace.as_str();
+        while let Some(start) = rest.find(RULE) {
+            let line = rest[start..]
+                .find('\n')
+                .map_or(rest.len(), |end| start + end);
+            let title = rest[start..line].trim_matches(|c| c == '-' || c == ' ');
+            rest = &rest[line..];
+            let end = rest.find(RULE).unwrap_or(rest.len());
+            stages.push((title, rest[..end].trim_matches('\n')));
+            rest = &rest[end..];
+        }
+        stages
+    }
+}
+
+/// The arguments of translations: VEX's own, which can be changed directly, and the state
+/// of the callbacks set by the methods here.
+///
+/// `callback_opaque` belongs to the wrapper. It is pointed back at the callbacks' state
+/// before every translation, so callbacks set directly (e.g. `chase_into_ok`) are passed
+/// that state, not anything of their own.
+pub struct TranslateArgs(pub vex_sys::VexTranslateArgs, Box<Callbacks>);
 
 impl TranslateArgs {
     pub fn new(arch_guest: Arch, arch_host: Arch, endness: VexEndness) -> Self {
+        Self::new_cross(arch_guest, endness, arch_host, endness)
+    }
+
+    /// Like `new`, for a guest and a host of different endianness, e.g. a big-endian PPC64
+    /// or s390x guest on an AMD64 host.
+    pub fn new_cross(
+        arch_guest: Arch,
+        endness_guest: VexEndness,
+        arch_host: Arch,
+        endness_host: VexEndness,
+    ) -> Self {
         let abiinfo_both = AbiInfo::default();
         let archinfo_guest = ArchInfo::default();
+        let mut callbacks = Box::new(Callbacks::default());
 
-        Self(vex_sys::VexTranslateArgs {
+        let vta = vex_sys::VexTranslateArgs {
             abiinfo_both: vex_sys::VexAbiInfo {
                 // Use some values that makes AMD64 happy.
                 guest_stack_redzone_size: 128,
@@ -136,16 +301,16 @@ impl TranslateArgs {
                 // Use some values that makes ARM64 happy.
                 arm64_dMinLine_lg2_szB: 6,
                 arm64_iMinLine_lg2_szB: 6,
-                endness,
-                hwcaps: 0,
+                endness: endness_guest,
+                hwcaps: baseline_hwcaps(arch_guest),
                 ..archinfo_guest.0
             },
             archinfo_host: vex_sys::VexArchInfo {
-                endness,
-                hwcaps: 0,
+                endness: endness_host,
+                hwcaps: baseline_hwcaps(arch_host),
                 ..archinfo_guest.0
             },
-            callback_opaque: std::ptr::null_mut(),
+            callback_opaque: &mut *callbacks as *mut Callbacks as *mut c_void,
             guest_bytes: std::ptr::null(),
             guest_bytes_addr: 0,
             guest_extents: std::ptr::null_mut(),
@@ -156,7 +321,7 @@ impl TranslateArgs {
             instrument1: None,
             instrument2: None,
             finaltidy: None,
-            needs_self_check: Some(return_0),
+            needs_self_check: Some(needs_self_check),
             preamble_function: None,
             traceflags: 0,
             sigill_diag: 0,
@@ -166,7 +331,85 @@ impl TranslateArgs {
             disp_cp_chain_me_to_fastEP: std::ptr::null(),
             disp_cp_xindir: std::ptr::null(),
             disp_cp_xassisted: failure_disp as *const _,
-        })
+        };
+        Self(vta, callbacks)
+    }
+
+    /// Wrap arguments made directly for VEX, with the default callbacks' state. Their
+    /// `callback_opaque` is replaced before every translation.
+    pub fn from_raw(vta: vex_sys::VexTranslateArgs) -> Self {
+        Self(vta, Box::default())
+    }
+
+    pub fn self_check(&self) -> SelfCheckPolicy {
+        self.1.self_check
+    }
+
+    pub fn set_self_check(&mut self, policy: SelfCheckPolicy) {
+        self.1.self_check = policy;
+    }
+
+    /// Call `preamble` at the start of every block, e.g. to redirect calls to a function
+    /// by making its first block jump elsewhere. A preamble that completes the block must
+    /// also set its `next`, jump kind and `offs_ip`.
+    pub fn set_preamble(&mut self, preamble: impl FnMut(Addr, &mut ir::IRSB) -> bool + 'static) {
+        self.1.preamble = Some(Box::new(preamble));
+        self.0.preamble_function = Some(preamble_function);
+    }
+
+    pub fn clear_preamble(&mut self) {
+        self.1.preamble = None;
+        self.0.preamble_function = None;
+    }
+
+    pub fn trace_flags(&self) -> TraceFlags {
+        TraceFlags::from_bits_truncate(self.0.traceflags as u32)
+    }
+
+    /// Have VEX print the output of these stages when translating, into
+    /// `Translation::trace`. `front_end` prints its stages to the log instead.
+    pub fn set_trace_flags(&mut self, flags: TraceFlags) {
+        self.0.traceflags = flags.bits() as vex_sys::Int;
+    }
+
+    /// Start every translation by incrementing a counter, for profiling. The counter's
+    /// address is patched in later, with `patch_prof_inc`, at the offset given by
+    /// `Translation::prof_inc`.
+    pub fn enable_profiling(&mut self) {
+        self.0.addProfInc = 1;
+    }
+
+    pub fn disable_profiling(&mut self) {
+        self.0.addProfInc = 0;
+    }
+
+    /// Make the profile increment at `place` in a translation count into `counter`.
+    ///
+    /// # Safety
+    /// `place` must be the profile increment of a translation made with these arguments,
+    /// and `counter` must outlive the translation, which increments it every time it runs.
+    pub unsafe fn patch_prof_inc(&self, place: *mut u8, counter: *mut u64) {
+        vex_sys::LibVEX_PatchProfInc(
+            self.0.arch_host,
+            self.0.archinfo_host.endness,
+            place as *mut c_void,
+            counter,
+        );
+    }
+
+    // VEX fails (and aborts) if asked to translate for an arch it doesn't have enabled.
+    fn check_arches(&self) -> TranslateResult<()> {
+        if !vex_sys::MULTIARCH && self.0.arch_guest != self.0.arch_host {
+            return Err(TranslateError::ArchNotEnabled);
+        }
+        Ok(())
+    }
+
+    fn take_preamble_panic(&mut self) -> TranslateResult<()> {
+        if mem::take(&mut self.1.preamble_panicked) {
+            return Err(TranslateError::PreamblePanicked);
+        }
+        Ok(())
     }
 
     /// Call VEX's front-end method, LibVEX_FrontEnd.
@@ -181,6 +424,7 @@ impl TranslateArgs {
     ) -> TranslateResult<ir::IRSB> {
         use std::mem::MaybeUninit;
         init();
+        self.check_arches()?;
 
         let mut vtr = MaybeUninit::<vex_sys::VexTranslateResult>::uninit();
         let mut ge = MaybeUninit::<vex_sys::VexGuestExtents>::uninit();
@@ -192,6 +436,8 @@ impl TranslateArgs {
         self.0.host_bytes_used = &mut host_bytes_used;
         self.0.guest_bytes = guest_bytes;
         self.0.guest_bytes_addr = guest_bytes_addr;
+        self.1.addr = guest_bytes_addr;
+        self.0.callback_opaque = &mut *self.1 as *mut Callbacks as *mut c_void;
 
         let _lock = LIFT_LOCK.exclusive_lock()?;
         let irsb = unsafe {
@@ -203,6 +449,7 @@ impl TranslateArgs {
             )
         };
         let vtr = unsafe { vtr.assume_init() };
+        self.take_preamble_panic()?;
 
         match vtr.status {
             vex_sys::VexTranslateResult_VexTransOK => Ok(ir::IRSB { inner: irsb, _lock }),
@@ -218,8 +465,20 @@ impl TranslateArgs {
         guest_bytes_addr: u64,
         host_bytes: &mut [u8],
     ) -> TranslateResult<i32> {
+        self.translate_full(guest_bytes, guest_bytes_addr, host_bytes)
+            .map(|translation| translation.size as i32)
+    }
+
+    /// Like `translate`, also returning what VEX reports about the translation.
+    pub fn translate_full(
+        &mut self,
+        guest_bytes: *const u8,
+        guest_bytes_addr: u64,
+        host_bytes: &mut [u8],
+    ) -> TranslateResult<Translation> {
         use std::mem::MaybeUninit;
         init();
+        self.check_arches()?;
 
         let mut ge = MaybeUninit::<vex_sys::VexGuestExtents>::uninit();
         self.0.guest_extents = ge.as_mut_ptr();
@@ -229,15 +488,54 @@ impl TranslateArgs {
         self.0.host_bytes_used = &mut host_bytes_used;
         self.0.guest_bytes = guest_bytes;
         self.0.guest_bytes_addr = guest_bytes_addr;
+        self.1.addr = guest_bytes_addr;
+        self.0.callback_opaque = &mut *self.1 as *mut Callbacks as *mut c_void;
 
         let _lock = LIFT_LOCK.exclusive_lock()?;
-        let vtr = unsafe { vex_sys::LibVEX_Translate(&mut self.0) };
-
-        match vtr.status {
-            vex_sys::VexTranslateResult_VexTransOK => Ok(host_bytes_used),
+        // Only a trace is captured, so that VEX's other messages reach the sink.
+        let (vtr, output) = if self.0.traceflags == 0 {
+            (
+                unsafe { vex_sys::LibVEX_Translate(&mut self.0) },
+                Vec::new(),
+            )
+        } else {
+            logger::capture(|| unsafe { vex_sys::LibVEX_Translate(&mut self.0) })
+        };
+        let status = match vtr.status {
+            vex_sys::VexTranslateResult_VexTransOK => Ok(()),
             vex_sys::VexTranslateResult_VexTransAccessFail => Err(TranslateError::AccessFail),
             vex_sys::VexTranslateResult_VexTransOutputFull => Err(TranslateError::OutputFull),
+        };
+        if let Err(err) = self.take_preamble_panic().and(status) {
+            // Nothing gets the trace of a failed translation, so it goes to the sink.
+            logger::print(&output);
+            return Err(err);
         }
+
+        // The trace starts at the title of its first stage.
+        let start = output
+            .windows(RULE.len())
+            .position(|bytes| bytes == RULE.as_bytes())
+            .unwrap_or(output.len());
+        logger::print(&output[..start]);
+        let ge = unsafe { ge.assume_init() };
+        Ok(Translation {
+            size: host_bytes_used as usize,
+            extents: (0..ge.n_used as usize)
+                .map(|i| (ge.base[i], ge.len[i] as u64))
+                .collect(),
+            prof_inc: (vtr.offs_profInc >= 0).then_some(vtr.offs_profInc as usize),
+            trace: String::from_utf8_lossy(&output[start..]).into_owned(),
+        })
+    }
+}
+
+// The capabilities VEX requires of every CPU of `arch`.
+fn baseline_hwcaps(arch: Arch) -> u32 {
+    match arch {
+        // s390x needs the long displacement facility, which came with the z990.
+        Arch::VexArchS390X => s390x::model::VEX_S390X_MODEL_Z990 | s390x::hwcap::LDISP,
+        _ => 0,
     }
 }
 
@@ -301,7 +599,7 @@ lazy_static! {
 
 #[cfg(test)]
 mod test {
-    use super::{Arch, TranslateArgs, VexEndness};
+    use super::{Arch, SelfCheckPolicy, TranslateArgs, TranslateError, VexEndness};
 
     #[test]
     fn sanity() {
@@ -452,4 +750,461 @@ mod test {
             assert_eq!(lifted, expected);
         }
     }
+
+    mod irsb_macro {
+        use super::*;
+        use crate::ir::IRSB;
+        use crate::IRSB;
+
+        #[test]
+        fn lifted() {
+            let mut vta = TranslateArgs::new(
+                Arch::VexArchAMD64,
+                Arch::VexArchAMD64,
+                VexEndness::VexEndnessLE,
+            );
+            let lifted = vta
+                .front_end(
+                    [0xb8, 0, 0, 0, 0, 0xe8, 0x5b, 0xfd, 0xff, 0xff].as_ptr(),
+                    0x12eb,
+                )
+                .unwrap();
+
+            // As printed by `lifted.to_string()`
+            let expected = IRSB! {
+                IRSB {
+                   t0:I64   t1:I64   t2:I64   t3:I64   t4:I64   t5:I64   t6:I64
+
+                   ------ IMark(0x12eb, 5, 0) ------
+                   PUT(16) = 0x0:I64
+                   PUT(184) = 0x12f0:I64
+                   ------ IMark(0x12f0, 5, 0) ------
+                   t4 = GET:I64(48)
+                   t3 = Sub64(t4,0x8:I64)
+                   PUT(48) = t3
+                   STle(t3) = 0x12f5:I64
+                   t5 = Sub64(t3,0x80:I64)
+                   ====== AbiHint(t5, 128, 0x1050:I64) ======
+                   PUT(184) = 0x1050:I64; exit-Call
+                }
+            };
+
+            assert_eq!(lifted, expected);
+        }
+
+        #[test]
+        fn registers() {
+            let named = IRSB! {
+                arch amd64;
+                t0:I64   t1:I64
+
+                t0 = GET:I64(rsp)
+                t1 = Sub64(t0,0x8:I64)
+                PUT(RSP) = t1
+                PUT(rax) = 0x0:I64
+                PUT(rip) = GET:I64(rdi); exit-Boring
+            };
+            let numbered = IRSB! {
+                t0:I64   t1:I64
+
+                t0 = GET:I64(48)
+                t1 = Sub64(t0,0x8:I64)
+                PUT(48) = t1
+                PUT(16) = 0x0:I64
+                PUT(184) = GET:I64(72); exit-Boring
+            };
+            assert_eq!(named, numbered);
+        }
+
+        #[test]
+        fn fragments() {
+            use crate::ir::JumpKind;
+            use crate::{ir_expr, ir_stmts};
+
+            let mut irsb = IRSB::new();
+            ir_stmts!(irsb, arch amd64; t0 = GET:I64(rsp); t1 = Sub64(t0,0x8:I64); PUT(rsp) = t1);
+            ir_stmts!(irsb, t2 = LDle:I64(t1) t3 = t2 STle(t1) = Add64(t3,0x1:I64));
+            ir_stmts!(irsb, t4:I32 t4 = 64to32(t3));
+            let next = ir_expr!(irsb, Add64(t2,0x10:I64));
+            irsb.set_next(next);
+            irsb.set_offs_ip(184);
+            irsb.set_jump_kind(JumpKind::Ijk_Boring);
+
+            let expected = IRSB! {
+                t0:I64   t1:I64   t2:I64   t3:I64   t4:I32
+
+                t0 = GET:I64(48)
+                t1 = Sub64(t0,0x8:I64)
+                PUT(48) = t1
+                t2 = LDle:I64(t1)
+                t3 = t2
+                STle(t1) = Add64(t3,0x1:I64)
+                t4 = 64to32(t3)
+                PUT(184) = Add64(t2,0x10:I64); exit-Boring
+            };
+            assert_eq!(irsb, expected);
+        }
+
+        #[test]
+        fn patterns() {
+            use crate::ir::{ConstEnum, Op};
+            use crate::{ir_expr, ir_match};
+
+            let irsb = IRSB::new();
+            let t0 = irsb.type_env().new_tmp(crate::ir::Type::Ity_I64);
+            let expr = ir_expr!(irsb, Add64(GET:I64(48),Sub64(t0,0x8:I64)));
+
+            let (off, e) = ir_match!(expr, Add64(GET:I64($off), $e)).unwrap();
+            assert_eq!(off, 48);
+            assert!(ir_match!(e, Sub64(t0, 0x8:I64)).is_some());
+            assert!(ir_match!(e, Sub64(t0, 0x9:I64)).is_none());
+            assert!(ir_match!(expr, Add32(_, _)).is_none());
+
+            let (tmp, co) = ir_match!(expr, arch amd64; Add64(GET:I64(rsp), Sub64($t:Temp, $c:Const)))
+                .unwrap();
+            assert_eq!(tmp, t0);
+            assert!(matches!(co.as_enum(), ConstEnum::U64(8)));
+            assert_eq!(ir_match!(expr, Add64(_, Sub64(_, $c:I64))), Some(8));
+            assert!(ir_match!(expr, Add64(GET:I64(16), _)).is_none());
+
+            let load = ir_expr!(irsb, ITE(CmpEQ64(t0,0x0:I64),LDle:I64(t0),0x1:I64));
+            let (cond, addr) = ir_match!(load, ITE($cond, LDle:I64($addr), 0x1:I64)).unwrap();
+            match cond.as_enum() {
+                crate::ir::ExprEnum::Binop(binop) => assert_eq!(binop.op(), Op::Iop_CmpEQ64),
+                _ => panic!("expected a Binop"),
+            }
+            assert_eq!(ir_match!(addr, $t:Temp), Some(t0));
+            assert!(ir_match!(load, ITE(_, LDbe:I64(_), _)).is_none());
+        }
+
+        #[test]
+        fn all_kinds() {
+            // `ir::ALL_KINDS`, as the macro only takes tokens.
+            let irsb = IRSB! {
+                t0:I64   t1:I64   t2:I1   t3:I32   t4:I32   t5:I64   t6:I64   t7:I1
+                t8:F64   t9:V128
+
+                IR-NoOp
+                ------ IMark(0x1000, 4, 0) ------
+                ====== AbiHint(0x20:I64, 128, t0) ======
+                t0 = GET:I64(48)
+                t1 = GETI(136:8xI64)[t3,-1]
+                PUTI(136:8xI64)[t3,1] = t1
+                t2 = CmpEQ64(t0,t1)
+                t5 = ITE(t2,LDle:I64(t0),0x0:I64)
+                t6 = amd64g_calculate_rflags_c[mcx=0x9]{0x4000}(0x8:I64,t0,t1,0x0:I64):I64
+                t8 = AddF64(0x0:I32,F64{0x3ff0000000000000},GET:F64(200))
+                t9 = 64HLtoV128(t0,t0)
+                PUT(216) = V128{0xff00}
+                t4 = if-strict (t2) 16Uto32(LDle(t0)) else 0x0:I32
+                if (t2) { STbe(t0) = t3 }
+                t3 = CASle(t0::t3->t4)
+                t5,t6 = CASle(t0::t5,t6->t1,t0)
+                t0 = LDle-Linked(t1)
+                t7 = ( STle-Cond(t1) = t0 )
+                IR-Fence
+                t1 = DIRTY t2 RdFX-mem(t0,16) RdFX-gst(16,8) MoFX-gst(200,8,reps4,step16) ::: amd64g_dirtyhelper_CPUID_baseline[rp=1]{0x5000}(GSPTR,t0)
+                DIRTY 1:I1 ::: amd64g_dirtyhelper_RDTSC{0x6000}()
+                STle(t0) = F32{0x3f800000}
+                if (t2) { PUT(184) = 0x1004:I64; exit-SigSEGV }
+                PUT(184) = t5; exit-Return
+            };
+
+            assert_eq!(irsb, IRSB::parse(crate::ir::ALL_KINDS).unwrap());
+        }
+    }
+
+    #[test]
+    fn self_check() {
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let mut vge: vex_sys::VexGuestExtents = unsafe { std::mem::zeroed() };
+        vge.n_used = 3;
+        vge.base = [0x1000, 0x7ff0, 0x9000];
+        vge.len = [0x10, 0x20, 0x10];
+        let mask = |vta: &mut TranslateArgs| unsafe {
+            let needs_self_check = vta.0.needs_self_check.unwrap();
+            needs_self_check(vta.0.callback_opaque, std::ptr::null_mut(), &vge)
+        };
+
+        assert_eq!(vta.self_check(), SelfCheckPolicy::None);
+        assert_eq!(mask(&mut vta), 0);
+        vta.set_self_check(SelfCheckPolicy::Stack {
+            start: 0x8000,
+            end: 0x9000,
+        });
+        assert_eq!(mask(&mut vta), 0b010);
+        vta.set_self_check(SelfCheckPolicy::All);
+        assert_eq!(mask(&mut vta), 0b111);
+
+        // The callbacks' state stays put when the arguments move.
+        let mut moved = Box::new(vta);
+        assert_eq!(mask(&mut moved), 0b111);
+
+        // A `callback_opaque` of the caller's is replaced when translating.
+        moved.0.callback_opaque = std::ptr::null_mut();
+        let code = [0xc3];
+        let mut buf = [0; 1000];
+        moved
+            .translate_full(code.as_ptr(), 0x1000, &mut buf)
+            .unwrap();
+        assert_eq!(mask(&mut moved), 0b111);
+
+        // Wrapping the raw arguments starts from the default state.
+        let mut raw = TranslateArgs::from_raw(moved.0);
+        assert_eq!(raw.self_check(), SelfCheckPolicy::None);
+        raw.translate_full(code.as_ptr(), 0x1000, &mut buf).unwrap();
+        assert_eq!(mask(&mut raw), 0);
+    }
+
+    #[test]
+    fn preamble() {
+        use super::ir::{owned, Const, Expr, JumpKind};
+
+        // 0x1000: ret
+        let code = [0xc3];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        vta.set_preamble(|addr, irsb| {
+            if addr != 0x1000 {
+                return false;
+            }
+            irsb.set_next(unsafe { Expr::const_(Const::u64(0x2000)) });
+            irsb.set_jump_kind(JumpKind::Ijk_Boring);
+            irsb.set_offs_ip(crate::amd64::offset::RIP);
+            true
+        });
+
+        {
+            let mut irsb = vta.front_end(code.as_ptr(), 0x1000).unwrap();
+            assert_eq!(irsb.iter_stmts().count(), 0);
+            assert_eq!(irsb.jump_kind(), JumpKind::Ijk_Boring);
+            assert_eq!(irsb.offs_ip(), crate::amd64::offset::RIP);
+            assert!(matches!(
+                owned::Expr::from(irsb.next()),
+                owned::Expr::Const(owned::Const::U64(0x2000))
+            ));
+        }
+        {
+            let irsb = vta.front_end(code.as_ptr(), 0x3000).unwrap();
+            assert_eq!(irsb.jump_kind(), JumpKind::Ijk_Ret);
+        }
+        vta.clear_preamble();
+        {
+            let irsb = vta.front_end(code.as_ptr(), 0x1000).unwrap();
+            assert_eq!(irsb.jump_kind(), JumpKind::Ijk_Ret);
+        }
+
+        vta.set_preamble(|_, _| panic!("preamble failed"));
+        assert!(matches!(
+            vta.front_end(code.as_ptr(), 0x1000),
+            Err(TranslateError::PreamblePanicked)
+        ));
+        let mut buf = [0; 1000];
+        assert!(matches!(
+            vta.translate_full(code.as_ptr(), 0x1000, &mut buf),
+            Err(TranslateError::PreamblePanicked)
+        ));
+        vta.clear_preamble();
+        assert!(vta.translate_full(code.as_ptr(), 0x1000, &mut buf).is_ok());
+    }
+
+    #[test]
+    fn profiling() {
+        // ret
+        let code = [0xc3];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let mut buf = [0; 1000];
+
+        let translation = vta.translate_full(code.as_ptr(), 0x1000, &mut buf).unwrap();
+        assert_eq!(translation.extents, [(0x1000, 1)]);
+        assert_eq!(translation.prof_inc, None);
+
+        vta.enable_profiling();
+        let translation = vta.translate_full(code.as_ptr(), 0x1000, &mut buf).unwrap();
+        let place = translation.prof_inc.unwrap();
+        assert!(place < translation.size);
+        let mut counter = 0u64;
+        let counter: *mut u64 = &mut counter;
+        unsafe { vta.patch_prof_inc(buf[place..].as_mut_ptr(), counter) };
+        let address = (counter as u64).to_le_bytes();
+        assert!(buf[place..translation.size]
+            .windows(8)
+            .any(|bytes| bytes == address));
+    }
+
+    #[test]
+    fn trace() {
+        use super::TraceFlags;
+        use crate::logger;
+
+        // ret
+        let code = [0xc3];
+        let mut vta = TranslateArgs::new(
+            Arch::VexArchAMD64,
+            Arch::VexArchAMD64,
+            VexEndness::VexEndnessLE,
+        );
+        let mut buf = [0; 1000];
+
+        let translation = vta.translate_full(code.as_ptr(), 0x1000, &mut buf).unwrap();
+        assert_eq!(translation.trace, "");
+        assert!(translation.trace_stages().is_empty());
+
+        vta.set_trace_flags(TraceFlags::FRONTEND | TraceFlags::ASSEMBLY);
+        assert_eq!(
+            vta.trace_flags(),
+            TraceFlags::FRONTEND | TraceFlags::ASSEMBLY
+        );
+        let translation = vta.translate_full(code.as_ptr(), 0x1000, &mut buf).unwrap();
+        let stages = translation.trace_stages();
+        let titles: Vec<_> = stages.iter().map(|&(title, _)| title).collect();
+        assert_eq!(titles, ["Front end", "Assembly"]);
+        assert!(stages[0].1.contains("IMark(0x1000, 1, 0)"));
+
+        // The trace of a failed translation goes to the sink instead.
+        let log = logger::VEX_LOG.lock();
+        let writes = logger::test::Writes::default();
+        logger::set_sink(logger::Sink::Writer(Box::new(writes.clone()))).unwrap();
+        let result = vta.translate_full(code.as_ptr(), 0x1000, &mut buf[..8]);
+        logger::set_sink(logger::Sink::Stderr).unwrap();
+        drop(log);
+        assert!(matches!(result, Err(TranslateError::OutputFull)));
+        assert!(writes.0.lock().unwrap().concat().contains("Front end"));
+    }
+
+    // Guest code of other arches, translated for AMD64.
+    mod cross_arch {
+        use super::*;
+        use crate::ir::owned::{Block, Expr, Stmt};
+        use crate::ir::{JumpKind, Op};
+
+        const ADDR: u64 = 0x1000;
+
+        // Two instructions: one that adds two registers into `sum`, and a return.
+        struct Case {
+            arch: Arch,
+            endness: VexEndness,
+            code: &'static [u8],
+            lens: [u32; 2],
+            sum: i32,
+            pc: i32,
+        }
+
+        fn check(case: Case) {
+            let mut vta = TranslateArgs::new_cross(
+                case.arch,
+                case.endness,
+                Arch::VexArchAMD64,
+                VexEndness::VexEndnessLE,
+            );
+            if !vex_sys::MULTIARCH {
+                assert!(matches!(
+                    vta.front_end(case.code.as_ptr(), ADDR),
+                    Err(TranslateError::ArchNotEnabled)
+                ));
+                return;
+            }
+
+            {
+                let block = Block::from(&vta.front_end(case.code.as_ptr(), ADDR).unwrap());
+                let imarks: Vec<_> = block
+                    .stmts
+                    .iter()
+                    .filter_map(|stmt| match *stmt {
+                        Stmt::IMark { addr, len, .. } => Some((addr, len)),
+                        _ => None,
+                    })
+                    .collect();
+                let [first, second] = case.lens;
+                assert_eq!(imarks, [(ADDR, first), (ADDR + first as u64, second)]);
+
+                let sum = block.stmts.iter().find_map(|stmt| match stmt {
+                    Stmt::Put {
+                        offset,
+                        data: Expr::RdTmp(tmp),
+                    } if *offset == case.sum => Some(*tmp),
+                    _ => None,
+                });
+                assert!(
+                    block.stmts.iter().any(|stmt| matches!(
+                        stmt,
+                        Stmt::WrTmp { tmp, data: Expr::Binop { op: Op::Iop_Add64, .. } }
+                            if Some(*tmp) == sum
+                    )),
+                    "no Add64 into {} for {:?}: {:#?}",
+                    case.sum,
+                    case.arch,
+                    block.stmts
+                );
+                assert_eq!(block.jump_kind, JumpKind::Ijk_Ret);
+                assert_eq!(block.offs_ip, case.pc);
+            }
+
+            let mut buf = [0; 1000];
+            let translation = vta
+                .translate_full(case.code.as_ptr(), ADDR, &mut buf)
+                .unwrap();
+            assert_eq!(translation.extents, [(ADDR, case.code.len() as u64)]);
+            assert!(translation.size > 0 && translation.size < buf.len());
+        }
+
+        #[test]
+        fn arm64() {
+            use crate::arm64::offset::{PC, X0};
+
+            check(Case {
+                arch: Arch::VexArchARM64,
+                endness: VexEndness::VexEndnessLE,
+                // add x0, x0, x1
+                // ret
+                code: &[0x00, 0x00, 0x01, 0x8b, 0xc0, 0x03, 0x5f, 0xd6],
+                lens: [4, 4],
+                sum: X0,
+                pc: PC,
+            });
+        }
+
+        #[test]
+        fn ppc64() {
+            use crate::ppc64::offset::{CIA, GPR3};
+
+            check(Case {
+                arch: Arch::VexArchPPC64,
+                endness: VexEndness::VexEndnessBE,
+                // add r3, r3, r4
+                // blr
+                code: &[0x7c, 0x63, 0x22, 0x14, 0x4e, 0x80, 0x00, 0x20],
+                lens: [4, 4],
+                sum: GPR3,
+                pc: CIA,
+            });
+        }
+
+        #[test]
+        fn s390x() {
+            use crate::s390x::offset::{r2, IA};
+
+            check(Case {
+                arch: Arch::VexArchS390X,
+                endness: VexEndness::VexEndnessBE,
+                // agr %r2, %r3
+                // br %r14
+                code: &[0xb9, 0x08, 0x00, 0x23, 0x07, 0xfe],
+                lens: [4, 2],
+                sum: r2,
+                pc: IA,
+            });
+        }
+    }
 }
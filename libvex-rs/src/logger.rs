@@ -1,3 +1,10 @@
+//! Where VEX's messages go.
+//!
+//! VEX prints its messages (debugging output, traces, and the reasons it fails) a few
+//! characters at a time. They are gathered into lines, and every complete line is given
+//! to the `Sink`, standard error by default. `with` captures them instead, e.g. to print
+//! IR.
+
 use std::cell::RefCell;
 use std::io::{self, Write};
 use std::mem;
@@ -6,38 +13,383 @@ use std::str::Utf8Error;
 use lazy_static::lazy_static;
 use parking_lot::ReentrantMutex;
 
-pub struct VexLogger(Option<Vec<u8>>);
+/// Where complete lines of VEX's messages go.
+pub enum Sink {
+    /// Standard error.
+    Stderr,
+    /// Any writer, given a line (with its newline) at a time.
+    Writer(Box<dyn Write + Send>),
+    /// Records of the `log` crate at this level, with the target `vex`. Empty lines are
+    /// dropped.
+    #[cfg(feature = "log")]
+    Log(log::Level),
+    /// Events of the `tracing` crate at this level, with the target `vex`. Empty lines
+    /// are dropped.
+    #[cfg(feature = "tracing")]
+    Tracing(tracing::Level),
+}
+
+impl Sink {
+    fn line(&mut self, line: &[u8]) -> io::Result<()> {
+        match self {
+            Self::Stderr => io::stderr().write_all(line),
+            Self::Writer(writer) => writer.write_all(line),
+            #[cfg(feature = "log")]
+            Self::Log(level) => {
+                if let Some(text) = text(line) {
+                    log::log!(target: "vex", *level, "{}", text);
+                }
+                Ok(())
+            }
+            #[cfg(feature = "tracing")]
+            Self::Tracing(level) => {
+                if let Some(text) = text(line) {
+                    // `tracing` needs the level to be a constant.
+                    match *level {
+                        tracing::Level::ERROR => tracing::error!(target: "vex", "{}", text),
+                        tracing::Level::WARN => tracing::warn!(target: "vex", "{}", text),
+                        tracing::Level::INFO => tracing::info!(target: "vex", "{}", text),
+                        tracing::Level::DEBUG => tracing::debug!(target: "vex", "{}", text),
+                        _ => tracing::trace!(target: "vex", "{}", text),
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Stderr => io::stderr().flush(),
+            Self::Writer(writer) => writer.flush(),
+            #[cfg(feature = "log")]
+            Self::Log(_) => {
+                log::logger().flush();
+                Ok(())
+            }
+            #[cfg(feature = "tracing")]
+            Self::Tracing(_) => Ok(()),
+        }
+    }
+}
+
+// The text of a line for a log record, unless it's empty.
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn text(line: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(line);
+    let text = text.trim_end_matches('\n');
+    Some(text.to_owned()).filter(|text| !text.is_empty())
+}
+
+pub(crate) struct VexLogger {
+    capture: Option<Vec<u8>>,
+    sink: Sink,
+    // The start of a line that hasn't been given to the sink yet.
+    partial: Vec<u8>,
+}
 
 impl Write for VexLogger {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        match &mut self.0 {
-            Some(vec) => Write::write(vec, buf),
-            None => Write::write(&mut io::stderr(), buf),
+        if let Some(vec) = &mut self.capture {
+            return Write::write(vec, buf);
         }
+        self.send(buf)?;
+        Ok(buf.len())
     }
 
+    /// Give the sink anything being captured, and the partial line too.
     fn flush(&mut self) -> io::Result<()> {
-        Write::flush(&mut io::stderr())
+        if let Some(captured) = self.capture.as_mut().map(mem::take) {
+            self.send(&captured)?;
+        }
+        if !self.partial.is_empty() {
+            let line = mem::take(&mut self.partial);
+            self.sink.line(&line)?;
+        }
+        self.sink.flush()
     }
 }
 
 impl VexLogger {
+    // Give the sink every line completed by `buf`.
+    fn send(&mut self, buf: &[u8]) -> io::Result<()> {
+        let mut rest = buf;
+        while let Some(end) = rest.iter().position(|&b| b == b'\n') {
+            self.partial.extend_from_slice(&rest[..=end]);
+            let line = mem::take(&mut self.partial);
+            self.sink.line(&line)?;
+            rest = &rest[end + 1..];
+        }
+        self.partial.extend_from_slice(rest);
+        Ok(())
+    }
+
     fn new() -> ReentrantMutex<RefCell<Self>> {
-        ReentrantMutex::new(RefCell::new(Self(None)))
+        ReentrantMutex::new(RefCell::new(Self {
+            capture: None,
+            sink: Sink::Stderr,
+            partial: Vec::new(),
+        }))
     }
 }
 
 lazy_static! {
-    pub static ref VEX_LOG: ReentrantMutex<RefCell<VexLogger>> = VexLogger::new();
+    pub(crate) static ref VEX_LOG: ReentrantMutex<RefCell<VexLogger>> = VexLogger::new();
+}
+
+/// Send VEX's messages to `sink` from now on, after flushing the old sink.
+pub fn set_sink(sink: Sink) -> io::Result<()> {
+    let guard = VEX_LOG.lock();
+    let mut log = guard.borrow_mut();
+    log.flush()?;
+    log.sink = sink;
+    Ok(())
 }
 
-pub fn with<F, R>(mut f: F) -> (R, Result<String, Utf8Error>)
+/// Give the sink any partial line, and anything `with` is capturing, e.g. before a panic
+/// that would lose it.
+pub fn flush() -> io::Result<()> {
+    VEX_LOG.lock().borrow_mut().flush()
+}
+
+// Ends a capture, putting back the one it replaced. If it is dropped without being
+// finished, i.e. when `with`'s closure panics, what it captured goes to the sink instead.
+struct Capture<'a> {
+    log: &'a RefCell<VexLogger>,
+    old: Option<Option<Vec<u8>>>,
+}
+
+impl Capture<'_> {
+    fn finish(mut self) -> Vec<u8> {
+        let old = self.old.take().unwrap();
+        mem::replace(&mut self.log.borrow_mut().capture, old).unwrap()
+    }
+}
+
+impl Drop for Capture<'_> {
+    fn drop(&mut self) {
+        // A logger that is still borrowed is left alone, rather than panicking again.
+        if let (Some(old), Ok(mut log)) = (self.old.take(), self.log.try_borrow_mut()) {
+            let captured = mem::replace(&mut log.capture, old).unwrap_or_default();
+            let _ = log.send(&captured);
+        }
+    }
+}
+
+/// Run `f`, and return what VEX printed while it ran, instead of sending it to the sink.
+pub fn with<F, R>(f: F) -> (R, Result<String, Utf8Error>)
+where
+    F: FnMut() -> R,
+{
+    let (res, s) = capture(f);
+    (res, std::str::from_utf8(&s).map(str::to_string))
+}
+
+// Like `with`, returning the bytes VEX printed as they are.
+pub(crate) fn capture<F, R>(mut f: F) -> (R, Vec<u8>)
 where
     F: FnMut() -> R,
 {
     let guard = VEX_LOG.lock();
-    let old = guard.borrow_mut().0.replace(Vec::new());
+    let old = guard.borrow_mut().capture.replace(Vec::new());
+    let capture = Capture {
+        log: &guard,
+        old: Some(old),
+    };
     let res = f();
-    let s = mem::replace(&mut guard.borrow_mut().0, old).unwrap();
-    (res, std::str::from_utf8(&s).map(str::to_string))
+    (res, capture.finish())
+}
+
+// Handle `bytes` as if VEX printed them.
+pub(crate) fn print(bytes: &[u8]) {
+    let guard = VEX_LOG.lock();
+    let _ = guard.borrow_mut().write_all(bytes);
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    // Keeps every write separately.
+    #[derive(Clone, Default)]
+    pub(crate) struct Writes(pub(crate) Arc<Mutex<Vec<String>>>);
+
+    impl Write for Writes {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let write = String::from_utf8(buf.to_vec()).unwrap();
+            self.0.lock().unwrap().push(write);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn lines() {
+        // Keep other tests' messages out of the sink.
+        let guard = VEX_LOG.lock();
+        let writes = Writes::default();
+        set_sink(Sink::Writer(Box::new(writes.clone()))).unwrap();
+
+        let write = |text: &str| guard.borrow_mut().write_all(text.as_bytes()).unwrap();
+        write("vex: ");
+        write("first\nsecond");
+        write(" line\n\nthird");
+        assert_eq!(
+            *writes.0.lock().unwrap(),
+            ["vex: first\n", "second line\n", "\n"]
+        );
+
+        let ((), captured) = with(|| write("captured\n"));
+        assert_eq!(captured.unwrap(), "captured\n");
+
+        set_sink(Sink::Stderr).unwrap();
+        assert_eq!(
+            *writes.0.lock().unwrap(),
+            ["vex: first\n", "second line\n", "\n", "third"]
+        );
+    }
+
+    #[test]
+    fn panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let guard = VEX_LOG.lock();
+        let writes = Writes::default();
+        set_sink(Sink::Writer(Box::new(writes.clone()))).unwrap();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| {
+            with(|| {
+                guard.borrow_mut().write_all(b"vex: failed\n").unwrap();
+                panic!("VEX failed")
+            })
+        }));
+        assert!(panicked.is_err());
+        assert!(guard.borrow().capture.is_none());
+        assert_eq!(*writes.0.lock().unwrap(), ["vex: failed\n"]);
+
+        let ((), captured) = with(|| {
+            guard.borrow_mut().write_all(b"vex: flushed\n").unwrap();
+            flush().unwrap();
+        });
+        assert_eq!(captured.unwrap(), "");
+        assert_eq!(
+            *writes.0.lock().unwrap(),
+            ["vex: failed\n", "vex: flushed\n"]
+        );
+
+        set_sink(Sink::Stderr).unwrap();
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log() {
+        // Keeps the records of the `vex` target.
+        struct Records(Mutex<Vec<(log::Level, String)>>);
+
+        impl log::Log for Records {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.target() == "vex"
+            }
+
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    let text = record.args().to_string();
+                    self.0.lock().unwrap().push((record.level(), text));
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        static RECORDS: Records = Records(Mutex::new(Vec::new()));
+        log::set_logger(&RECORDS).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let guard = VEX_LOG.lock();
+        set_sink(Sink::Log(log::Level::Warn)).unwrap();
+        guard
+            .borrow_mut()
+            .write_all(b"vex: first\n\nsecond\n")
+            .unwrap();
+        set_sink(Sink::Stderr).unwrap();
+        assert_eq!(
+            *RECORDS.0.lock().unwrap(),
+            [
+                (log::Level::Warn, "vex: first".to_owned()),
+                (log::Level::Warn, "second".to_owned())
+            ]
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing() {
+        use std::fmt::Debug;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata, Subscriber};
+
+        // Keeps the events of the `vex` target.
+        #[derive(Clone, Default)]
+        struct Events(Arc<Mutex<Vec<(Level, String)>>>);
+
+        struct Message<'a>(&'a mut String);
+
+        impl Visit for Message<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                if field.name() == "message" {
+                    *self.0 = format!("{:?}", value);
+                }
+            }
+        }
+
+        impl Subscriber for Events {
+            fn enabled(&self, _: &Metadata) -> bool {
+                true
+            }
+
+            fn new_span(&self, _: &Attributes) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, _: &Record) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event) {
+                if event.metadata().target() == "vex" {
+                    let mut text = String::new();
+                    event.record(&mut Message(&mut text));
+                    let level = *event.metadata().level();
+                    self.0.lock().unwrap().push((level, text));
+                }
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let events = Events::default();
+        tracing::subscriber::with_default(events.clone(), || {
+            let guard = VEX_LOG.lock();
+            set_sink(Sink::Tracing(Level::INFO)).unwrap();
+            guard
+                .borrow_mut()
+                .write_all(b"vex: first\n\nsecond\n")
+                .unwrap();
+            set_sink(Sink::Stderr).unwrap();
+        });
+        assert_eq!(
+            *events.0.lock().unwrap(),
+            [
+                (Level::INFO, "vex: first".to_owned()),
+                (Level::INFO, "second".to_owned())
+            ]
+        );
+    }
 }
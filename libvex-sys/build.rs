@@ -127,6 +127,22 @@ fn main() -> Result<()> {
 
         // Tell rustc to link to libvex
         println!("cargo:rustc-link-search=native={}", vex_dir.display());
+        // libvex only has the front and back ends of the host's arch enabled.
+        // libvexmultiarch overrides its entry points with ones that enable all of them,
+        // so it has to come first. A `VEX_LIBS` directory may not have it, in which case
+        // only the host's arch can be translated, as `MULTIARCH` tells.
+        let multiarch = format!("vexmultiarch-{}-{}", arch, platform);
+        println!("cargo:rustc-check-cfg=cfg(vex_multiarch)");
+        if vex_dir.join(format!("lib{}.a", multiarch)).exists() {
+            println!("cargo:rustc-link-lib=static={}", multiarch);
+            println!("cargo:rustc-cfg=vex_multiarch");
+        } else {
+            println!(
+                "cargo:warning=lib{}.a not found in {}, only the host's arch can be translated",
+                multiarch,
+                vex_dir.display()
+            );
+        }
         println!("cargo:rustc-link-lib=static=vex-{}-{}", arch, platform);
     }
 
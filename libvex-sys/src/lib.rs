@@ -5,6 +5,10 @@
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+/// Whether libvexmultiarch was linked, enabling the front and back ends of every arch.
+/// Otherwise only those of the arch VEX was built for are enabled.
+pub const MULTIARCH: bool = cfg!(vex_multiarch);
+
 #[repr(C)]
 pub struct _IRStmt__bindgen_ty_1__bindgen_ty_1(());
 